@@ -1,57 +1,50 @@
 use hip_runtime_sys::{
-    hipDeviceProp_t, hipDriverGetVersion, hipError_t, hipGetDeviceCount, hipGetDeviceProperties,
-    hipInit, hipRuntimeGetVersion,
+    attribute, hipDriverGetVersion, hipInit, hipRuntimeGetVersion, Device, HipError,
 };
 
-fn main() {
-    let result = unsafe { hipInit(0) };
-    assert_eq!(result, hipError_t::hipSuccess);
+fn main() -> Result<(), HipError> {
+    unsafe { hipInit(0) }.into_result()?;
 
     let mut driver_version: i32 = 0;
-    let result = unsafe { hipDriverGetVersion(&mut driver_version) };
-    assert_eq!(result, hipError_t::hipSuccess);
+    unsafe { hipDriverGetVersion(&mut driver_version) }.into_result()?;
     println!("Driver Version: {driver_version}");
 
     let mut runtime_version: i32 = 0;
-    let result = unsafe { hipRuntimeGetVersion(&mut runtime_version) };
-    assert_eq!(result, hipError_t::hipSuccess);
+    unsafe { hipRuntimeGetVersion(&mut runtime_version) }.into_result()?;
     println!("Runtime Version: {runtime_version}");
 
-    let mut device_count: i32 = 0;
-    let result = unsafe { hipGetDeviceCount(&mut device_count) };
-    assert_eq!(result, hipError_t::hipSuccess);
-    println!("Device Count: {device_count}");
+    let devices = Device::all()?;
+    println!("Device Count: {}", devices.len());
 
-    for i in 0..device_count {
-        let (name, device_prop) = unsafe {
-            let mut device_prop: hipDeviceProp_t = std::mem::zeroed();
-            let result = hipGetDeviceProperties(&mut device_prop, i);
-            assert_eq!(result, hipError_t::hipSuccess);
-            let device_name_u8: &[u8] =
-                std::slice::from_raw_parts(device_prop.name.as_ptr() as *const u8, 256);
-            let result_str = std::str::from_utf8(device_name_u8).unwrap();
-            (result_str, device_prop)
-        };
+    for device in devices {
         println!(
-            "Device {}: {} | multi {}",
-            i, name, device_prop.isMultiGpuBoard
+            "Device {}: {} ({}) | multi {}",
+            device.ordinal(),
+            device.name()?,
+            device.gcn_arch_name()?,
+            device.attribute::<attribute::IsMultiGpuBoard>()?
         );
         println!(
             " -> mem    | glb: {}GiB, shared/blk: {}KiB, ",
-            device_prop.totalGlobalMem / (1024 * 1024 * 1024),
-            device_prop.sharedMemPerBlock / (1024)
+            device.total_memory()? / (1024 * 1024 * 1024),
+            device.attribute::<attribute::MaxSharedMemoryPerBlock>()? / 1024
         );
         println!(
             " -> thread | max/blk: {}, warpSize {}, max [{} {} {}]",
-            device_prop.maxThreadsPerBlock,
-            device_prop.warpSize,
-            device_prop.maxThreadsDim[0],
-            device_prop.maxThreadsDim[1],
-            device_prop.maxThreadsDim[2]
+            device.attribute::<attribute::MaxThreadsPerBlock>()?,
+            device.attribute::<attribute::WarpSize>()?,
+            device.attribute::<attribute::MaxBlockDimX>()?,
+            device.attribute::<attribute::MaxBlockDimY>()?,
+            device.attribute::<attribute::MaxBlockDimZ>()?
         );
         println!(
             " -> grid   | max [{} {} {}]",
-            device_prop.maxGridSize[0], device_prop.maxGridSize[1], device_prop.maxGridSize[2]
+            device.attribute::<attribute::MaxGridDimX>()?,
+            device.attribute::<attribute::MaxGridDimY>()?,
+            device.attribute::<attribute::MaxGridDimZ>()?
         );
+        println!(" -> pci    | {}", device.pci_bus_id()?);
     }
+
+    Ok(())
 }
@@ -0,0 +1,184 @@
+//! Build script support for the `dynamic-loading` feature of the hip-sys
+//! crates.
+//!
+//! [`generate`] rewrites the `extern "C"` declarations in bindgen's output
+//! into functions that call through a table resolved from the library at
+//! runtime by a `hip_loader::Loader`. The crate using the output must
+//! depend on `hip-loader` and provide `crate::dynamic::api()`, returning the
+//! loaded table, and `crate::dynamic::unavailable(symbol)`, which panics.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::path::Path;
+use syn::{
+    Expr, ExprLit, FnArg, ForeignItem, Item, Lit, Meta, MetaNameValue, Pat, ReturnType, Type,
+};
+
+/// A status type returned by the bindings, and the variants used to
+/// report that the library or a function in it is unavailable.
+pub struct Status {
+    /// The name of the type, e.g. "hipError_t".
+    pub ty: &'static str,
+    /// The variant returned when the library couldn't be loaded.
+    pub not_loaded: &'static str,
+    /// The variant returned when the library doesn't export the function.
+    pub not_found: &'static str,
+}
+
+/// Read the bindings in `bindings` and write them to `out` with every
+/// foreign function replaced by a shim. Functions returning one of the
+/// `statuses` report a missing library or function through it; others
+/// panic.
+pub fn generate(bindings: &str, out: &Path, statuses: &[Status]) {
+    let src = std::fs::read_to_string(bindings)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", bindings, e));
+    let file =
+        syn::parse_file(&src).unwrap_or_else(|e| panic!("Couldn't parse {}: {}", bindings, e));
+
+    let mut items = Vec::new();
+    let mut fields = Vec::new();
+    let mut loads = Vec::new();
+    let mut names = Vec::new();
+    let mut shims = Vec::new();
+    for item in file.items {
+        let foreign = match item {
+            Item::ForeignMod(foreign) => foreign,
+            item => {
+                items.push(quote!(#item));
+                continue;
+            }
+        };
+        for foreign_item in foreign.items {
+            let func = match foreign_item {
+                ForeignItem::Fn(func) => func,
+                other => panic!("Unsupported foreign item: {}", quote!(#other)),
+            };
+            assert!(
+                func.sig.variadic.is_none(),
+                "Variadic function `{}` can't be loaded dynamically",
+                func.sig.ident
+            );
+
+            // `link_name` overrides the symbol to look up; it doesn't
+            // apply to the generated Rust function.
+            let (link_names, attrs): (Vec<_>, Vec<_>) = func
+                .attrs
+                .iter()
+                .partition(|attr| attr.path().is_ident("link_name"));
+            let ident = &func.sig.ident;
+            let name = ident.to_string();
+            let symbol = match link_names.first().map(|attr| &attr.meta) {
+                Some(Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(s), ..
+                        }),
+                    ..
+                })) => format!("{}\0", s.value().trim_start_matches('\u{1}')),
+                _ => format!("{name}\0"),
+            };
+            let inputs = &func.sig.inputs;
+            let output = &func.sig.output;
+            let args: Vec<_> = inputs.iter().map(arg_name).collect();
+            let arg_types: Vec<_> = inputs
+                .iter()
+                .map(|arg| match arg {
+                    FnArg::Typed(pat) => &pat.ty,
+                    FnArg::Receiver(_) => unreachable!(),
+                })
+                .collect();
+
+            // Functions returning a status report a missing library or
+            // symbol through it; anything else has to panic.
+            let status =
+                return_type(output).and_then(|ty| statuses.iter().find(|status| status.ty == ty));
+            let (not_loaded, not_found) = match status {
+                Some(status) => {
+                    let ty = format_ident!("{}", status.ty);
+                    let not_loaded = format_ident!("{}", status.not_loaded);
+                    let not_found = format_ident!("{}", status.not_found);
+                    (quote!(#ty::#not_loaded), quote!(#ty::#not_found))
+                }
+                None => (
+                    quote!(crate::dynamic::unavailable(#name)),
+                    quote!(crate::dynamic::unavailable(#name)),
+                ),
+            };
+
+            fields.push(quote! {
+                #ident: Option<unsafe extern "C" fn(#(#arg_types),*) #output>
+            });
+            loads.push(quote! {
+                #ident: lib.get(#symbol.as_bytes()).ok().map(|f: ::hip_loader::Symbol<_>| *f)
+            });
+            names.push(name.clone());
+            shims.push(quote! {
+                #(#attrs)*
+                pub unsafe fn #ident(#inputs) #output {
+                    match crate::dynamic::api() {
+                        Some(api) => match api.#ident {
+                            Some(__hip_fn) => __hip_fn(#(#args),*),
+                            None => #not_found,
+                        },
+                        None => #not_loaded,
+                    }
+                }
+            });
+        }
+    }
+
+    let idents: Vec<_> = names.iter().map(|name| format_ident!("{}", name)).collect();
+    let tokens: TokenStream = quote! {
+        #(#items)*
+
+        pub(crate) struct Api {
+            _lib: ::hip_loader::Library,
+            #(#fields,)*
+        }
+
+        impl ::hip_loader::Api for Api {
+            const SYMBOLS: &'static [&'static str] = &[#(#names),*];
+
+            unsafe fn new(lib: ::hip_loader::Library) -> Api {
+                Api {
+                    #(#loads,)*
+                    _lib: lib,
+                }
+            }
+
+            fn has_symbol(&self, name: &str) -> Option<bool> {
+                match name {
+                    #(#names => Some(self.#idents.is_some()),)*
+                    _ => None,
+                }
+            }
+        }
+
+        #(#shims)*
+    };
+    std::fs::write(out, tokens.to_string())
+        .unwrap_or_else(|e| panic!("Couldn't write {}: {}", out.display(), e));
+}
+
+fn arg_name(arg: &FnArg) -> TokenStream {
+    match arg {
+        FnArg::Typed(pat) => match &*pat.pat {
+            Pat::Ident(ident) => {
+                let ident = &ident.ident;
+                quote!(#ident)
+            }
+            other => panic!("Unsupported argument pattern: {}", quote!(#other)),
+        },
+        FnArg::Receiver(_) => unreachable!(),
+    }
+}
+
+fn return_type(output: &ReturnType) -> Option<String> {
+    match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path.path.get_ident().map(ToString::to_string),
+            _ => None,
+        },
+        ReturnType::Default => None,
+    }
+}
@@ -0,0 +1,197 @@
+//! Runtime loading of ROCm libraries, shared by the `dynamic-loading`
+//! feature of `hip-runtime-sys`, `hipblas-sys` and `hipblaslt-sys`.
+//!
+//! Each of those crates' build scripts uses `hip-loader-build` to rewrite
+//! its `extern "C"` declarations into functions that call through an
+//! [`Api`] table, and keeps a static [`Loader`] that opens the library the
+//! first time the table is needed.
+
+pub use libloading::{Library, Symbol};
+
+use once_cell::sync::OnceCell;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// A table of functions resolved from a library. Implemented by the code
+/// `hip-loader-build` generates.
+pub trait Api: Sized {
+    /// The names of every function in the table.
+    const SYMBOLS: &'static [&'static str];
+
+    /// Resolve every function in the table from `lib`. Functions the
+    /// library doesn't export are left unresolved.
+    ///
+    /// # Safety
+    /// `lib` must be the library the table was generated for, so that each
+    /// exported function has the signature of its binding.
+    unsafe fn new(lib: Library) -> Self;
+
+    /// Whether `symbol` was resolved, or `None` if it isn't in the table.
+    fn has_symbol(&self, symbol: &str) -> Option<bool>;
+}
+
+/// An error from loading a library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadError {
+    /// None of the candidate libraries could be opened.
+    LibraryNotFound {
+        /// The library that was searched for, e.g. "hipBLAS".
+        library: &'static str,
+        /// Every path or name that was tried.
+        candidates: Vec<PathBuf>,
+        /// The loader's message for the last candidate.
+        message: String,
+    },
+    /// The library was opened, but doesn't export this function.
+    MissingSymbol {
+        /// The library that was opened.
+        library: &'static str,
+        /// The function that's missing.
+        symbol: String,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::LibraryNotFound {
+                library,
+                candidates,
+                message,
+            } => write!(
+                f,
+                "Unable to load the {library} library from any of {candidates:?}: {message}"
+            ),
+            LoadError::MissingSymbol { library, symbol } => {
+                write!(f, "The {library} library doesn't export `{symbol}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Open the library at `path`. On Unix, its symbols are made visible to the
+/// whole process, as they would be if it were linked, so that e.g.
+/// `hipblasStatusToString` can be found without knowing which library
+/// exports it.
+#[cfg(unix)]
+unsafe fn open_library(path: &Path) -> Result<Library, libloading::Error> {
+    use libloading::os::unix::{Library, RTLD_GLOBAL, RTLD_LAZY};
+    Library::open(Some(path), RTLD_LAZY | RTLD_GLOBAL).map(Into::into)
+}
+
+/// Open the library at `path`.
+#[cfg(not(unix))]
+unsafe fn open_library(path: &Path) -> Result<Library, libloading::Error> {
+    Library::new(path)
+}
+
+/// Where to find a library, and the table of its functions once it's been
+/// opened.
+pub struct Loader<A> {
+    library: &'static str,
+    names: &'static [&'static str],
+    path_vars: &'static [&'static str],
+    default_dir: &'static str,
+    api: OnceCell<Result<A, LoadError>>,
+}
+
+impl<A> Loader<A> {
+    /// A loader for `library` (a name for messages, e.g. "hipBLAS"), which
+    /// searches for each of `names` with the system loader, then in the
+    /// `lib` directory of each of the `path_vars` environment variables that
+    /// is set, then in `default_dir`.
+    pub const fn new(
+        library: &'static str,
+        names: &'static [&'static str],
+        path_vars: &'static [&'static str],
+        default_dir: &'static str,
+    ) -> Loader<A> {
+        Loader {
+            library,
+            names,
+            path_vars,
+            default_dir,
+            api: OnceCell::new(),
+        }
+    }
+}
+
+impl<A: Api> Loader<A> {
+    fn candidates(&self) -> Vec<PathBuf> {
+        let lib_dirs: Vec<PathBuf> = self
+            .path_vars
+            .iter()
+            .filter_map(std::env::var_os)
+            .map(|path| PathBuf::from(path).join("lib"))
+            .chain(std::iter::once(PathBuf::from(self.default_dir)))
+            .collect();
+        let mut candidates: Vec<PathBuf> = self.names.iter().map(PathBuf::from).collect();
+        for lib_dir in &lib_dirs {
+            candidates.extend(self.names.iter().map(|name| lib_dir.join(name)));
+        }
+        candidates
+    }
+
+    fn open(&self) -> Result<A, LoadError> {
+        let candidates = self.candidates();
+        let mut message = String::new();
+        for candidate in &candidates {
+            // Safety: loading a library runs its initialisers, which is the
+            // same thing that happens when linking against it.
+            match unsafe { open_library(candidate) } {
+                Ok(lib) => return Ok(unsafe { A::new(lib) }),
+                Err(e) => message = e.to_string(),
+            }
+        }
+        Err(LoadError::LibraryNotFound {
+            library: self.library,
+            candidates,
+            message,
+        })
+    }
+
+    /// The table of functions, opening the library if this is the first
+    /// call. `None` if it couldn't be opened.
+    pub fn api(&self) -> Option<&A> {
+        self.api.get_or_init(|| self.open()).as_ref().ok()
+    }
+
+    /// Open the library, if it hasn't been opened already.
+    pub fn load(&self) -> Result<(), LoadError> {
+        self.api
+            .get_or_init(|| self.open())
+            .as_ref()
+            .map(|_| ())
+            .map_err(Clone::clone)
+    }
+
+    /// Check that the library is loaded and exports `symbol`.
+    pub fn check_symbol(&self, symbol: &str) -> Result<(), LoadError> {
+        self.load()?;
+        match self.api().and_then(|api| api.has_symbol(symbol)) {
+            Some(true) => Ok(()),
+            _ => Err(LoadError::MissingSymbol {
+                library: self.library,
+                symbol: symbol.to_string(),
+            }),
+        }
+    }
+
+    /// The names of all functions in the table that the library exports, or
+    /// doesn't if `exported` is false. Empty if the library couldn't be
+    /// loaded.
+    pub fn symbols(&self, exported: bool) -> Vec<&'static str> {
+        match self.api() {
+            Some(api) => A::SYMBOLS
+                .iter()
+                .copied()
+                .filter(|symbol| api.has_symbol(symbol) == Some(exported))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
@@ -89,7 +89,9 @@ bindgen "${SCRIPTPATH}"/wrapper.h \
     --raw-line "#![allow(non_camel_case_types)]" \
     --raw-line "#![allow(non_upper_case_globals)]" \
     --raw-line "#![allow(non_snake_case)]" \
-    --rustified-enum "hip.*" \
+    --newtype-enum "hipError_t" \
+    --no-debug "hipError_t" \
+    --rustified-enum "hip([^E]|E[^r]).*" \
     --generate-block \
     --ctypes-prefix "::libc" \
     --with-derive-default \
@@ -4,28 +4,30 @@ fn main() {
     println!("cargo:rerun-if-env-changed=HIP_PATH");
     println!("cargo:rerun-if-env-changed=ROCM_PATH");
 
-    let hip_path = root_candidates()
-        .find(|path| path.join("include/hip/hip_runtime_api.h").is_file())
-        .unwrap_or_else(|| {
-            panic!(
-                "Unable to find include path containing `hip/hip_runtime_api.h` under any of: {:?}.
-                Set the `HIP_PATH` environment variable such that `$HIP_PATH/include/hip/hip_runtime_api.h` exists.",
-                root_candidates().collect::<Vec<_>>()
-            )
-        });
+    let hip_path =
+        root_candidates().find(|path| path.join("include/hip/hip_runtime_api.h").is_file());
 
-    println!(
-        "cargo:warning={}: Using '{}' as HIP_PATH",
-        env!("CARGO_PKG_NAME"),
-        hip_path.display()
-    );
+    if let Some(hip_path) = &hip_path {
+        println!(
+            "cargo:warning={}: Using '{}' as HIP_PATH",
+            env!("CARGO_PKG_NAME"),
+            hip_path.display()
+        );
+    }
 
-    let hip_lib = hip_path.join("lib");
-    println!("cargo:rustc-link-search=native={}", hip_lib.display());
-    println!("cargo:rustc-link-lib=dylib=amdhip64");
+    // With dynamic loading, libamdhip64 is opened at runtime, so a HIP
+    // installation is only needed if the bindings are being regenerated.
+    if !cfg!(feature = "dynamic-loading") {
+        let hip_path = hip_path.as_ref().unwrap_or_else(|| missing_hip_path());
+        let hip_lib = hip_path.join("lib");
+        println!("cargo:rustc-link-search=native={}", hip_lib.display());
+        println!("cargo:rustc-link-lib=dylib=amdhip64");
+    }
 
     #[cfg(feature = "bindgen")]
     {
+        let hip_path = hip_path.as_ref().unwrap_or_else(|| missing_hip_path());
+
         // The bindgen::Builder is the main entry point to bindgen, and lets you
         // build up options for the resulting bindings.
         println!("cargo:rerun-if-changed=wrapper.h");
@@ -38,7 +40,13 @@ fn main() {
             .clang_arg(format!("-I{}", hip_path.join("include").display()))
             .clang_arg(format!("-I{}", hip_path.join("include/hipify").display()))
             .clang_arg("-D__HIP_PLATFORM_AMD__") // needed for rocm>6)
-            .rustified_non_exhaustive_enum("hip.*")
+            // Statuses come back from the runtime, which may return codes
+            // newer than these bindings; a Rust enum can't hold those. Its
+            // Debug is written by hand in error.rs to print the code's name.
+            .newtype_enum("hipError_t")
+            .no_debug("hipError_t")
+            // Every other enum, i.e. all but those starting "hipEr".
+            .rustified_non_exhaustive_enum("hip([^E]|E[^r]).*")
             .generate_block(false)
             .size_t_is_usize(true)
             .ctypes_prefix("::libc")
@@ -57,6 +65,30 @@ fn main() {
             .write_to_file("src/bindings.rs")
             .expect("Couldn't write bindings!");
     }
+
+    #[cfg(feature = "dynamic-loading")]
+    {
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=src/bindings.rs");
+        let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+        hip_loader_build::generate(
+            "src/bindings.rs",
+            &out_dir.join("bindings.rs"),
+            &[hip_loader_build::Status {
+                ty: "hipError_t",
+                not_loaded: "hipErrorSharedObjectInitFailed",
+                not_found: "hipErrorSharedObjectSymbolNotFound",
+            }],
+        );
+    }
+}
+
+fn missing_hip_path() -> ! {
+    panic!(
+        "Unable to find include path containing `hip/hip_runtime_api.h` under any of: {:?}.
+        Set the `HIP_PATH` environment variable such that `$HIP_PATH/include/hip/hip_runtime_api.h` exists.",
+        root_candidates().collect::<Vec<_>>()
+    )
 }
 
 /**
@@ -78,4 +110,4 @@ fn root_candidates() -> impl Iterator<Item = PathBuf> {
     ].iter()
         .map(Into::<PathBuf>::into);
     env_vars.chain(roots)
-}
\ No newline at end of file
+}
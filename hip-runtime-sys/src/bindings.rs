@@ -1880,111 +1880,257 @@ impl hipError_t {
 impl hipError_t {
     pub const hipErrorInvalidResourceHandle: hipError_t = hipError_t::hipErrorInvalidHandle;
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
-pub enum hipError_t {
+impl hipError_t {
     #[doc = "< Successful completion."]
-    hipSuccess = 0,
+    pub const hipSuccess: hipError_t = hipError_t(0);
+}
+impl hipError_t {
     #[doc = "< One or more of the parameters passed to the API call is NULL\n< or not in an acceptable range."]
-    hipErrorInvalidValue = 1,
-    hipErrorOutOfMemory = 2,
-    hipErrorNotInitialized = 3,
-    hipErrorDeinitialized = 4,
-    hipErrorProfilerDisabled = 5,
-    hipErrorProfilerNotInitialized = 6,
-    hipErrorProfilerAlreadyStarted = 7,
-    hipErrorProfilerAlreadyStopped = 8,
-    hipErrorInvalidConfiguration = 9,
-    hipErrorInvalidPitchValue = 12,
-    hipErrorInvalidSymbol = 13,
+    pub const hipErrorInvalidValue: hipError_t = hipError_t(1);
+}
+impl hipError_t {
+    pub const hipErrorOutOfMemory: hipError_t = hipError_t(2);
+}
+impl hipError_t {
+    pub const hipErrorNotInitialized: hipError_t = hipError_t(3);
+}
+impl hipError_t {
+    pub const hipErrorDeinitialized: hipError_t = hipError_t(4);
+}
+impl hipError_t {
+    pub const hipErrorProfilerDisabled: hipError_t = hipError_t(5);
+}
+impl hipError_t {
+    pub const hipErrorProfilerNotInitialized: hipError_t = hipError_t(6);
+}
+impl hipError_t {
+    pub const hipErrorProfilerAlreadyStarted: hipError_t = hipError_t(7);
+}
+impl hipError_t {
+    pub const hipErrorProfilerAlreadyStopped: hipError_t = hipError_t(8);
+}
+impl hipError_t {
+    pub const hipErrorInvalidConfiguration: hipError_t = hipError_t(9);
+}
+impl hipError_t {
+    pub const hipErrorInvalidPitchValue: hipError_t = hipError_t(12);
+}
+impl hipError_t {
+    pub const hipErrorInvalidSymbol: hipError_t = hipError_t(13);
+}
+impl hipError_t {
     #[doc = "< Invalid Device Pointer"]
-    hipErrorInvalidDevicePointer = 17,
+    pub const hipErrorInvalidDevicePointer: hipError_t = hipError_t(17);
+}
+impl hipError_t {
     #[doc = "< Invalid memory copy direction"]
-    hipErrorInvalidMemcpyDirection = 21,
-    hipErrorInsufficientDriver = 35,
-    hipErrorMissingConfiguration = 52,
-    hipErrorPriorLaunchFailure = 53,
-    hipErrorInvalidDeviceFunction = 98,
+    pub const hipErrorInvalidMemcpyDirection: hipError_t = hipError_t(21);
+}
+impl hipError_t {
+    pub const hipErrorInsufficientDriver: hipError_t = hipError_t(35);
+}
+impl hipError_t {
+    pub const hipErrorMissingConfiguration: hipError_t = hipError_t(52);
+}
+impl hipError_t {
+    pub const hipErrorPriorLaunchFailure: hipError_t = hipError_t(53);
+}
+impl hipError_t {
+    pub const hipErrorInvalidDeviceFunction: hipError_t = hipError_t(98);
+}
+impl hipError_t {
     #[doc = "< Call to hipGetDeviceCount returned 0 devices"]
-    hipErrorNoDevice = 100,
+    pub const hipErrorNoDevice: hipError_t = hipError_t(100);
+}
+impl hipError_t {
     #[doc = "< DeviceID must be in range 0...#compute-devices."]
-    hipErrorInvalidDevice = 101,
-    hipErrorInvalidImage = 200,
+    pub const hipErrorInvalidDevice: hipError_t = hipError_t(101);
+}
+impl hipError_t {
+    pub const hipErrorInvalidImage: hipError_t = hipError_t(200);
+}
+impl hipError_t {
     #[doc = "< Produced when input context is invalid."]
-    hipErrorInvalidContext = 201,
-    hipErrorContextAlreadyCurrent = 202,
-    hipErrorMapFailed = 205,
-    hipErrorUnmapFailed = 206,
-    hipErrorArrayIsMapped = 207,
-    hipErrorAlreadyMapped = 208,
-    hipErrorNoBinaryForGpu = 209,
-    hipErrorAlreadyAcquired = 210,
-    hipErrorNotMapped = 211,
-    hipErrorNotMappedAsArray = 212,
-    hipErrorNotMappedAsPointer = 213,
-    hipErrorECCNotCorrectable = 214,
-    hipErrorUnsupportedLimit = 215,
-    hipErrorContextAlreadyInUse = 216,
-    hipErrorPeerAccessUnsupported = 217,
+    pub const hipErrorInvalidContext: hipError_t = hipError_t(201);
+}
+impl hipError_t {
+    pub const hipErrorContextAlreadyCurrent: hipError_t = hipError_t(202);
+}
+impl hipError_t {
+    pub const hipErrorMapFailed: hipError_t = hipError_t(205);
+}
+impl hipError_t {
+    pub const hipErrorUnmapFailed: hipError_t = hipError_t(206);
+}
+impl hipError_t {
+    pub const hipErrorArrayIsMapped: hipError_t = hipError_t(207);
+}
+impl hipError_t {
+    pub const hipErrorAlreadyMapped: hipError_t = hipError_t(208);
+}
+impl hipError_t {
+    pub const hipErrorNoBinaryForGpu: hipError_t = hipError_t(209);
+}
+impl hipError_t {
+    pub const hipErrorAlreadyAcquired: hipError_t = hipError_t(210);
+}
+impl hipError_t {
+    pub const hipErrorNotMapped: hipError_t = hipError_t(211);
+}
+impl hipError_t {
+    pub const hipErrorNotMappedAsArray: hipError_t = hipError_t(212);
+}
+impl hipError_t {
+    pub const hipErrorNotMappedAsPointer: hipError_t = hipError_t(213);
+}
+impl hipError_t {
+    pub const hipErrorECCNotCorrectable: hipError_t = hipError_t(214);
+}
+impl hipError_t {
+    pub const hipErrorUnsupportedLimit: hipError_t = hipError_t(215);
+}
+impl hipError_t {
+    pub const hipErrorContextAlreadyInUse: hipError_t = hipError_t(216);
+}
+impl hipError_t {
+    pub const hipErrorPeerAccessUnsupported: hipError_t = hipError_t(217);
+}
+impl hipError_t {
     #[doc = "< In CUDA DRV, it is CUDA_ERROR_INVALID_PTX"]
-    hipErrorInvalidKernelFile = 218,
-    hipErrorInvalidGraphicsContext = 219,
-    hipErrorInvalidSource = 300,
-    hipErrorFileNotFound = 301,
-    hipErrorSharedObjectSymbolNotFound = 302,
-    hipErrorSharedObjectInitFailed = 303,
-    hipErrorOperatingSystem = 304,
-    hipErrorInvalidHandle = 400,
+    pub const hipErrorInvalidKernelFile: hipError_t = hipError_t(218);
+}
+impl hipError_t {
+    pub const hipErrorInvalidGraphicsContext: hipError_t = hipError_t(219);
+}
+impl hipError_t {
+    pub const hipErrorInvalidSource: hipError_t = hipError_t(300);
+}
+impl hipError_t {
+    pub const hipErrorFileNotFound: hipError_t = hipError_t(301);
+}
+impl hipError_t {
+    pub const hipErrorSharedObjectSymbolNotFound: hipError_t = hipError_t(302);
+}
+impl hipError_t {
+    pub const hipErrorSharedObjectInitFailed: hipError_t = hipError_t(303);
+}
+impl hipError_t {
+    pub const hipErrorOperatingSystem: hipError_t = hipError_t(304);
+}
+impl hipError_t {
+    pub const hipErrorInvalidHandle: hipError_t = hipError_t(400);
+}
+impl hipError_t {
     #[doc = "< Resource required is not in a valid state to perform operation."]
-    hipErrorIllegalState = 401,
-    hipErrorNotFound = 500,
+    pub const hipErrorIllegalState: hipError_t = hipError_t(401);
+}
+impl hipError_t {
+    pub const hipErrorNotFound: hipError_t = hipError_t(500);
+}
+impl hipError_t {
     #[doc = "< Indicates that asynchronous operations enqueued earlier are not\n< ready.  This is not actually an error, but is used to distinguish\n< from hipSuccess (which indicates completion).  APIs that return\n< this error include hipEventQuery and hipStreamQuery."]
-    hipErrorNotReady = 600,
-    hipErrorIllegalAddress = 700,
+    pub const hipErrorNotReady: hipError_t = hipError_t(600);
+}
+impl hipError_t {
+    pub const hipErrorIllegalAddress: hipError_t = hipError_t(700);
+}
+impl hipError_t {
     #[doc = "< Out of resources error."]
-    hipErrorLaunchOutOfResources = 701,
-    hipErrorLaunchTimeOut = 702,
-    hipErrorPeerAccessAlreadyEnabled = 704,
-    hipErrorPeerAccessNotEnabled = 705,
-    hipErrorSetOnActiveProcess = 708,
-    hipErrorContextIsDestroyed = 709,
+    pub const hipErrorLaunchOutOfResources: hipError_t = hipError_t(701);
+}
+impl hipError_t {
+    pub const hipErrorLaunchTimeOut: hipError_t = hipError_t(702);
+}
+impl hipError_t {
+    pub const hipErrorPeerAccessAlreadyEnabled: hipError_t = hipError_t(704);
+}
+impl hipError_t {
+    pub const hipErrorPeerAccessNotEnabled: hipError_t = hipError_t(705);
+}
+impl hipError_t {
+    pub const hipErrorSetOnActiveProcess: hipError_t = hipError_t(708);
+}
+impl hipError_t {
+    pub const hipErrorContextIsDestroyed: hipError_t = hipError_t(709);
+}
+impl hipError_t {
     #[doc = "< Produced when the kernel calls assert."]
-    hipErrorAssert = 710,
-    hipErrorHostMemoryAlreadyRegistered = 712,
-    hipErrorHostMemoryNotRegistered = 713,
-    hipErrorLaunchFailure = 719,
-    hipErrorCooperativeLaunchTooLarge = 720,
+    pub const hipErrorAssert: hipError_t = hipError_t(710);
+}
+impl hipError_t {
+    pub const hipErrorHostMemoryAlreadyRegistered: hipError_t = hipError_t(712);
+}
+impl hipError_t {
+    pub const hipErrorHostMemoryNotRegistered: hipError_t = hipError_t(713);
+}
+impl hipError_t {
+    pub const hipErrorLaunchFailure: hipError_t = hipError_t(719);
+}
+impl hipError_t {
+    pub const hipErrorCooperativeLaunchTooLarge: hipError_t = hipError_t(720);
+}
+impl hipError_t {
     #[doc = "< Produced when the hip API is not supported/implemented"]
-    hipErrorNotSupported = 801,
+    pub const hipErrorNotSupported: hipError_t = hipError_t(801);
+}
+impl hipError_t {
     #[doc = "< The operation is not permitted when the stream\n< is capturing."]
-    hipErrorStreamCaptureUnsupported = 900,
+    pub const hipErrorStreamCaptureUnsupported: hipError_t = hipError_t(900);
+}
+impl hipError_t {
     #[doc = "< The current capture sequence on the stream\n< has been invalidated due to a previous error."]
-    hipErrorStreamCaptureInvalidated = 901,
+    pub const hipErrorStreamCaptureInvalidated: hipError_t = hipError_t(901);
+}
+impl hipError_t {
     #[doc = "< The operation would have resulted in a merge of\n< two independent capture sequences."]
-    hipErrorStreamCaptureMerge = 902,
+    pub const hipErrorStreamCaptureMerge: hipError_t = hipError_t(902);
+}
+impl hipError_t {
     #[doc = "< The capture was not initiated in this stream."]
-    hipErrorStreamCaptureUnmatched = 903,
+    pub const hipErrorStreamCaptureUnmatched: hipError_t = hipError_t(903);
+}
+impl hipError_t {
     #[doc = "< The capture sequence contains a fork that was not\n< joined to the primary stream."]
-    hipErrorStreamCaptureUnjoined = 904,
+    pub const hipErrorStreamCaptureUnjoined: hipError_t = hipError_t(904);
+}
+impl hipError_t {
     #[doc = "< A dependency would have been created which crosses\n< the capture sequence boundary. Only implicit\n< in-stream ordering dependencies  are allowed\n< to cross the boundary"]
-    hipErrorStreamCaptureIsolation = 905,
+    pub const hipErrorStreamCaptureIsolation: hipError_t = hipError_t(905);
+}
+impl hipError_t {
     #[doc = "< The operation would have resulted in a disallowed\n< implicit dependency on a current capture sequence\n< from hipStreamLegacy."]
-    hipErrorStreamCaptureImplicit = 906,
+    pub const hipErrorStreamCaptureImplicit: hipError_t = hipError_t(906);
+}
+impl hipError_t {
     #[doc = "< The operation is not permitted on an event which was last\n< recorded in a capturing stream."]
-    hipErrorCapturedEvent = 907,
+    pub const hipErrorCapturedEvent: hipError_t = hipError_t(907);
+}
+impl hipError_t {
     #[doc = "< A stream capture sequence not initiated with\n< the hipStreamCaptureModeRelaxed argument to\n< hipStreamBeginCapture was passed to\n< hipStreamEndCapture in a different thread."]
-    hipErrorStreamCaptureWrongThread = 908,
+    pub const hipErrorStreamCaptureWrongThread: hipError_t = hipError_t(908);
+}
+impl hipError_t {
     #[doc = "< This error indicates that the graph update\n< not performed because it included changes which\n< violated constraintsspecific to instantiated graph\n< update."]
-    hipErrorGraphExecUpdateFailure = 910,
-    hipErrorUnknown = 999,
+    pub const hipErrorGraphExecUpdateFailure: hipError_t = hipError_t(910);
+}
+impl hipError_t {
+    pub const hipErrorUnknown: hipError_t = hipError_t(999);
+}
+impl hipError_t {
     #[doc = "< HSA runtime memory call returned error.  Typically not seen\n< in production systems."]
-    hipErrorRuntimeMemory = 1052,
+    pub const hipErrorRuntimeMemory: hipError_t = hipError_t(1052);
+}
+impl hipError_t {
     #[doc = "< HSA runtime call other than memory returned error.  Typically\n< not seen in production systems."]
-    hipErrorRuntimeOther = 1053,
+    pub const hipErrorRuntimeOther: hipError_t = hipError_t(1053);
+}
+impl hipError_t {
     #[doc = "< Marker that more error codes are needed."]
-    hipErrorTbd = 1054,
+    pub const hipErrorTbd: hipError_t = hipError_t(1054);
 }
+#[repr(transparent)]
+#[derive(Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct hipError_t(pub ::libc::c_uint);
 impl hipDeviceAttribute_t {
     pub const hipDeviceAttributeEccEnabled: hipDeviceAttribute_t =
         hipDeviceAttribute_t::hipDeviceAttributeCudaCompatibleBegin;
@@ -11959,6 +12105,17 @@ extern "C" {
         texObject: hipTextureObject_t,
     ) -> hipError_t;
 }
+extern "C" {
+    #[doc = " @brief Create a surface object.\n\n @param [out] pSurfObject  Pointer of surface object to be created.\n @param [in] pResDesc  Pointer of suface object descriptor.\n\n @returns #hipSuccess, #hipErrorInvalidValue\n"]
+    pub fn hipCreateSurfaceObject(
+        pSurfObject: *mut hipSurfaceObject_t,
+        pResDesc: *const hipResourceDesc,
+    ) -> hipError_t;
+}
+extern "C" {
+    #[doc = " @brief Destroy a surface object.\n\n @param [in] surfaceObject  Surface object to be destroyed.\n\n @returns #hipSuccess, #hipErrorInvalidValue\n"]
+    pub fn hipDestroySurfaceObject(surfaceObject: hipSurfaceObject_t) -> hipError_t;
+}
 extern "C" {
     #[doc = "  @addtogroup TextureD Texture Management [Deprecated]\n  @{\n  @ingroup Texture\n  This section describes the deprecated texture management functions of HIP runtime API.\n/\n/**\n @brief Gets the texture reference related with the symbol.\n\n @param [out] texref  texture reference\n @param [in] symbol  pointer to the symbol related with the texture for the reference\n\n @returns hipSuccess, hipErrorInvalidValue\n"]
     pub fn hipGetTextureReference(
@@ -13206,3 +13363,79 @@ extern "C" {
 pub struct __locale_data {
     pub _address: u8,
 }
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub enum hipDataType {
+    HIP_R_32F = 0,
+    HIP_R_64F = 1,
+    HIP_R_16F = 2,
+    HIP_R_8I = 3,
+    HIP_C_32F = 4,
+    HIP_C_64F = 5,
+    HIP_C_16F = 6,
+    HIP_C_8I = 7,
+    HIP_R_8U = 8,
+    HIP_C_8U = 9,
+    HIP_R_32I = 10,
+    HIP_C_32I = 11,
+    HIP_R_32U = 12,
+    HIP_C_32U = 13,
+    HIP_R_16BF = 14,
+    HIP_C_16BF = 15,
+    HIP_R_4I = 16,
+    HIP_C_4I = 17,
+    HIP_R_4U = 18,
+    HIP_C_4U = 19,
+    HIP_R_16I = 20,
+    HIP_C_16I = 21,
+    HIP_R_16U = 22,
+    HIP_C_16U = 23,
+    HIP_R_64I = 24,
+    HIP_C_64I = 25,
+    HIP_R_64U = 26,
+    HIP_C_64U = 27,
+    HIP_R_8F_E4M3 = 28,
+    HIP_R_8F_E5M2 = 29,
+    HIP_R_8F_E4M3_FNUZ = 1000,
+    HIP_R_8F_E5M2_FNUZ = 1001,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub enum hipLibraryPropertyType {
+    HIP_LIBRARY_MAJOR_VERSION = 0,
+    HIP_LIBRARY_MINOR_VERSION = 1,
+    HIP_LIBRARY_PATCH_LEVEL = 2,
+}
+#[doc = " \\brief Struct to represent a 16 bit brain floating point number."]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct hip_bfloat16 {
+    pub data: u16,
+}
+#[test]
+fn bindgen_test_layout_hip_bfloat16() {
+    const UNINIT: ::std::mem::MaybeUninit<hip_bfloat16> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<hip_bfloat16>(),
+        2usize,
+        concat!("Size of: ", stringify!(hip_bfloat16))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<hip_bfloat16>(),
+        2usize,
+        concat!("Alignment of ", stringify!(hip_bfloat16))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(hip_bfloat16),
+            "::",
+            stringify!(data)
+        )
+    );
+}
@@ -0,0 +1,650 @@
+//! Inspecting AMDGPU code objects and clang offload bundles without a GPU.
+//!
+//! A code object is a 64-bit little-endian ELF file for one GPU target, with
+//! its kernels described by a MessagePack note. An offload bundle, as built
+//! by `hipcc`, packs code objects for several targets (and usually an empty
+//! host entry) behind a `__CLANG_OFFLOAD_BUNDLE__` header.
+
+use crate::msgpack::{self, Value};
+use std::{convert::TryInto, fmt, str::FromStr};
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const BUNDLE_MAGIC: &[u8] = b"__CLANG_OFFLOAD_BUNDLE__";
+const COMPRESSED_BUNDLE_MAGIC: &[u8] = b"CCOB";
+
+const EM_AMDGPU: u16 = 224;
+const SHT_NOTE: u32 = 7;
+const NT_AMDGPU_METADATA: u32 = 32;
+
+// `EI_ABIVERSION` values for code object versions 3 and later.
+const ABI_VERSION_V3: u8 = 1;
+
+const EF_AMDGPU_MACH: u32 = 0x0ff;
+// Code object v3.
+const EF_AMDGPU_XNACK_V3: u32 = 0x100;
+const EF_AMDGPU_SRAMECC_V3: u32 = 0x200;
+// Code object v4 and later: unsupported, any, off or on.
+const EF_AMDGPU_XNACK_V4: u32 = 0x300;
+const EF_AMDGPU_SRAMECC_V4: u32 = 0xc00;
+
+/// An error from inspecting a code object or offload bundle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CodeObjectError {
+    /// The data is neither a code object nor an offload bundle.
+    UnknownFormat,
+    /// The data is a compressed offload bundle, which can't be inspected.
+    CompressedBundle,
+    /// The data ends before something it describes.
+    Truncated,
+    /// The data is an ELF file, but not a 64-bit little-endian AMDGPU one.
+    NotAmdgpu,
+    /// The GPU target couldn't be determined, or isn't a valid target ID.
+    UnknownTarget(String),
+    /// The kernel metadata is missing or malformed.
+    Metadata(String),
+}
+
+impl fmt::Display for CodeObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodeObjectError::UnknownFormat => {
+                write!(f, "Not an AMDGPU code object or offload bundle")
+            }
+            CodeObjectError::CompressedBundle => {
+                write!(f, "Compressed offload bundles can't be inspected")
+            }
+            CodeObjectError::Truncated => write!(f, "The code object is truncated"),
+            CodeObjectError::NotAmdgpu => {
+                write!(f, "Not a 64-bit little-endian AMDGPU ELF file")
+            }
+            CodeObjectError::UnknownTarget(target) => write!(f, "Unknown GPU target `{target}`"),
+            CodeObjectError::Metadata(message) => write!(f, "Invalid kernel metadata: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for CodeObjectError {}
+
+/// A GPU target ID: a processor and the target features it was built with,
+/// e.g. `gfx90a:xnack-`. A feature that isn't mentioned may be either.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TargetId {
+    processor: String,
+    // Sorted by name.
+    features: Vec<(String, bool)>,
+}
+
+impl TargetId {
+    /// The processor, e.g. `gfx90a`.
+    pub fn processor(&self) -> &str {
+        &self.processor
+    }
+
+    /// The features that are on or off, sorted by name.
+    pub fn features(&self) -> &[(String, bool)] {
+        &self.features
+    }
+
+    /// Whether `feature`, e.g. `xnack`, is on or off, or `None` if either
+    /// will do.
+    pub fn feature(&self, feature: &str) -> Option<bool> {
+        self.features
+            .iter()
+            .find(|(name, _)| name == feature)
+            .map(|&(_, on)| on)
+    }
+
+    /// Whether code built for this target runs on a device whose target is
+    /// `device`, as reported by [`Device::gcn_arch_name`](crate::Device::gcn_arch_name).
+    pub fn is_compatible_with(&self, device: &TargetId) -> bool {
+        self.processor == device.processor
+            && self.features.iter().all(|(name, on)| {
+                device
+                    .feature(name)
+                    .map_or(true, |device_on| device_on == *on)
+            })
+    }
+
+    /// The target in a triple such as `amdgcn-amd-amdhsa--gfx90a:xnack-`,
+    /// or `None` if it isn't an AMDGPU triple.
+    fn from_triple(triple: &str) -> Option<Result<TargetId, CodeObjectError>> {
+        let parts: Vec<&str> = triple.splitn(5, '-').collect();
+        let id = match parts[..] {
+            ["amdgcn", _, _, _, id] => id,
+            // The older form, without an environment.
+            ["amdgcn", _, _, id] if id.starts_with("gfx") => id,
+            _ => return None,
+        };
+        Some(id.parse())
+    }
+
+    /// The target recorded in an ELF header's `e_flags`.
+    fn from_elf_flags(abi_version: u8, flags: u32) -> Result<TargetId, CodeObjectError> {
+        let processor = processor_name(flags & EF_AMDGPU_MACH)
+            .ok_or_else(|| CodeObjectError::UnknownTarget(format!("e_flags {flags:#x}")))?;
+        let mut features = Vec::new();
+        if abi_version == ABI_VERSION_V3 {
+            features.push(("sramecc".to_owned(), flags & EF_AMDGPU_SRAMECC_V3 != 0));
+            features.push(("xnack".to_owned(), flags & EF_AMDGPU_XNACK_V3 != 0));
+        } else {
+            // Bit patterns for "off" and "on"; anything else is "any".
+            let setting = |mask: u32, off: u32| match flags & mask {
+                value if value == off => Some(false),
+                value if value == mask => Some(true),
+                _ => None,
+            };
+            for (name, on) in [
+                ("sramecc", setting(EF_AMDGPU_SRAMECC_V4, 0x800)),
+                ("xnack", setting(EF_AMDGPU_XNACK_V4, 0x200)),
+            ] {
+                if let Some(on) = on {
+                    features.push((name.to_owned(), on));
+                }
+            }
+        }
+        Ok(TargetId {
+            processor: processor.to_owned(),
+            features,
+        })
+    }
+}
+
+impl FromStr for TargetId {
+    type Err = CodeObjectError;
+
+    /// Parse a target ID such as `gfx942:sramecc+:xnack-`.
+    fn from_str(s: &str) -> Result<TargetId, CodeObjectError> {
+        let invalid = || CodeObjectError::UnknownTarget(s.to_owned());
+        let mut parts = s.split(':');
+        let processor = parts.next().filter(|p| !p.is_empty()).ok_or_else(invalid)?;
+        let mut features = Vec::new();
+        for feature in parts {
+            let on = match feature.as_bytes().last() {
+                Some(b'+') => true,
+                Some(b'-') => false,
+                _ => return Err(invalid()),
+            };
+            let name = &feature[..feature.len() - 1];
+            if name.is_empty() || features.iter().any(|(n, _)| n == name) {
+                return Err(invalid());
+            }
+            features.push((name.to_owned(), on));
+        }
+        features.sort();
+        Ok(TargetId {
+            processor: processor.to_owned(),
+            features,
+        })
+    }
+}
+
+impl fmt::Display for TargetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.processor)?;
+        for (name, on) in &self.features {
+            write!(f, ":{}{}", name, if *on { '+' } else { '-' })?;
+        }
+        Ok(())
+    }
+}
+
+/// What a code object's metadata says about one of its kernels.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KernelMetadata {
+    /// The kernel's name, as passed to [`Module::function`](crate::Module::function).
+    pub name: String,
+    /// The symbol of the kernel descriptor, usually the name with `.kd`
+    /// appended.
+    pub symbol: String,
+    /// The size of the kernel arguments, including hidden ones, in bytes.
+    pub kernarg_size: u32,
+    /// The alignment of the kernel arguments, in bytes.
+    pub kernarg_align: u32,
+    /// The static LDS (shared memory) per work-group, in bytes.
+    pub lds_size: u32,
+    /// The static scratch memory per work-item, in bytes.
+    pub scratch_size: u32,
+    /// The number of scalar registers per wavefront.
+    pub sgpr_count: u32,
+    /// The number of vector registers per work-item.
+    pub vgpr_count: u32,
+    /// The number of accumulation registers per work-item; zero on targets
+    /// without them.
+    pub agpr_count: u32,
+    /// The number of scalar registers spilled to memory.
+    pub sgpr_spill_count: u32,
+    /// The number of vector registers spilled to memory.
+    pub vgpr_spill_count: u32,
+    /// The number of work-items in a wavefront.
+    pub wavefront_size: u32,
+    /// The most work-items the kernel can be launched with per work-group.
+    pub max_flat_workgroup_size: u32,
+    /// The arguments, including hidden ones, in order.
+    pub args: Vec<KernelArgMetadata>,
+}
+
+impl KernelMetadata {
+    /// The arguments the caller passes, without the hidden ones the
+    /// runtime fills in.
+    pub fn explicit_args(&self) -> impl Iterator<Item = &KernelArgMetadata> {
+        self.args.iter().filter(|arg| !arg.kind.is_hidden())
+    }
+
+    fn from_value(value: &Value) -> Result<KernelMetadata, CodeObjectError> {
+        let name = str_field(value, ".name")
+            .ok_or_else(|| CodeObjectError::Metadata("kernel without `.name`".to_owned()))?;
+        let owner = format!("`{name}`");
+        let field = |key: &str| u32_field(value, key, &owner);
+        let required = |key: &str| required(field(key)?, key, &owner);
+        let args = match value.get(".args") {
+            Some(args) => args
+                .as_array()
+                .ok_or_else(|| CodeObjectError::Metadata(format!("`{name}` has a bad `.args`")))?
+                .iter()
+                .map(|arg| KernelArgMetadata::from_value(arg, &name))
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+        Ok(KernelMetadata {
+            symbol: required_str(value, ".symbol", &owner)?,
+            kernarg_size: required(".kernarg_segment_size")?,
+            kernarg_align: required(".kernarg_segment_align")?,
+            lds_size: required(".group_segment_fixed_size")?,
+            scratch_size: required(".private_segment_fixed_size")?,
+            sgpr_count: required(".sgpr_count")?,
+            vgpr_count: required(".vgpr_count")?,
+            agpr_count: field(".agpr_count")?.unwrap_or(0),
+            sgpr_spill_count: field(".sgpr_spill_count")?.unwrap_or(0),
+            vgpr_spill_count: field(".vgpr_spill_count")?.unwrap_or(0),
+            wavefront_size: required(".wavefront_size")?,
+            max_flat_workgroup_size: required(".max_flat_workgroup_size")?,
+            args,
+            name,
+        })
+    }
+}
+
+/// How a kernel argument is passed, from its `.value_kind`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArgKind {
+    /// A value copied into the arguments.
+    ByValue,
+    /// A pointer to global memory.
+    GlobalBuffer,
+    /// A pointer to dynamically allocated LDS.
+    DynamicSharedPointer,
+    /// An image handle.
+    Image,
+    /// A sampler handle.
+    Sampler,
+    /// A pipe handle.
+    Pipe,
+    /// A device queue handle.
+    Queue,
+    /// An argument the runtime fills in, e.g. `hidden_global_offset_x`, by
+    /// the part of its kind after `hidden_`.
+    Hidden(String),
+    /// A kind this crate doesn't know about.
+    Other(String),
+}
+
+impl ArgKind {
+    fn from_name(name: &str) -> ArgKind {
+        match name {
+            "by_value" => ArgKind::ByValue,
+            "global_buffer" => ArgKind::GlobalBuffer,
+            "dynamic_shared_pointer" => ArgKind::DynamicSharedPointer,
+            "image" => ArgKind::Image,
+            "sampler" => ArgKind::Sampler,
+            "pipe" => ArgKind::Pipe,
+            "queue" => ArgKind::Queue,
+            name => match name.strip_prefix("hidden_") {
+                Some(hidden) => ArgKind::Hidden(hidden.to_owned()),
+                None => ArgKind::Other(name.to_owned()),
+            },
+        }
+    }
+
+    /// Whether the runtime fills the argument in rather than the caller.
+    pub fn is_hidden(&self) -> bool {
+        matches!(self, ArgKind::Hidden(_))
+    }
+
+    /// Whether the argument is a pointer to device memory.
+    pub fn is_pointer(&self) -> bool {
+        matches!(self, ArgKind::GlobalBuffer | ArgKind::DynamicSharedPointer)
+    }
+}
+
+/// What a code object's metadata says about one argument of a kernel.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KernelArgMetadata {
+    /// The parameter's name in the source, if it was recorded.
+    pub name: Option<String>,
+    /// The parameter's type in the source, if it was recorded.
+    pub type_name: Option<String>,
+    /// The offset of the argument from the start of the arguments, in
+    /// bytes.
+    pub offset: u32,
+    /// The size of the argument, in bytes.
+    pub size: u32,
+    /// How the argument is passed.
+    pub kind: ArgKind,
+}
+
+impl KernelArgMetadata {
+    fn from_value(value: &Value, kernel: &str) -> Result<KernelArgMetadata, CodeObjectError> {
+        let owner = format!("an argument of `{kernel}`");
+        Ok(KernelArgMetadata {
+            name: str_field(value, ".name"),
+            type_name: str_field(value, ".type_name"),
+            offset: required(u32_field(value, ".offset", &owner)?, ".offset", &owner)?,
+            size: required(u32_field(value, ".size", &owner)?, ".size", &owner)?,
+            kind: ArgKind::from_name(&required_str(value, ".value_kind", &owner)?),
+        })
+    }
+}
+
+fn str_field(map: &Value, key: &str) -> Option<String> {
+    map.get(key).and_then(Value::as_str).map(str::to_owned)
+}
+
+fn required_str(map: &Value, key: &str, owner: &str) -> Result<String, CodeObjectError> {
+    required(str_field(map, key), key, owner)
+}
+
+/// The `u32` at `key` in the metadata of `owner`, if there is one.
+fn u32_field(map: &Value, key: &str, owner: &str) -> Result<Option<u32>, CodeObjectError> {
+    map.get(key)
+        .map(|field| {
+            field
+                .as_u64()
+                .and_then(|n| n.try_into().ok())
+                .ok_or_else(|| CodeObjectError::Metadata(format!("{owner} has a bad `{key}`")))
+        })
+        .transpose()
+}
+
+fn required<T>(field: Option<T>, key: &str, owner: &str) -> Result<T, CodeObjectError> {
+    field.ok_or_else(|| CodeObjectError::Metadata(format!("{owner} has no `{key}`")))
+}
+
+/// An AMDGPU code object for a single target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeObject {
+    /// The target the code was built for.
+    pub target: TargetId,
+    /// The kernels, in the order the metadata lists them.
+    pub kernels: Vec<KernelMetadata>,
+}
+
+impl CodeObject {
+    /// Parse a code object, e.g. an `.hsaco` file.
+    pub fn parse(data: &[u8]) -> Result<CodeObject, CodeObjectError> {
+        if !data.starts_with(ELF_MAGIC) {
+            return Err(CodeObjectError::UnknownFormat);
+        }
+        let elf = Elf::parse(data)?;
+        let metadata = elf.metadata()?;
+        let target = match metadata.get("amdhsa.target").and_then(Value::as_str) {
+            Some(triple) => TargetId::from_triple(triple)
+                .unwrap_or_else(|| Err(CodeObjectError::UnknownTarget(triple.to_owned())))?,
+            // Version 3 metadata only records the target in the header.
+            None => TargetId::from_elf_flags(elf.abi_version, elf.flags)?,
+        };
+        let kernels = metadata
+            .get("amdhsa.kernels")
+            .and_then(Value::as_array)
+            .ok_or_else(|| CodeObjectError::Metadata("no `amdhsa.kernels`".to_owned()))?
+            .iter()
+            .map(KernelMetadata::from_value)
+            .collect::<Result<_, _>>()?;
+        Ok(CodeObject { target, kernels })
+    }
+
+    /// Every code object in `image`, which is either a single code object
+    /// or an offload bundle.
+    pub fn from_image(image: &[u8]) -> Result<Vec<CodeObject>, CodeObjectError> {
+        if image.starts_with(ELF_MAGIC) {
+            Ok(vec![CodeObject::parse(image)?])
+        } else {
+            OffloadBundle::parse(image)?.code_objects()
+        }
+    }
+
+    /// The kernel called `name`.
+    pub fn kernel(&self, name: &str) -> Option<&KernelMetadata> {
+        self.kernels.iter().find(|kernel| kernel.name == name)
+    }
+}
+
+/// One entry of an [`OffloadBundle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BundleEntry<'a> {
+    /// The offload kind and target, e.g.
+    /// `hipv4-amdgcn-amd-amdhsa--gfx90a:xnack-`.
+    pub triple: &'a str,
+    /// The entry's contents.
+    pub data: &'a [u8],
+}
+
+impl BundleEntry<'_> {
+    /// The GPU target of the entry, or `None` for the host and anything else
+    /// that isn't AMDGPU code.
+    pub fn target(&self) -> Option<Result<TargetId, CodeObjectError>> {
+        let (_kind, triple) = self.triple.split_once('-')?;
+        TargetId::from_triple(triple)
+    }
+}
+
+/// A clang offload bundle, the fat-binary format `hipcc` embeds device code
+/// in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OffloadBundle<'a> {
+    entries: Vec<BundleEntry<'a>>,
+}
+
+impl<'a> OffloadBundle<'a> {
+    /// Parse an uncompressed offload bundle.
+    pub fn parse(data: &'a [u8]) -> Result<OffloadBundle<'a>, CodeObjectError> {
+        if data.starts_with(COMPRESSED_BUNDLE_MAGIC) {
+            return Err(CodeObjectError::CompressedBundle);
+        }
+        if !data.starts_with(BUNDLE_MAGIC) {
+            return Err(CodeObjectError::UnknownFormat);
+        }
+        let mut pos = BUNDLE_MAGIC.len();
+        let count = read_u64(data, pos)?;
+        pos += 8;
+        // Each entry's header takes at least 24 bytes, which bounds the
+        // count before anything is allocated for it.
+        if count > (data.len() / 24) as u64 {
+            return Err(CodeObjectError::Truncated);
+        }
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let offset = read_u64(data, pos)?;
+            let size = read_u64(data, pos + 8)?;
+            let triple_len = read_u64(data, pos + 16)?;
+            pos += 24;
+            let triple = slice(data, pos as u64, triple_len)?;
+            pos += triple.len();
+            let triple = std::str::from_utf8(triple).map_err(|_| {
+                CodeObjectError::UnknownTarget(String::from_utf8_lossy(triple).into())
+            })?;
+            entries.push(BundleEntry {
+                triple,
+                data: slice(data, offset, size)?,
+            });
+        }
+        Ok(OffloadBundle { entries })
+    }
+
+    /// The entries, in the order they're stored.
+    pub fn entries(&self) -> &[BundleEntry<'a>] {
+        &self.entries
+    }
+
+    /// The GPU targets the bundle has code for.
+    pub fn targets(&self) -> Result<Vec<TargetId>, CodeObjectError> {
+        self.entries
+            .iter()
+            .filter_map(BundleEntry::target)
+            .collect()
+    }
+
+    /// Parse the code object of every GPU target.
+    pub fn code_objects(&self) -> Result<Vec<CodeObject>, CodeObjectError> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.target().is_some())
+            .map(|entry| CodeObject::parse(entry.data))
+            .collect()
+    }
+}
+
+/// The parts of an ELF file needed to find the metadata.
+struct Elf<'a> {
+    data: &'a [u8],
+    abi_version: u8,
+    flags: u32,
+    section_headers: &'a [u8],
+    section_header_size: usize,
+}
+
+impl<'a> Elf<'a> {
+    fn parse(data: &'a [u8]) -> Result<Elf<'a>, CodeObjectError> {
+        const ELFCLASS64: u8 = 2;
+        const ELFDATA2LSB: u8 = 1;
+        let ident = slice(data, 0, 16)?;
+        if ident[4] != ELFCLASS64 || ident[5] != ELFDATA2LSB {
+            return Err(CodeObjectError::NotAmdgpu);
+        }
+        if read_u16(data, 0x12)? != EM_AMDGPU {
+            return Err(CodeObjectError::NotAmdgpu);
+        }
+        let section_header_size = usize::from(read_u16(data, 0x3a)?);
+        if section_header_size < 0x40 {
+            return Err(CodeObjectError::NotAmdgpu);
+        }
+        let count = u64::from(read_u16(data, 0x3c)?);
+        let section_headers = slice(
+            data,
+            read_u64(data, 0x28)?,
+            count * section_header_size as u64,
+        )?;
+        Ok(Elf {
+            data,
+            abi_version: ident[8],
+            flags: read_u32(data, 0x30)?,
+            section_headers,
+            section_header_size,
+        })
+    }
+
+    /// The contents of every section of type `kind`.
+    fn sections(&self, kind: u32) -> impl Iterator<Item = Result<&'a [u8], CodeObjectError>> + '_ {
+        self.section_headers
+            .chunks_exact(self.section_header_size)
+            .filter(move |header| read_u32(header, 0x04) == Ok(kind))
+            .map(move |header| slice(self.data, read_u64(header, 0x18)?, read_u64(header, 0x20)?))
+    }
+
+    /// The decoded AMDGPU metadata note.
+    fn metadata(&self) -> Result<Value, CodeObjectError> {
+        for section in self.sections(SHT_NOTE) {
+            let section = section?;
+            let mut pos = 0;
+            while pos < section.len() {
+                let name_len = read_u32(section, pos)? as u64;
+                let desc_len = read_u32(section, pos + 4)? as u64;
+                let kind = read_u32(section, pos + 8)?;
+                let name = slice(section, pos as u64 + 12, name_len)?;
+                let desc_pos = pos as u64 + 12 + align4(name_len);
+                let desc = slice(section, desc_pos, desc_len)?;
+                if name == b"AMDGPU\0" && kind == NT_AMDGPU_METADATA {
+                    return msgpack::decode(desc).map_err(CodeObjectError::Metadata);
+                }
+                pos = (desc_pos + align4(desc_len)) as usize;
+            }
+        }
+        Err(CodeObjectError::Metadata(
+            "no AMDGPU metadata note".to_owned(),
+        ))
+    }
+}
+
+/// The name of an `EF_AMDGPU_MACH` processor.
+fn processor_name(mach: u32) -> Option<&'static str> {
+    Some(match mach {
+        0x020 => "gfx600",
+        0x021 => "gfx601",
+        0x022 => "gfx700",
+        0x023 => "gfx701",
+        0x024 => "gfx702",
+        0x025 => "gfx703",
+        0x026 => "gfx704",
+        0x028 => "gfx801",
+        0x029 => "gfx802",
+        0x02a => "gfx803",
+        0x02b => "gfx810",
+        0x02c => "gfx900",
+        0x02d => "gfx902",
+        0x02e => "gfx904",
+        0x02f => "gfx906",
+        0x030 => "gfx908",
+        0x031 => "gfx909",
+        0x032 => "gfx90c",
+        0x033 => "gfx1010",
+        0x034 => "gfx1011",
+        0x035 => "gfx1012",
+        0x036 => "gfx1030",
+        0x037 => "gfx1031",
+        0x038 => "gfx1032",
+        0x039 => "gfx1033",
+        0x03a => "gfx602",
+        0x03b => "gfx705",
+        0x03c => "gfx805",
+        0x03d => "gfx1035",
+        0x03e => "gfx1034",
+        0x03f => "gfx90a",
+        0x040 => "gfx940",
+        0x041 => "gfx1100",
+        0x042 => "gfx1013",
+        0x044 => "gfx1103",
+        0x045 => "gfx1036",
+        0x046 => "gfx1101",
+        0x047 => "gfx1102",
+        0x04b => "gfx941",
+        0x04c => "gfx942",
+        _ => return None,
+    })
+}
+
+fn align4(len: u64) -> u64 {
+    (len + 3) & !3
+}
+
+/// The `len` bytes at `offset`, failing if they're out of bounds.
+fn slice(data: &[u8], offset: u64, len: u64) -> Result<&[u8], CodeObjectError> {
+    let end = offset.checked_add(len).ok_or(CodeObjectError::Truncated)?;
+    if end > data.len() as u64 {
+        return Err(CodeObjectError::Truncated);
+    }
+    Ok(&data[offset as usize..end as usize])
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, CodeObjectError> {
+    let bytes = slice(data, offset as u64, 2)?;
+    Ok(u16::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, CodeObjectError> {
+    let bytes = slice(data, offset as u64, 4)?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, CodeObjectError> {
+    let bytes = slice(data, offset as u64, 8)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
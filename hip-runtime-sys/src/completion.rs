@@ -0,0 +1,117 @@
+//! Futures that resolve when device work completes, for use from async code
+//! without blocking an executor thread.
+
+use crate::{hipStreamNonBlocking, Event, Result, Stream};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// A future that resolves once the work queued on a stream before it was
+/// created has completed. Created by [`Stream::completion`] and
+/// [`Event::wait_async`].
+///
+/// The runtime wakes the task from a host callback, so this works with any
+/// executor.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct Completion {
+    shared: Arc<Shared>,
+    // Keeps a stream created just for this future alive until it's done.
+    _stream: Option<Stream>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    result: Option<Result<()>>,
+    waker: Option<Waker>,
+}
+
+impl Shared {
+    fn complete(&self, result: Result<()>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.result = Some(result);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl Completion {
+    fn new(stream: &Stream) -> Completion {
+        let shared = Arc::new(Shared::default());
+        let callback_shared = shared.clone();
+        if let Err(e) = stream.add_callback(move |status| callback_shared.complete(status)) {
+            shared.complete(Err(e));
+        }
+        Completion {
+            shared,
+            _stream: None,
+        }
+    }
+
+    fn ready(result: Result<()>) -> Completion {
+        let shared = Arc::new(Shared::default());
+        shared.complete(result);
+        Completion {
+            shared,
+            _stream: None,
+        }
+    }
+}
+
+impl Future for Completion {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if let Some(result) = state.result {
+            return Poll::Ready(result);
+        }
+        match &state.waker {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => state.waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+impl Stream {
+    /// A future that resolves once the work queued on the stream so far
+    /// completes, with the stream's status.
+    pub fn completion(&self) -> Completion {
+        Completion::new(self)
+    }
+}
+
+impl Event {
+    /// A future that resolves once the event completes. The wait happens on
+    /// a new stream on the event's device.
+    pub fn wait_async(&self) -> Completion {
+        let stream = self.device().scope(|| -> Result<Stream> {
+            let stream = Stream::with_flags(hipStreamNonBlocking)?;
+            stream.wait_event(self)?;
+            Ok(stream)
+        });
+        match stream {
+            Ok(Ok(stream)) => {
+                let completion = Completion::new(&stream);
+                Completion {
+                    _stream: Some(stream),
+                    ..completion
+                }
+            }
+            Ok(Err(e)) | Err(e) => Completion::ready(Err(e)),
+        }
+    }
+}
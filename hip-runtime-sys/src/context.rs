@@ -0,0 +1,226 @@
+//! Contexts, for code that switches devices without disturbing its
+//! caller's current device.
+//!
+//! Each device has a primary context, which the runtime API uses. A thread
+//! has a stack of contexts, and the one on top decides its current device.
+//! Pushing a context with a [`ContextGuard`] switches to its device until
+//! the guard is dropped.
+
+use crate::{
+    hipCtxCreate, hipCtxDestroy, hipCtxPopCurrent, hipCtxPushCurrent, hipCtx_t,
+    hipDevicePrimaryCtxGetState, hipDevicePrimaryCtxRelease, hipDevicePrimaryCtxReset,
+    hipDevicePrimaryCtxRetain, hipDevicePrimaryCtxSetFlags, hipError_t, Device, HipError, Result,
+};
+use std::{marker::PhantomData, ptr};
+
+flags! {
+    /// Flags for a context. Without a scheduling flag, the runtime picks
+    /// how to wait.
+    pub struct ContextFlags {
+        /// Spin while waiting for the device: the lowest latency, at the
+        /// cost of a CPU core.
+        const SCHEDULE_SPIN = hipDeviceScheduleSpin;
+        /// Yield the CPU while waiting for the device.
+        const SCHEDULE_YIELD = hipDeviceScheduleYield;
+        /// Block while waiting for the device. The same as yielding on
+        /// ROCm.
+        const SCHEDULE_BLOCKING_SYNC = hipDeviceScheduleBlockingSync;
+        /// Allow mapping host memory. Always allowed on ROCm.
+        const MAP_HOST = hipDeviceMapHost;
+        /// Keep local memory at its largest size. Ignored on ROCm.
+        const LMEM_RESIZE_TO_MAX = hipDeviceLmemResizeToMax;
+    }
+}
+
+/// A context created for a device, destroyed on drop.
+#[derive(Debug)]
+pub struct Context {
+    raw: hipCtx_t,
+    device: Device,
+}
+
+// Safety: a context can be pushed on, and destroyed from, any thread.
+unsafe impl Send for Context {}
+unsafe impl Sync for Context {}
+
+impl Context {
+    /// Create a context for `device`. Unlike `hipCtxCreate`, this leaves
+    /// the calling thread's contexts as they were.
+    pub fn new(device: Device, flags: ContextFlags) -> Result<Context> {
+        let previous = Device::current()?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipCtxCreate(&mut raw, flags.bits(), device.ordinal()) }.into_result()?;
+        let context = Context { raw, device };
+        // `hipCtxCreate` pushed the new context.
+        let mut popped = ptr::null_mut();
+        unsafe { hipCtxPopCurrent(&mut popped) }.into_result()?;
+        previous.set_current()?;
+        Ok(context)
+    }
+
+    /// Take ownership of a raw context.
+    ///
+    /// # Safety
+    /// `raw` must be a valid context for `device` that nothing else will
+    /// destroy.
+    pub unsafe fn from_raw(raw: hipCtx_t, device: Device) -> Context {
+        Context { raw, device }
+    }
+
+    /// The raw context, which remains owned by this `Context`.
+    pub fn as_raw(&self) -> hipCtx_t {
+        self.raw
+    }
+
+    /// Give up ownership of the raw context without destroying it.
+    pub fn into_raw(self) -> hipCtx_t {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// The device the context is for.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Make this the calling thread's context, and its device current,
+    /// until the guard is dropped.
+    pub fn push(&self) -> Result<ContextGuard<'_>> {
+        ContextGuard::push(self.raw)
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe { hipCtxDestroy(self.raw) };
+    }
+}
+
+/// A reference to a device's primary context, which the runtime API uses.
+/// Keeps the context alive until dropped.
+#[derive(Debug)]
+pub struct PrimaryContext {
+    raw: hipCtx_t,
+    device: Device,
+}
+
+// Safety: the reference can be released from any thread.
+unsafe impl Send for PrimaryContext {}
+unsafe impl Sync for PrimaryContext {}
+
+impl PrimaryContext {
+    /// Retain `device`'s primary context, creating it if needed.
+    pub fn retain(device: Device) -> Result<PrimaryContext> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipDevicePrimaryCtxRetain(&mut raw, device.ordinal()) }.into_result()?;
+        Ok(PrimaryContext { raw, device })
+    }
+
+    /// The flags of `device`'s primary context, and whether it's active.
+    pub fn state(device: Device) -> Result<(ContextFlags, bool)> {
+        let mut flags = 0;
+        let mut active = 0;
+        unsafe { hipDevicePrimaryCtxGetState(device.ordinal(), &mut flags, &mut active) }
+            .into_result()?;
+        Ok((ContextFlags::from_bits(flags), active != 0))
+    }
+
+    /// Set the flags of `device`'s primary context.
+    pub fn set_flags(device: Device, flags: ContextFlags) -> Result<()> {
+        unsafe { hipDevicePrimaryCtxSetFlags(device.ordinal(), flags.bits()) }.into_result()
+    }
+
+    /// Destroy every allocation and all other state in `device`'s primary
+    /// context.
+    ///
+    /// # Safety
+    /// Nothing that lives in the context, such as a
+    /// [`DeviceBuffer`](crate::DeviceBuffer), [`Stream`](crate::Stream) or
+    /// [`Module`](crate::Module) on the device, may be used or dropped
+    /// afterwards.
+    pub unsafe fn reset(device: Device) -> Result<()> {
+        hipDevicePrimaryCtxReset(device.ordinal()).into_result()
+    }
+
+    /// The raw context, which stays retained by this `PrimaryContext`.
+    pub fn as_raw(&self) -> hipCtx_t {
+        self.raw
+    }
+
+    /// The device the context belongs to.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Make this the calling thread's context, and its device current,
+    /// until the guard is dropped.
+    pub fn push(&self) -> Result<ContextGuard<'_>> {
+        ContextGuard::push(self.raw)
+    }
+}
+
+impl Drop for PrimaryContext {
+    fn drop(&mut self) {
+        unsafe { hipDevicePrimaryCtxRelease(self.device.ordinal()) };
+    }
+}
+
+/// A context pushed onto the calling thread's stack by
+/// [`Context::push`] or [`PrimaryContext::push`]. Dropping it pops the
+/// context and makes the device that was current before the push current
+/// again, even if the stack is then empty.
+///
+/// Guards must be dropped in the reverse order they were pushed in, so that
+/// each pops its own context. If another context is on top of the stack, the
+/// guard leaves the stack as it is, and [`ContextGuard::pop`] fails with
+/// `hipErrorInvalidContext`.
+#[derive(Debug)]
+#[must_use = "the context is popped when the guard is dropped"]
+pub struct ContextGuard<'c> {
+    raw: hipCtx_t,
+    previous: Device,
+    // Borrows the context, and must be dropped on the thread that pushed it.
+    _context: PhantomData<&'c ()>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl ContextGuard<'_> {
+    fn push<'c>(raw: hipCtx_t) -> Result<ContextGuard<'c>> {
+        let previous = Device::current()?;
+        unsafe { hipCtxPushCurrent(raw) }.into_result()?;
+        Ok(ContextGuard {
+            raw,
+            previous,
+            _context: PhantomData,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Pop the context now, reporting any error that dropping the guard
+    /// would ignore.
+    pub fn pop(self) -> Result<()> {
+        let result = self.pop_and_restore();
+        std::mem::forget(self);
+        result
+    }
+
+    fn pop_and_restore(&self) -> Result<()> {
+        let mut popped = ptr::null_mut();
+        unsafe { hipCtxPopCurrent(&mut popped) }.into_result()?;
+        if popped != self.raw {
+            // Another guard's context was on top; put it back.
+            unsafe { hipCtxPushCurrent(popped) }.into_result()?;
+            return Err(HipError::from(hipError_t::hipErrorInvalidContext));
+        }
+        // The runtime only switches devices on a pop if contexts remain on
+        // the stack.
+        self.previous.set_current()
+    }
+}
+
+impl Drop for ContextGuard<'_> {
+    fn drop(&mut self) {
+        let _ = self.pop_and_restore();
+    }
+}
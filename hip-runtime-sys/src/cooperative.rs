@@ -0,0 +1,385 @@
+//! Cooperative launches, whose blocks all run at once and can synchronize
+//! with each other, and launches of kernels on several devices together.
+
+use crate::{
+    attribute, hipError_t, hipExtLaunchMultiKernelMultiDevice, hipFuncAttributes,
+    hipFuncGetAttributes, hipLaunchCooperativeKernel, hipLaunchCooperativeKernelMultiDevice,
+    hipLaunchParams, hipOccupancyMaxActiveBlocksPerMultiprocessor, Device, HipError,
+    KernelArgBuffer, KernelArgs, LaunchConfig, LaunchLimits, Result, Stream,
+};
+use std::{convert::TryFrom, error::Error, ffi::c_void, fmt, mem::MaybeUninit, ptr};
+
+/// A kernel compiled into this program, identified by its host-side stub:
+/// the address HIP C++ code gets by taking the address of a `__global__`
+/// function.
+///
+/// HIP's cooperative and multi-device launches only accept kernels like
+/// this, not [`Function`](crate::Function)s loaded from a
+/// [`Module`](crate::Module).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HostKernel {
+    stub: *const c_void,
+}
+
+// Safety: the stub is only an identifier, which HIP looks up on any thread.
+unsafe impl Send for HostKernel {}
+unsafe impl Sync for HostKernel {}
+
+impl HostKernel {
+    /// The kernel whose host-side stub is at `stub`.
+    ///
+    /// # Safety
+    /// `stub` must be the address of a `__global__` function registered
+    /// with the HIP runtime by code linked into this program.
+    pub unsafe fn from_stub(stub: *const c_void) -> HostKernel {
+        HostKernel { stub }
+    }
+
+    /// The address of the host-side stub.
+    pub fn as_ptr(self) -> *const c_void {
+        self.stub
+    }
+
+    /// The limits of the kernel on `device`: the device's, narrowed by the
+    /// kernel's own block size limit and static shared memory.
+    pub fn limits(self, device: Device) -> Result<LaunchLimits> {
+        let mut limits = LaunchLimits::of(device)?;
+        let mut attributes = MaybeUninit::<hipFuncAttributes>::zeroed();
+        device
+            .scope(|| unsafe { hipFuncGetAttributes(attributes.as_mut_ptr(), self.stub) })?
+            .into_result()?;
+        let attributes = unsafe { attributes.assume_init() };
+        if attributes.maxThreadsPerBlock > 0 {
+            limits.max_threads_per_block = limits
+                .max_threads_per_block
+                .min(attributes.maxThreadsPerBlock as u32);
+        }
+        limits.max_shared_mem = limits
+            .max_shared_mem
+            .saturating_sub(attributes.sharedSizeBytes as u32);
+        Ok(limits)
+    }
+
+    /// How many blocks of `block_size` threads, each with `shared_mem` bytes
+    /// of dynamic shared memory, fit on a compute unit of `device` at once.
+    pub fn max_active_blocks(
+        self,
+        device: Device,
+        block_size: u32,
+        shared_mem: u32,
+    ) -> Result<u32> {
+        let mut blocks = 0;
+        device
+            .scope(|| unsafe {
+                hipOccupancyMaxActiveBlocksPerMultiprocessor(
+                    &mut blocks,
+                    self.stub,
+                    block_size as i32,
+                    shared_mem as usize,
+                )
+            })?
+            .into_result()?;
+        Ok(blocks.max(0) as u32)
+    }
+
+    /// The most blocks a cooperative launch shaped like `config` can have
+    /// on `device`: as many as fit on all its compute units at once.
+    pub fn max_cooperative_blocks(self, device: Device, config: &LaunchConfig<'_>) -> Result<u64> {
+        let block_size = u32::try_from(config.threads_per_block()).unwrap_or(u32::MAX);
+        let per_cu = self.max_active_blocks(device, block_size, config.shared_mem)?;
+        let cus = device.attribute::<attribute::MultiprocessorCount>()?;
+        Ok(u64::from(per_cu) * cus.max(0) as u64)
+    }
+
+    /// Check that a cooperative launch shaped like `config` is possible on
+    /// `device`. Fails with `hipErrorNotSupported` if the device can't make
+    /// cooperative launches (or, if `multi_device`, cooperative launches
+    /// across devices), `hipErrorInvalidConfiguration` if the shape exceeds
+    /// the kernel's limits, or `hipErrorCooperativeLaunchTooLarge` if the
+    /// blocks don't all fit on the device at once.
+    fn check_cooperative(
+        self,
+        device: Device,
+        config: &LaunchConfig<'_>,
+        multi_device: bool,
+    ) -> Result<()> {
+        let supported = if multi_device {
+            device.attribute::<attribute::CooperativeMultiDeviceLaunch>()?
+        } else {
+            device.attribute::<attribute::CooperativeLaunch>()?
+        };
+        if !supported {
+            return Err(HipError::from(hipError_t::hipErrorNotSupported));
+        }
+        config.check(&self.limits(device)?)?;
+        let grid = config.grid;
+        let blocks = u64::from(grid.x) * u64::from(grid.y) * u64::from(grid.z);
+        if blocks > self.max_cooperative_blocks(device, config)? {
+            return Err(HipError::from(
+                hipError_t::hipErrorCooperativeLaunchTooLarge,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Launch the kernel cooperatively on the device of `config`'s stream,
+    /// or the current device if it has none. Every block runs at the same
+    /// time, so the blocks can synchronize with each other.
+    ///
+    /// Fails without launching if the device doesn't support cooperative
+    /// launches or the blocks wouldn't all fit on it at once; see
+    /// [`HostKernel::max_cooperative_blocks`].
+    ///
+    /// # Safety
+    /// `args` must match the kernel's parameters, and the kernel must only
+    /// access memory that stays valid, and isn't used by anything else,
+    /// until it completes.
+    pub unsafe fn launch_cooperative<A: KernelArgs>(
+        self,
+        config: &LaunchConfig<'_>,
+        args: A,
+    ) -> Result<()> {
+        let device = match config.stream {
+            Some(stream) => stream.device(),
+            None => Device::current()?,
+        };
+        self.check_cooperative(device, config, false)?;
+        let mut buffer = KernelArgBuffer::new();
+        args.push_to(&mut buffer);
+        let mut params = buffer.param_pointers();
+        hipLaunchCooperativeKernel(
+            self.stub,
+            config.grid,
+            config.block,
+            params.as_mut_ptr(),
+            config.shared_mem,
+            config.stream.map_or(ptr::null_mut(), Stream::as_raw),
+        )
+        .into_result()
+    }
+}
+
+flags! {
+    /// Flags for a cooperative [`MultiDeviceLaunch`].
+    pub struct MultiDeviceLaunchFlags {
+        /// Don't wait for earlier work on every launch's device before
+        /// starting any kernel.
+        const NO_PRE_SYNC = hipCooperativeLaunchMultiDeviceNoPreSync;
+        /// Don't make later work on every launch's device wait for all the
+        /// kernels to finish.
+        const NO_POST_SYNC = hipCooperativeLaunchMultiDeviceNoPostSync;
+    }
+}
+
+/// Kernel launches on several devices, queued together: at most one launch
+/// per device, each on a stream of its device.
+///
+/// ```no_run
+/// use hip_runtime_sys::{Device, HostKernel, LaunchConfig, MultiDeviceLaunch, Stream};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let stub = std::ptr::null();
+/// // `stub` is a `__global__` function's address, from linked HIP C++ code.
+/// let kernel = unsafe { HostKernel::from_stub(stub) };
+/// let mut launch = MultiDeviceLaunch::cooperative();
+/// let mut streams = Vec::new();
+/// for device in Device::all()? {
+///     device.set_current()?;
+///     streams.push((device, Stream::new()?));
+/// }
+/// for (device, stream) in &streams {
+///     let config = LaunchConfig::new(4, 256).on_stream(stream);
+///     launch = launch.add(*device, kernel, config, (device.ordinal(),));
+/// }
+/// unsafe { launch.launch() }?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultiDeviceLaunch<'s> {
+    cooperative: bool,
+    flags: MultiDeviceLaunchFlags,
+    launches: Vec<DeviceLaunch<'s>>,
+}
+
+struct DeviceLaunch<'s> {
+    device: Device,
+    kernel: HostKernel,
+    config: LaunchConfig<'s>,
+    args: KernelArgBuffer,
+}
+
+impl<'s> MultiDeviceLaunch<'s> {
+    /// Launches whose kernels run cooperatively: every block on every
+    /// device runs at the same time, so they can all synchronize with each
+    /// other. Uses `hipLaunchCooperativeKernelMultiDevice`.
+    pub fn cooperative() -> MultiDeviceLaunch<'s> {
+        MultiDeviceLaunch {
+            cooperative: true,
+            flags: MultiDeviceLaunchFlags::empty(),
+            launches: Vec::new(),
+        }
+    }
+
+    /// Launches that are all queued before any other work can be queued on
+    /// their streams, but otherwise run independently. Uses
+    /// `hipExtLaunchMultiKernelMultiDevice`.
+    pub fn independent() -> MultiDeviceLaunch<'s> {
+        MultiDeviceLaunch {
+            cooperative: false,
+            ..MultiDeviceLaunch::cooperative()
+        }
+    }
+
+    /// Set the flags the launches are made with.
+    pub fn flags(mut self, flags: MultiDeviceLaunchFlags) -> MultiDeviceLaunch<'s> {
+        self.flags = flags;
+        self
+    }
+
+    /// Add a launch of `kernel` on `device`, on `config`'s stream, which
+    /// must belong to `device`.
+    pub fn add<A: KernelArgs>(
+        mut self,
+        device: Device,
+        kernel: HostKernel,
+        config: LaunchConfig<'s>,
+        args: A,
+    ) -> MultiDeviceLaunch<'s> {
+        let mut buffer = KernelArgBuffer::new();
+        args.push_to(&mut buffer);
+        self.launches.push(DeviceLaunch {
+            device,
+            kernel,
+            config,
+            args: buffer,
+        });
+        self
+    }
+
+    /// The devices launched on, in the order they were added.
+    pub fn devices(&self) -> impl Iterator<Item = Device> + '_ {
+        self.launches.iter().map(|launch| launch.device)
+    }
+
+    /// Check every launch without making any. Fails, naming the device,
+    /// with `hipErrorInvalidValue` if a launch has no stream,
+    /// `hipErrorInvalidDevice` if a launch's stream is on another device or
+    /// a device has two launches, or like
+    /// [`HostKernel::launch_cooperative`] if a cooperative launch isn't
+    /// possible. Fails with `hipErrorInvalidValue`, naming no device, if
+    /// there are no launches.
+    pub fn check(&self) -> std::result::Result<(), MultiDeviceLaunchError> {
+        if self.launches.is_empty() {
+            return Err(MultiDeviceLaunchError {
+                device: None,
+                error: HipError::from(hipError_t::hipErrorInvalidValue),
+            });
+        }
+        for (i, launch) in self.launches.iter().enumerate() {
+            let fail = |error| MultiDeviceLaunchError {
+                device: Some(launch.device),
+                error,
+            };
+            match launch.config.stream {
+                None => return Err(fail(HipError::from(hipError_t::hipErrorInvalidValue))),
+                Some(stream) if stream.device() != launch.device => {
+                    return Err(fail(HipError::from(hipError_t::hipErrorInvalidDevice)));
+                }
+                Some(_) => {}
+            }
+            if self.launches[..i]
+                .iter()
+                .any(|other| other.device == launch.device)
+            {
+                return Err(fail(HipError::from(hipError_t::hipErrorInvalidDevice)));
+            }
+            let checked = if self.cooperative {
+                launch
+                    .kernel
+                    .check_cooperative(launch.device, &launch.config, true)
+            } else {
+                launch
+                    .kernel
+                    .limits(launch.device)
+                    .and_then(|limits| launch.config.check(&limits))
+            };
+            checked.map_err(fail)?;
+        }
+        Ok(())
+    }
+
+    /// [Check](MultiDeviceLaunch::check) the launches, then make them all.
+    /// If the runtime then rejects them, the error names no device.
+    ///
+    /// # Safety
+    /// Each launch's arguments must match its kernel's parameters, and the
+    /// kernels must only access memory that stays valid, and isn't used by
+    /// anything else, until they complete.
+    pub unsafe fn launch(mut self) -> std::result::Result<(), MultiDeviceLaunchError> {
+        self.check()?;
+        // The parameter arrays point into the argument buffers, which stay
+        // put until `self` is dropped.
+        let mut params: Vec<Vec<*mut c_void>> = self
+            .launches
+            .iter_mut()
+            .map(|launch| launch.args.param_pointers())
+            .collect();
+        let mut list: Vec<hipLaunchParams> = self
+            .launches
+            .iter()
+            .zip(&mut params)
+            .map(|(launch, params)| hipLaunchParams {
+                func: launch.kernel.stub as *mut c_void,
+                gridDim: launch.config.grid,
+                blockDim: launch.config.block,
+                args: params.as_mut_ptr(),
+                sharedMem: launch.config.shared_mem as usize,
+                stream: launch.config.stream.map_or(ptr::null_mut(), Stream::as_raw),
+            })
+            .collect();
+        let launch = if self.cooperative {
+            hipLaunchCooperativeKernelMultiDevice
+        } else {
+            hipExtLaunchMultiKernelMultiDevice
+        };
+        launch(list.as_mut_ptr(), list.len() as i32, self.flags.bits())
+            .into_result()
+            .map_err(|error| MultiDeviceLaunchError {
+                device: None,
+                error,
+            })
+    }
+}
+
+/// The error from a [`MultiDeviceLaunch`], with the device whose launch
+/// caused it, if that's known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultiDeviceLaunchError {
+    /// The device whose launch failed the checks, or `None` if there were
+    /// no launches or the runtime rejected them.
+    pub device: Option<Device>,
+    /// What went wrong.
+    pub error: HipError,
+}
+
+impl fmt::Display for MultiDeviceLaunchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.device {
+            Some(device) => write!(
+                f,
+                "launch on device {} failed: {}",
+                device.ordinal(),
+                self.error
+            ),
+            None => write!(f, "multi-device launch failed: {}", self.error),
+        }
+    }
+}
+
+impl Error for MultiDeviceLaunchError {}
+
+impl From<MultiDeviceLaunchError> for HipError {
+    fn from(e: MultiDeviceLaunchError) -> HipError {
+        e.error
+    }
+}
@@ -0,0 +1,476 @@
+//! Device enumeration and queries.
+
+use crate::{
+    hipDeviceAttribute_t, hipDeviceComputeCapability, hipDeviceGetAttribute, hipDeviceGetName,
+    hipDeviceGetPCIBusId, hipDeviceGetUuid, hipDeviceProp_t, hipDeviceTotalMem, hipError_t,
+    hipGetDevice, hipGetDeviceCount, hipGetDeviceProperties, hipSetDevice, hipUUID, HipError,
+    Result,
+};
+use std::{
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+};
+
+/// A HIP device, identified by its ordinal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Device {
+    ordinal: i32,
+}
+
+impl Device {
+    /// The number of HIP devices. Zero if there are none, rather than
+    /// `hipErrorNoDevice`.
+    pub fn count() -> Result<usize> {
+        let mut count = 0;
+        match unsafe { hipGetDeviceCount(&mut count) } {
+            hipError_t::hipErrorNoDevice => Ok(0),
+            e => e.into_result().map(|()| count as usize),
+        }
+    }
+
+    /// All HIP devices.
+    pub fn all() -> Result<Vec<Device>> {
+        Ok((0..Device::count()? as i32)
+            .map(|ordinal| Device { ordinal })
+            .collect())
+    }
+
+    /// The device with the given ordinal, if it exists.
+    pub fn get(ordinal: i32) -> Result<Device> {
+        if ordinal < 0 || ordinal as usize >= Device::count()? {
+            return Err(HipError::from(hipError_t::hipErrorInvalidDevice));
+        }
+        Ok(Device { ordinal })
+    }
+
+    /// The device with the given ordinal, without checking that it exists.
+    pub(crate) fn from_ordinal(ordinal: i32) -> Device {
+        Device { ordinal }
+    }
+
+    /// The device used by HIP calls on this thread.
+    pub fn current() -> Result<Device> {
+        let mut ordinal = 0;
+        unsafe { hipGetDevice(&mut ordinal) }.into_result()?;
+        Ok(Device { ordinal })
+    }
+
+    /// Make this the device used by HIP calls on this thread.
+    pub fn set_current(self) -> Result<()> {
+        unsafe { hipSetDevice(self.ordinal) }.into_result()
+    }
+
+    /// Make this the device used by HIP calls on this thread until the
+    /// guard is dropped, when the device that was current before becomes
+    /// current again.
+    pub fn activate(self) -> Result<DeviceGuard> {
+        let previous = Device::current()?;
+        if previous != self {
+            self.set_current()?;
+        }
+        Ok(DeviceGuard {
+            previous,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Run `f` with this device current on this thread, then make the
+    /// device that was current before current again, even if `f` panics.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> Result<R> {
+        let guard = self.activate()?;
+        let result = f();
+        guard.restore()?;
+        Ok(result)
+    }
+
+    /// The device's ordinal.
+    pub fn ordinal(self) -> i32 {
+        self.ordinal
+    }
+
+    /// The device's marketing name, e.g. "AMD Instinct MI210".
+    pub fn name(self) -> Result<String> {
+        let mut name = [0; 256];
+        unsafe { hipDeviceGetName(name.as_mut_ptr(), name.len() as i32, self.ordinal) }
+            .into_result()?;
+        Ok(string_from_buffer(&name))
+    }
+
+    /// The device's UUID.
+    pub fn uuid(self) -> Result<[u8; 16]> {
+        let mut uuid = hipUUID::default();
+        unsafe { hipDeviceGetUuid(&mut uuid, self.ordinal) }.into_result()?;
+        Ok(uuid.bytes.map(|b| b as u8))
+    }
+
+    /// The device's PCI bus id, in the form `domain:bus:device.function`.
+    pub fn pci_bus_id(self) -> Result<String> {
+        // 13 characters and a NUL are enough, but leave some room.
+        let mut id = [0; 64];
+        unsafe { hipDeviceGetPCIBusId(id.as_mut_ptr(), id.len() as i32, self.ordinal) }
+            .into_result()?;
+        Ok(string_from_buffer(&id))
+    }
+
+    /// The amount of global memory on the device, in bytes.
+    pub fn total_memory(self) -> Result<usize> {
+        let mut bytes = 0;
+        unsafe { hipDeviceTotalMem(&mut bytes, self.ordinal) }.into_result()?;
+        Ok(bytes)
+    }
+
+    /// The device's compute capability, as `(major, minor)`.
+    pub fn compute_capability(self) -> Result<(i32, i32)> {
+        let mut major = 0;
+        let mut minor = 0;
+        unsafe { hipDeviceComputeCapability(&mut major, &mut minor, self.ordinal) }
+            .into_result()?;
+        Ok((major, minor))
+    }
+
+    /// The device's GCN architecture name, including target features, e.g.
+    /// "gfx90a:sramecc+:xnack-".
+    pub fn gcn_arch_name(self) -> Result<String> {
+        Ok(string_from_buffer(&self.properties()?.gcnArchName))
+    }
+
+    /// All of the device's properties.
+    pub fn properties(self) -> Result<hipDeviceProp_t> {
+        let mut properties = MaybeUninit::<hipDeviceProp_t>::zeroed();
+        unsafe { hipGetDeviceProperties(properties.as_mut_ptr(), self.ordinal) }.into_result()?;
+        // Safety: the struct is plain data, so all-zeroes is valid even if
+        // the runtime didn't fill in every field.
+        Ok(unsafe { properties.assume_init() })
+    }
+
+    /// Query a device attribute, e.g.
+    /// `device.attribute::<attribute::MaxThreadsPerBlock>()`.
+    pub fn attribute<A: DeviceAttribute>(self) -> Result<A::Value> {
+        self.raw_attribute(A::ATTRIBUTE)
+            .map(AttributeValue::from_raw)
+    }
+
+    /// Query a device attribute by its raw identifier.
+    pub fn raw_attribute(self, attribute: hipDeviceAttribute_t) -> Result<i32> {
+        let mut value = 0;
+        unsafe { hipDeviceGetAttribute(&mut value, attribute, self.ordinal) }.into_result()?;
+        Ok(value)
+    }
+}
+
+/// The device that was current before [`Device::activate`], made current
+/// again on drop. Restoring is per thread, so the guard can't be sent to
+/// another one.
+#[derive(Debug)]
+#[must_use = "the previous device is made current when the guard is dropped"]
+pub struct DeviceGuard {
+    previous: Device,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl DeviceGuard {
+    /// The device that becomes current again when the guard is dropped.
+    pub fn previous(&self) -> Device {
+        self.previous
+    }
+
+    /// Make the previous device current now, reporting any error that
+    /// dropping the guard would ignore.
+    pub fn restore(self) -> Result<()> {
+        let result = self.previous.set_current();
+        mem::forget(self);
+        result
+    }
+}
+
+impl Drop for DeviceGuard {
+    fn drop(&mut self) {
+        let _ = self.previous.set_current();
+    }
+}
+
+/// Convert a NUL-padded C string buffer, ignoring everything from the first
+/// NUL onwards.
+fn string_from_buffer(buffer: &[std::os::raw::c_char]) -> String {
+    let bytes: Vec<u8> = buffer
+        .iter()
+        .map(|&c| c as u8)
+        .take_while(|&b| b != 0)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// A device attribute that can be queried with [`Device::attribute`].
+pub trait DeviceAttribute {
+    /// The raw attribute identifier.
+    const ATTRIBUTE: hipDeviceAttribute_t;
+    /// The type the attribute's value is converted to.
+    type Value: AttributeValue;
+}
+
+/// A type that device attribute values can be converted to.
+pub trait AttributeValue {
+    /// Convert the value reported by `hipDeviceGetAttribute`.
+    fn from_raw(raw: i32) -> Self;
+}
+
+impl AttributeValue for i32 {
+    fn from_raw(raw: i32) -> i32 {
+        raw
+    }
+}
+
+impl AttributeValue for bool {
+    fn from_raw(raw: i32) -> bool {
+        raw != 0
+    }
+}
+
+macro_rules! device_attributes {
+    ($($(#[$doc:meta])* $name:ident => $raw:ident: $ty:ty,)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Copy, Debug)]
+            pub enum $name {}
+
+            impl DeviceAttribute for $name {
+                const ATTRIBUTE: hipDeviceAttribute_t = hipDeviceAttribute_t::$raw;
+                type Value = $ty;
+            }
+        )*
+    };
+}
+
+/// Typed device attributes for [`Device::attribute`], one per
+/// [`hipDeviceAttribute_t`]. String-valued attributes are available through
+/// [`Device::name`], [`Device::uuid`] and [`Device::gcn_arch_name`] instead,
+/// and the total global memory, which can exceed an `i32`, through
+/// [`Device::total_memory`].
+pub mod attribute {
+    use super::DeviceAttribute;
+    use crate::hipDeviceAttribute_t;
+
+    device_attributes! {
+    /// Whether ECC support is enabled.
+    EccEnabled => hipDeviceAttributeEccEnabled: bool,
+    /// Cuda only. The maximum size of the window policy in bytes.
+    AccessPolicyMaxWindowSize => hipDeviceAttributeAccessPolicyMaxWindowSize: i32,
+    /// Cuda only. Asynchronous engines number.
+    AsyncEngineCount => hipDeviceAttributeAsyncEngineCount: i32,
+    /// Whether host memory can be mapped into device address space.
+    CanMapHostMemory => hipDeviceAttributeCanMapHostMemory: bool,
+    /// Cuda only. Device can access host registered memory at the same virtual
+    /// address as the CPU.
+    CanUseHostPointerForRegisteredMem => hipDeviceAttributeCanUseHostPointerForRegisteredMem: bool,
+    /// Peak clock frequency in kilohertz.
+    ClockRate => hipDeviceAttributeClockRate: i32,
+    /// Compute mode that device is currently in.
+    ComputeMode => hipDeviceAttributeComputeMode: i32,
+    /// Cuda only. Device supports Compute Preemption.
+    ComputePreemptionSupported => hipDeviceAttributeComputePreemptionSupported: bool,
+    /// Device can possibly execute multiple kernels concurrently.
+    ConcurrentKernels => hipDeviceAttributeConcurrentKernels: bool,
+    /// Device can coherently access managed memory concurrently with the CPU.
+    ConcurrentManagedAccess => hipDeviceAttributeConcurrentManagedAccess: bool,
+    /// Support cooperative launch.
+    CooperativeLaunch => hipDeviceAttributeCooperativeLaunch: bool,
+    /// Support cooperative launch on multiple devices.
+    CooperativeMultiDeviceLaunch => hipDeviceAttributeCooperativeMultiDeviceLaunch: bool,
+    /// Cuda only. Device can concurrently copy memory and execute a kernel.
+    /// Deprecated. Use instead asyncEngineCount.
+    DeviceOverlap => hipDeviceAttributeDeviceOverlap: bool,
+    /// Host can directly access managed memory on the device without migration.
+    DirectManagedMemAccessFromHost => hipDeviceAttributeDirectManagedMemAccessFromHost: bool,
+    /// Cuda only. Device supports caching globals in L1.
+    GlobalL1CacheSupported => hipDeviceAttributeGlobalL1CacheSupported: bool,
+    /// Cuda only. Link between the device and the host supports native atomic
+    /// operations.
+    HostNativeAtomicSupported => hipDeviceAttributeHostNativeAtomicSupported: bool,
+    /// Device is integrated GPU.
+    Integrated => hipDeviceAttributeIntegrated: bool,
+    /// Multiple GPU devices.
+    IsMultiGpuBoard => hipDeviceAttributeIsMultiGpuBoard: bool,
+    /// Run time limit for kernels executed on the device.
+    KernelExecTimeout => hipDeviceAttributeKernelExecTimeout: bool,
+    /// Size of L2 cache in bytes. 0 if the device doesn't have L2 cache.
+    L2CacheSize => hipDeviceAttributeL2CacheSize: i32,
+    /// caching locals in L1 is supported.
+    LocalL1CacheSupported => hipDeviceAttributeLocalL1CacheSupported: bool,
+    /// Cuda only. Luid device node mask. Undefined on TCC and non-Windows
+    /// platforms.
+    LuidDeviceNodeMask => hipDeviceAttributeLuidDeviceNodeMask: i32,
+    /// Major compute capability version number.
+    ComputeCapabilityMajor => hipDeviceAttributeComputeCapabilityMajor: i32,
+    /// Device supports allocating managed memory on this system.
+    ManagedMemory => hipDeviceAttributeManagedMemory: bool,
+    /// Cuda only. Max block size per multiprocessor.
+    MaxBlocksPerMultiProcessor => hipDeviceAttributeMaxBlocksPerMultiProcessor: i32,
+    /// Max block size in width.
+    MaxBlockDimX => hipDeviceAttributeMaxBlockDimX: i32,
+    /// Max block size in height.
+    MaxBlockDimY => hipDeviceAttributeMaxBlockDimY: i32,
+    /// Max block size in depth.
+    MaxBlockDimZ => hipDeviceAttributeMaxBlockDimZ: i32,
+    /// Max grid size in width.
+    MaxGridDimX => hipDeviceAttributeMaxGridDimX: i32,
+    /// Max grid size in height.
+    MaxGridDimY => hipDeviceAttributeMaxGridDimY: i32,
+    /// Max grid size in depth.
+    MaxGridDimZ => hipDeviceAttributeMaxGridDimZ: i32,
+    /// Maximum size of 1D surface.
+    MaxSurface1D => hipDeviceAttributeMaxSurface1D: i32,
+    /// Cuda only. Maximum dimensions of 1D layered surface.
+    MaxSurface1DLayered => hipDeviceAttributeMaxSurface1DLayered: i32,
+    /// Maximum dimension (width, height) of 2D surface.
+    MaxSurface2D => hipDeviceAttributeMaxSurface2D: i32,
+    /// Cuda only. Maximum dimensions of 2D layered surface.
+    MaxSurface2DLayered => hipDeviceAttributeMaxSurface2DLayered: i32,
+    /// Maximum dimension (width, height, depth) of 3D surface.
+    MaxSurface3D => hipDeviceAttributeMaxSurface3D: i32,
+    /// Cuda only. Maximum dimensions of Cubemap surface.
+    MaxSurfaceCubemap => hipDeviceAttributeMaxSurfaceCubemap: i32,
+    /// Cuda only. Maximum dimension of Cubemap layered surface.
+    MaxSurfaceCubemapLayered => hipDeviceAttributeMaxSurfaceCubemapLayered: i32,
+    /// Maximum size of 1D texture.
+    MaxTexture1DWidth => hipDeviceAttributeMaxTexture1DWidth: i32,
+    /// Cuda only. Maximum dimensions of 1D layered texture.
+    MaxTexture1DLayered => hipDeviceAttributeMaxTexture1DLayered: i32,
+    /// Maximum number of elements allocatable in a 1D linear texture. Use
+    /// cudaDeviceGetTexture1DLinearMaxWidth() instead on Cuda.
+    MaxTexture1DLinear => hipDeviceAttributeMaxTexture1DLinear: i32,
+    /// Cuda only. Maximum size of 1D mipmapped texture.
+    MaxTexture1DMipmap => hipDeviceAttributeMaxTexture1DMipmap: i32,
+    /// Maximum dimension width of 2D texture.
+    MaxTexture2DWidth => hipDeviceAttributeMaxTexture2DWidth: i32,
+    /// Maximum dimension height of 2D texture.
+    MaxTexture2DHeight => hipDeviceAttributeMaxTexture2DHeight: i32,
+    /// Cuda only. Maximum dimensions of 2D texture if gather operations performed.
+    MaxTexture2DGather => hipDeviceAttributeMaxTexture2DGather: i32,
+    /// Cuda only. Maximum dimensions of 2D layered texture.
+    MaxTexture2DLayered => hipDeviceAttributeMaxTexture2DLayered: i32,
+    /// Cuda only. Maximum dimensions (width, height, pitch) of 2D textures bound to
+    /// pitched memory.
+    MaxTexture2DLinear => hipDeviceAttributeMaxTexture2DLinear: i32,
+    /// Cuda only. Maximum dimensions of 2D mipmapped texture.
+    MaxTexture2DMipmap => hipDeviceAttributeMaxTexture2DMipmap: i32,
+    /// Maximum dimension width of 3D texture.
+    MaxTexture3DWidth => hipDeviceAttributeMaxTexture3DWidth: i32,
+    /// Maximum dimension height of 3D texture.
+    MaxTexture3DHeight => hipDeviceAttributeMaxTexture3DHeight: i32,
+    /// Maximum dimension depth of 3D texture.
+    MaxTexture3DDepth => hipDeviceAttributeMaxTexture3DDepth: i32,
+    /// Cuda only. Maximum dimensions of alternate 3D texture.
+    MaxTexture3DAlt => hipDeviceAttributeMaxTexture3DAlt: i32,
+    /// Cuda only. Maximum dimensions of Cubemap texture.
+    MaxTextureCubemap => hipDeviceAttributeMaxTextureCubemap: i32,
+    /// Cuda only. Maximum dimensions of Cubemap layered texture.
+    MaxTextureCubemapLayered => hipDeviceAttributeMaxTextureCubemapLayered: i32,
+    /// Maximum dimension of a block.
+    MaxThreadsDim => hipDeviceAttributeMaxThreadsDim: i32,
+    /// Maximum number of threads per block.
+    MaxThreadsPerBlock => hipDeviceAttributeMaxThreadsPerBlock: i32,
+    /// Maximum resident threads per multiprocessor.
+    MaxThreadsPerMultiProcessor => hipDeviceAttributeMaxThreadsPerMultiProcessor: i32,
+    /// Maximum pitch in bytes allowed by memory copies.
+    MaxPitch => hipDeviceAttributeMaxPitch: i32,
+    /// Global memory bus width in bits.
+    MemoryBusWidth => hipDeviceAttributeMemoryBusWidth: i32,
+    /// Peak memory clock frequency in kilohertz.
+    MemoryClockRate => hipDeviceAttributeMemoryClockRate: i32,
+    /// Minor compute capability version number.
+    ComputeCapabilityMinor => hipDeviceAttributeComputeCapabilityMinor: i32,
+    /// Cuda only. Unique ID of device group on the same multi-GPU board.
+    MultiGpuBoardGroupID => hipDeviceAttributeMultiGpuBoardGroupID: i32,
+    /// Number of multiprocessors on the device.
+    MultiprocessorCount => hipDeviceAttributeMultiprocessorCount: i32,
+    /// Device supports coherently accessing pageable memory without calling
+    /// hipHostRegister on it.
+    PageableMemoryAccess => hipDeviceAttributePageableMemoryAccess: bool,
+    /// Device accesses pageable memory via the host's page tables.
+    PageableMemoryAccessUsesHostPageTables => hipDeviceAttributePageableMemoryAccessUsesHostPageTables: bool,
+    /// PCI Bus ID.
+    PciBusId => hipDeviceAttributePciBusId: i32,
+    /// PCI Device ID.
+    PciDeviceId => hipDeviceAttributePciDeviceId: i32,
+    /// PCI Domain ID.
+    PciDomainID => hipDeviceAttributePciDomainID: i32,
+    /// Cuda11 only. Maximum l2 persisting lines capacity in bytes.
+    PersistingL2CacheMaxSize => hipDeviceAttributePersistingL2CacheMaxSize: i32,
+    /// 32-bit registers available to a thread block. This number is shared by all
+    /// thread blocks simultaneously resident on a multiprocessor.
+    MaxRegistersPerBlock => hipDeviceAttributeMaxRegistersPerBlock: i32,
+    /// 32-bit registers available per block.
+    MaxRegistersPerMultiprocessor => hipDeviceAttributeMaxRegistersPerMultiprocessor: i32,
+    /// Cuda11 only. Shared memory reserved by CUDA driver per block.
+    ReservedSharedMemPerBlock => hipDeviceAttributeReservedSharedMemPerBlock: i32,
+    /// Maximum shared memory available per block in bytes.
+    MaxSharedMemoryPerBlock => hipDeviceAttributeMaxSharedMemoryPerBlock: i32,
+    /// Cuda only. Maximum shared memory per block usable by special opt in.
+    SharedMemPerBlockOptin => hipDeviceAttributeSharedMemPerBlockOptin: i32,
+    /// Cuda only. Shared memory available per multiprocessor.
+    SharedMemPerMultiprocessor => hipDeviceAttributeSharedMemPerMultiprocessor: i32,
+    /// Cuda only. Performance ratio of single precision to double precision.
+    SingleToDoublePrecisionPerfRatio => hipDeviceAttributeSingleToDoublePrecisionPerfRatio: i32,
+    /// Cuda only. Whether to support stream priorities.
+    StreamPrioritiesSupported => hipDeviceAttributeStreamPrioritiesSupported: bool,
+    /// Cuda only. Alignment requirement for surfaces.
+    SurfaceAlignment => hipDeviceAttributeSurfaceAlignment: i32,
+    /// Cuda only. Whether device is a Tesla device using TCC driver.
+    TccDriver => hipDeviceAttributeTccDriver: bool,
+    /// Alignment requirement for textures.
+    TextureAlignment => hipDeviceAttributeTextureAlignment: i32,
+    /// Pitch alignment requirement for 2D texture references bound to pitched
+    /// memory;.
+    TexturePitchAlignment => hipDeviceAttributeTexturePitchAlignment: i32,
+    /// Constant memory size in bytes.
+    TotalConstantMemory => hipDeviceAttributeTotalConstantMemory: i32,
+    // Global memory, which overflows an i32 on most devices, is
+    // `Device::total_memory`.
+    /// Cuda only. An unified address space shared with the host.
+    UnifiedAddressing => hipDeviceAttributeUnifiedAddressing: bool,
+    /// Warp size in threads.
+    WarpSize => hipDeviceAttributeWarpSize: i32,
+    /// Device supports HIP Stream Ordered Memory Allocator.
+    MemoryPoolsSupported => hipDeviceAttributeMemoryPoolsSupported: bool,
+    /// Device supports HIP virtual memory management.
+    VirtualMemoryManagementSupported => hipDeviceAttributeVirtualMemoryManagementSupported: bool,
+    /// Device architecture.
+    Arch => hipDeviceAttributeArch: i32,
+    /// Maximum Shared Memory PerMultiprocessor.
+    MaxSharedMemoryPerMultiprocessor => hipDeviceAttributeMaxSharedMemoryPerMultiprocessor: i32,
+    /// Device gcn architecture.
+    GcnArch => hipDeviceAttributeGcnArch: i32,
+    /// Address of the HDP_MEM_COHERENCY_FLUSH_CNTL register.
+    HdpMemFlushCntl => hipDeviceAttributeHdpMemFlushCntl: i32,
+    /// Address of the HDP_REG_COHERENCY_FLUSH_CNTL register.
+    HdpRegFlushCntl => hipDeviceAttributeHdpRegFlushCntl: i32,
+    /// Supports cooperative launch on multiple devices with unmatched functions.
+    CooperativeMultiDeviceUnmatchedFunc => hipDeviceAttributeCooperativeMultiDeviceUnmatchedFunc: bool,
+    /// Supports cooperative launch on multiple devices with unmatched grid
+    /// dimensions.
+    CooperativeMultiDeviceUnmatchedGridDim => hipDeviceAttributeCooperativeMultiDeviceUnmatchedGridDim: bool,
+    /// Supports cooperative launch on multiple devices with unmatched block
+    /// dimensions.
+    CooperativeMultiDeviceUnmatchedBlockDim => hipDeviceAttributeCooperativeMultiDeviceUnmatchedBlockDim: bool,
+    /// Supports cooperative launch on multiple devices with unmatched shared
+    /// memories.
+    CooperativeMultiDeviceUnmatchedSharedMem => hipDeviceAttributeCooperativeMultiDeviceUnmatchedSharedMem: bool,
+    /// Whether it is LargeBar.
+    IsLargeBar => hipDeviceAttributeIsLargeBar: bool,
+    /// Revision of the GPU in this device.
+    AsicRevision => hipDeviceAttributeAsicRevision: i32,
+    /// '1' if Device supports hipStreamWaitValue32() and hipStreamWaitValue64(),
+    /// '0' otherwise.
+    CanUseStreamWaitValue => hipDeviceAttributeCanUseStreamWaitValue: bool,
+    /// '1' if Device supports image, '0' otherwise.
+    ImageSupport => hipDeviceAttributeImageSupport: bool,
+    /// All available physical compute units for the device.
+    PhysicalMultiProcessorCount => hipDeviceAttributePhysicalMultiProcessorCount: i32,
+    /// '1' if Device supports fine grain, '0' otherwise.
+    FineGrainSupport => hipDeviceAttributeFineGrainSupport: bool,
+    /// Constant frequency of wall clock in kilohertz.
+    WallClockRate => hipDeviceAttributeWallClockRate: i32,
+    /// Frequency of the clock used by `clock()` in device code, in kilohertz.
+    ClockInstructionRate => hipDeviceAttributeClockInstructionRate: i32,
+    }
+}
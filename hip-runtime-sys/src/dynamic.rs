@@ -0,0 +1,77 @@
+//! Runtime loading of the HIP runtime library.
+//!
+//! With the `dynamic-loading` feature, nothing links against `libamdhip64`.
+//! Instead, the library is opened the first time any HIP function is called
+//! (or when [`load`] is called) and every function is resolved into a table.
+//! Functions returning [`hipError_t`](crate::hipError_t) report a missing
+//! library with `hipErrorSharedObjectInitFailed` and a missing symbol with
+//! `hipErrorSharedObjectSymbolNotFound`; other functions panic,
+//! so check [`is_available`] or [`check_symbol`] before calling them.
+
+use crate::bindings::Api;
+
+use hip_loader::Loader;
+
+pub use hip_loader::LoadError;
+
+/// The names under which the HIP runtime library is searched for, in order.
+#[cfg(windows)]
+pub const LIBRARY_NAMES: &[&str] = &["amdhip64.dll", "amdhip64_6.dll", "amdhip64_5.dll"];
+/// The names under which the HIP runtime library is searched for, in order.
+#[cfg(not(windows))]
+pub const LIBRARY_NAMES: &[&str] = &["libamdhip64.so", "libamdhip64.so.6", "libamdhip64.so.5"];
+
+// The system loader searches first, then `$HIP_PATH/lib`, then the `lib`
+// directory of `DEFAULT_HIP_PATH`.
+static LOADER: Loader<Api> = Loader::new(
+    "HIP runtime",
+    LIBRARY_NAMES,
+    &["HIP_PATH"],
+    "/opt/rocm/hip/lib",
+);
+
+pub(crate) fn api() -> Option<&'static Api> {
+    LOADER.api()
+}
+
+// Only called by functions that don't return a status.
+#[allow(dead_code)]
+#[cold]
+pub(crate) fn unavailable(symbol: &str) -> ! {
+    match check_symbol(symbol) {
+        Err(e) => panic!("{}: {e}", env!("CARGO_PKG_NAME")),
+        Ok(()) => unreachable!(),
+    }
+}
+
+/// Load the HIP runtime library, if it hasn't been loaded already. This
+/// happens implicitly on the first call to any HIP function, but calling it
+/// up front allows a missing library to be handled gracefully.
+pub fn load() -> Result<(), LoadError> {
+    LOADER.load()
+}
+
+/// Whether the HIP runtime library could be loaded.
+pub fn is_available() -> bool {
+    load().is_ok()
+}
+
+/// Check that the HIP runtime library is loaded and exports `symbol`.
+/// Functions added in newer HIP versions may be missing from older
+/// installations.
+pub fn check_symbol(symbol: &str) -> Result<(), LoadError> {
+    LOADER.check_symbol(symbol)
+}
+
+/// The names of all HIP runtime functions exported by the loaded library.
+/// Empty if the library couldn't be loaded.
+pub fn available_symbols() -> Vec<&'static str> {
+    LOADER.symbols(true)
+}
+
+/// The names of all HIP runtime functions that the loaded library doesn't
+/// export, e.g. because they were added in a newer HIP. Empty if the library
+/// couldn't be loaded.
+pub fn missing_symbols() -> Vec<&'static str> {
+    LOADER.symbols(false)
+}
@@ -0,0 +1,220 @@
+//! Idiomatic errors for the HIP runtime.
+
+use crate::{hipError_t, hipGetErrorName, hipGetErrorString};
+use std::{ffi::CStr, fmt};
+
+/// A `Result` whose error defaults to [`HipError`].
+pub type Result<T, E = HipError> = std::result::Result<T, E>;
+
+/// Every [`hipError_t`] known to these bindings, with its name.
+macro_rules! known_errors {
+    ($($name:ident),* $(,)?) => {
+        const KNOWN_ERRORS: &[(hipError_t, &str)] = &[$((hipError_t::$name, stringify!($name))),*];
+    };
+}
+
+known_errors!(
+    hipSuccess,
+    hipErrorInvalidValue,
+    hipErrorOutOfMemory,
+    hipErrorNotInitialized,
+    hipErrorDeinitialized,
+    hipErrorProfilerDisabled,
+    hipErrorProfilerNotInitialized,
+    hipErrorProfilerAlreadyStarted,
+    hipErrorProfilerAlreadyStopped,
+    hipErrorInvalidConfiguration,
+    hipErrorInvalidPitchValue,
+    hipErrorInvalidSymbol,
+    hipErrorInvalidDevicePointer,
+    hipErrorInvalidMemcpyDirection,
+    hipErrorInsufficientDriver,
+    hipErrorMissingConfiguration,
+    hipErrorPriorLaunchFailure,
+    hipErrorInvalidDeviceFunction,
+    hipErrorNoDevice,
+    hipErrorInvalidDevice,
+    hipErrorInvalidImage,
+    hipErrorInvalidContext,
+    hipErrorContextAlreadyCurrent,
+    hipErrorMapFailed,
+    hipErrorUnmapFailed,
+    hipErrorArrayIsMapped,
+    hipErrorAlreadyMapped,
+    hipErrorNoBinaryForGpu,
+    hipErrorAlreadyAcquired,
+    hipErrorNotMapped,
+    hipErrorNotMappedAsArray,
+    hipErrorNotMappedAsPointer,
+    hipErrorECCNotCorrectable,
+    hipErrorUnsupportedLimit,
+    hipErrorContextAlreadyInUse,
+    hipErrorPeerAccessUnsupported,
+    hipErrorInvalidKernelFile,
+    hipErrorInvalidGraphicsContext,
+    hipErrorInvalidSource,
+    hipErrorFileNotFound,
+    hipErrorSharedObjectSymbolNotFound,
+    hipErrorSharedObjectInitFailed,
+    hipErrorOperatingSystem,
+    hipErrorInvalidHandle,
+    hipErrorIllegalState,
+    hipErrorNotFound,
+    hipErrorNotReady,
+    hipErrorIllegalAddress,
+    hipErrorLaunchOutOfResources,
+    hipErrorLaunchTimeOut,
+    hipErrorPeerAccessAlreadyEnabled,
+    hipErrorPeerAccessNotEnabled,
+    hipErrorSetOnActiveProcess,
+    hipErrorContextIsDestroyed,
+    hipErrorAssert,
+    hipErrorHostMemoryAlreadyRegistered,
+    hipErrorHostMemoryNotRegistered,
+    hipErrorLaunchFailure,
+    hipErrorCooperativeLaunchTooLarge,
+    hipErrorNotSupported,
+    hipErrorStreamCaptureUnsupported,
+    hipErrorStreamCaptureInvalidated,
+    hipErrorStreamCaptureMerge,
+    hipErrorStreamCaptureUnmatched,
+    hipErrorStreamCaptureUnjoined,
+    hipErrorStreamCaptureIsolation,
+    hipErrorStreamCaptureImplicit,
+    hipErrorCapturedEvent,
+    hipErrorStreamCaptureWrongThread,
+    hipErrorGraphExecUpdateFailure,
+    hipErrorUnknown,
+    hipErrorRuntimeMemory,
+    hipErrorRuntimeOther,
+    hipErrorTbd
+);
+
+impl hipError_t {
+    /// Convert a raw status code into a known [`hipError_t`]. Returns `None`
+    /// for codes these bindings don't know about, e.g. those added by a newer
+    /// HIP runtime.
+    pub fn from_code(code: u32) -> Option<hipError_t> {
+        KNOWN_ERRORS.iter().map(|&(e, _)| e).find(|e| e.0 == code)
+    }
+
+    /// `Ok(())` for [`hipError_t::hipSuccess`], otherwise the error.
+    pub fn into_result(self) -> Result<()> {
+        match self {
+            hipError_t::hipSuccess => Ok(()),
+            e => Err(HipError::from(e)),
+        }
+    }
+}
+
+impl fmt::Debug for hipError_t {
+    /// Prints the variant's name, e.g. `hipErrorOutOfMemory`, or
+    /// `hipError_t(code)` for codes these bindings don't know about.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match known_name(self.0) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "hipError_t({})", self.0),
+        }
+    }
+}
+
+/// The name of the [`hipError_t`] variant for a status code, if it's known to
+/// these bindings.
+fn known_name(code: u32) -> Option<&'static str> {
+    KNOWN_ERRORS
+        .iter()
+        .find(|(e, _)| e.0 == code)
+        .map(|&(_, name)| name)
+}
+
+/// An error returned by the HIP runtime.
+///
+/// The raw status code is kept rather than a [`hipError_t`], so codes that
+/// aren't known to these bindings are preserved.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HipError {
+    code: u32,
+}
+
+impl HipError {
+    /// Create an error from a raw status code. Returns `None` for
+    /// `hipSuccess`.
+    pub fn from_code(code: u32) -> Option<HipError> {
+        if code == hipError_t::hipSuccess.0 {
+            None
+        } else {
+            Some(HipError { code })
+        }
+    }
+
+    /// The raw status code.
+    pub fn code(self) -> u32 {
+        self.code
+    }
+
+    /// The [`hipError_t`] for this error, if it's known to these bindings.
+    pub fn kind(self) -> Option<hipError_t> {
+        hipError_t::from_code(self.code)
+    }
+
+    /// The name of the error according to the runtime, e.g.
+    /// "hipErrorOutOfMemory".
+    pub fn name(self) -> String {
+        match known_name(self.code) {
+            #[cfg(feature = "dynamic-loading")]
+            Some(name) if crate::check_symbol("hipGetErrorName").is_err() => name.to_string(),
+            Some(name) => unsafe { string_from_runtime(hipGetErrorName(hipError_t(self.code))) }
+                .unwrap_or_else(|| name.to_string()),
+            None => format!("hipError_t({})", self.code),
+        }
+    }
+
+    /// A description of the error according to the runtime.
+    pub fn description(self) -> String {
+        match known_name(self.code) {
+            #[cfg(feature = "dynamic-loading")]
+            Some(name) if crate::check_symbol("hipGetErrorString").is_err() => name.to_string(),
+            Some(name) => unsafe { string_from_runtime(hipGetErrorString(hipError_t(self.code))) }
+                .unwrap_or_else(|| name.to_string()),
+            None => "unknown error".to_string(),
+        }
+    }
+}
+
+/// Copy a string owned by the runtime.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string.
+unsafe fn string_from_runtime(ptr: *const std::os::raw::c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+    }
+}
+
+impl From<hipError_t> for HipError {
+    /// Note that this accepts `hipSuccess`; prefer
+    /// [`hipError_t::into_result`].
+    fn from(e: hipError_t) -> HipError {
+        HipError { code: e.0 }
+    }
+}
+
+impl fmt::Debug for HipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match known_name(self.code) {
+            Some(name) => write!(f, "HipError({name})"),
+            None => write!(f, "HipError({})", self.code),
+        }
+    }
+}
+
+impl fmt::Display for HipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.name(), self.code, self.description())
+    }
+}
+
+impl std::error::Error for HipError {}
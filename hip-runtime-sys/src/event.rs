@@ -0,0 +1,95 @@
+//! Events for synchronising with and timing work on streams.
+
+use crate::{
+    hipError_t, hipEventCreateWithFlags, hipEventDefault, hipEventDestroy, hipEventElapsedTime,
+    hipEventQuery, hipEventRecord, hipEventSynchronize, hipEvent_t, Device, Result, Stream,
+};
+use std::{ptr, time::Duration};
+
+/// A HIP event, destroyed on drop. It belongs to the device that was
+/// current when it was created, which is made current to record it.
+#[derive(Debug)]
+pub struct Event {
+    raw: hipEvent_t,
+    device: Device,
+}
+
+// Safety: HIP events can be used from any thread.
+unsafe impl Send for Event {}
+unsafe impl Sync for Event {}
+
+impl Event {
+    /// Create an event with the default flags.
+    pub fn new() -> Result<Event> {
+        Event::with_flags(hipEventDefault)
+    }
+
+    /// Create an event with `hipEvent*` flags, e.g. `hipEventDisableTiming`.
+    pub fn with_flags(flags: u32) -> Result<Event> {
+        let device = Device::current()?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipEventCreateWithFlags(&mut raw, flags) }.into_result()?;
+        Ok(Event { raw, device })
+    }
+
+    /// Take ownership of a raw event.
+    ///
+    /// # Safety
+    /// `raw` must be a valid event on `device` that nothing else will
+    /// destroy.
+    pub unsafe fn from_raw(raw: hipEvent_t, device: Device) -> Event {
+        Event { raw, device }
+    }
+
+    /// The raw event, which remains owned by this `Event`.
+    pub fn as_raw(&self) -> hipEvent_t {
+        self.raw
+    }
+
+    /// Give up ownership of the raw event without destroying it.
+    pub fn into_raw(self) -> hipEvent_t {
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+
+    /// The device the event belongs to.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Record the event once the work queued on `stream` so far completes.
+    pub fn record(&self, stream: &Stream) -> Result<()> {
+        self.device
+            .scope(|| unsafe { hipEventRecord(self.raw, stream.as_raw()) })?
+            .into_result()
+    }
+
+    /// Block until the event completes.
+    pub fn synchronize(&self) -> Result<()> {
+        unsafe { hipEventSynchronize(self.raw) }.into_result()
+    }
+
+    /// Whether the event has completed, without blocking.
+    pub fn query(&self) -> Result<bool> {
+        match unsafe { hipEventQuery(self.raw) } {
+            hipError_t::hipErrorNotReady => Ok(false),
+            e => e.into_result().map(|()| true),
+        }
+    }
+
+    /// The time between `start` and this event completing. Both must have
+    /// been recorded without `hipEventDisableTiming`. Saturates at zero if
+    /// `start` completed later.
+    pub fn elapsed_since(&self, start: &Event) -> Result<Duration> {
+        let mut ms = 0.0;
+        unsafe { hipEventElapsedTime(&mut ms, start.raw, self.raw) }.into_result()?;
+        Ok(Duration::from_secs_f64(f64::from(ms.max(0.0)) / 1000.0))
+    }
+}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        let _ = unsafe { hipEventDestroy(self.raw) };
+    }
+}
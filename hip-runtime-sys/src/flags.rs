@@ -0,0 +1,53 @@
+//! Typed sets of the `u32` flags taken by HIP functions.
+
+/// Define a set of flags over `u32` constants from the bindings.
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$flag_meta:meta])* const $flag:ident = $raw:ident;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name(u32);
+
+        impl $name {
+            $($(#[$flag_meta])* pub const $flag: $name = $name(crate::$raw);)*
+
+            /// No flags.
+            pub const fn empty() -> $name {
+                $name(0)
+            }
+
+            /// Flags from their raw value. Unknown bits are kept.
+            pub const fn from_bits(bits: u32) -> $name {
+                $name(bits)
+            }
+
+            /// The raw value passed to HIP.
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+
+            /// Whether every flag in `other` is set.
+            pub const fn contains(self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name(self.0 | other.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+        }
+    };
+}
@@ -0,0 +1,712 @@
+//! Graphs of device work that are built once and launched many times.
+//!
+//! A [`Graph<'a>`](Graph) borrows all the memory its nodes use for `'a`, as
+//! does every [`ExecutableGraph`] instantiated from it, so nothing a graph
+//! reads or writes can be freed or touched on the host while it might run.
+
+use crate::memory::check_len;
+use crate::{
+    hipError_t, hipEventDisableTiming, hipGraphAddChildGraphNode, hipGraphAddDependencies,
+    hipGraphAddEmptyNode, hipGraphAddHostNode, hipGraphAddKernelNode, hipGraphAddMemcpyNode1D,
+    hipGraphAddMemsetNode, hipGraphClone, hipGraphCreate, hipGraphDestroy,
+    hipGraphExecChildGraphNodeSetParams, hipGraphExecDestroy, hipGraphExecHostNodeSetParams,
+    hipGraphExecKernelNodeSetParams, hipGraphExecMemcpyNodeSetParams1D,
+    hipGraphExecMemsetNodeSetParams, hipGraphExecUpdate, hipGraphExecUpdateResult, hipGraphExec_t,
+    hipGraphInstantiateWithFlags, hipGraphLaunch, hipGraphNode_t, hipGraph_t, hipHostNodeParams,
+    hipKernelNodeParams, hipMemcpyKind, hipMemsetParams, hipStreamBeginCapture,
+    hipStreamCaptureMode, hipStreamEndCapture, DeviceSlice, DeviceSliceMut, Event, HipError,
+    Result, Stream, StreamScope,
+};
+use std::{
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::Arc,
+};
+
+/// A node in a [`Graph`], used to add dependencies on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+// Slices of nodes are passed to HIP as arrays of raw handles.
+#[repr(transparent)]
+pub struct GraphNode {
+    raw: hipGraphNode_t,
+}
+
+// Safety: a node handle is only an identifier; using it requires the graph.
+unsafe impl Send for GraphNode {}
+unsafe impl Sync for GraphNode {}
+
+impl GraphNode {
+    /// Wrap a raw node handle.
+    ///
+    /// # Safety
+    /// `raw` must be a node of a graph it's used with.
+    pub unsafe fn from_raw(raw: hipGraphNode_t) -> GraphNode {
+        GraphNode { raw }
+    }
+
+    /// The raw node handle.
+    pub fn as_raw(self) -> hipGraphNode_t {
+        self.raw
+    }
+}
+
+/// Define node handles that remember what kind of node they are, so their
+/// parameters can be updated in an [`ExecutableGraph`].
+macro_rules! graph_nodes {
+    ($($(#[$meta:meta])* pub struct $name:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub struct $name(GraphNode);
+
+            impl $name {
+                /// The untyped node, e.g. for dependencies.
+                pub fn node(self) -> GraphNode {
+                    self.0
+                }
+            }
+
+            impl From<$name> for GraphNode {
+                fn from(node: $name) -> GraphNode {
+                    node.0
+                }
+            }
+        )*
+    };
+}
+
+graph_nodes! {
+    /// A kernel launch node.
+    pub struct KernelNode;
+    /// A one-dimensional copy node.
+    pub struct CopyNode;
+    /// A memset node.
+    pub struct MemsetNode;
+    /// A host function node.
+    pub struct HostNode;
+    /// A node running a copy of another graph.
+    pub struct ChildGraphNode;
+    /// A node that does nothing, for joining dependencies.
+    pub struct EmptyNode;
+}
+
+/// A closure run by host nodes. It's shared by every graph and executable
+/// graph that has a node calling it, and freed once they're all dropped.
+struct HostFn<'a>(Box<dyn Fn() + Send + Sync + 'a>);
+
+fn host_params(f: &Arc<HostFn<'_>>) -> hipHostNodeParams {
+    unsafe extern "C" fn trampoline(data: *mut c_void) {
+        let f = &*(data as *const HostFn<'_>);
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| (f.0)()));
+    }
+
+    hipHostNodeParams {
+        fn_: Some(trampoline),
+        userData: Arc::as_ptr(f) as *mut c_void,
+    }
+}
+
+/// A HIP graph under construction, destroyed on drop. Memory used by its
+/// nodes is borrowed for `'a`; as with [`StreamScope`], that means a buffer
+/// written by one node can't be read by another through these views.
+///
+/// ```no_run
+/// # use hip_runtime_sys::{DeviceBuffer, Graph, Stream};
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # fn main() -> hip_runtime_sys::Result<()> {
+/// let host = vec![1.0f32; 1024];
+/// let mut buffer = unsafe { DeviceBuffer::<f32>::uninitialized(host.len())? };
+/// let uploads = AtomicUsize::new(0);
+/// let mut graph = Graph::new()?;
+/// let upload = graph.add_copy_from_host(buffer.as_slice_mut(), &host, &[])?;
+/// graph.add_host(
+///     || {
+///         uploads.fetch_add(1, Ordering::Relaxed);
+///     },
+///     &[upload.into()],
+/// )?;
+/// let mut exec = graph.instantiate()?;
+/// let stream = Stream::new()?;
+/// for _ in 0..10 {
+///     exec.launch(&stream)?;
+/// }
+/// exec.synchronize()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Graph<'a> {
+    raw: hipGraph_t,
+    host_fns: Vec<Arc<HostFn<'a>>>,
+    _marker: PhantomData<&'a ()>,
+}
+
+// Safety: the graph is owned, and only used through `&mut self` or for
+// instantiating and cloning, which HIP allows from any thread.
+unsafe impl Send for Graph<'_> {}
+
+impl<'a> Graph<'a> {
+    /// Create an empty graph.
+    pub fn new() -> Result<Graph<'a>> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipGraphCreate(&mut raw, 0) }.into_result()?;
+        Ok(unsafe { Graph::from_raw(raw) })
+    }
+
+    /// Take ownership of a raw graph.
+    ///
+    /// # Safety
+    /// `raw` must be a valid graph that nothing else will destroy, and the
+    /// memory its nodes use must stay valid for `'a`. It must not have host
+    /// nodes whose data is freed before the graph.
+    pub unsafe fn from_raw(raw: hipGraph_t) -> Graph<'a> {
+        Graph {
+            raw,
+            host_fns: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The raw graph, which remains owned by this `Graph`.
+    pub fn as_raw(&self) -> hipGraph_t {
+        self.raw
+    }
+
+    /// Add a node that does nothing, e.g. to join several dependencies.
+    pub fn add_empty(&mut self, dependencies: &[GraphNode]) -> Result<EmptyNode> {
+        let mut node = ptr::null_mut();
+        unsafe {
+            hipGraphAddEmptyNode(
+                &mut node,
+                self.raw,
+                dependencies.as_ptr() as *const hipGraphNode_t,
+                dependencies.len(),
+            )
+        }
+        .into_result()?;
+        Ok(EmptyNode(GraphNode { raw: node }))
+    }
+
+    /// Add a kernel launch.
+    ///
+    /// # Safety
+    /// `params` must describe a valid launch, and the kernel must only
+    /// access memory that stays valid for `'a`.
+    pub unsafe fn add_kernel(
+        &mut self,
+        params: &hipKernelNodeParams,
+        dependencies: &[GraphNode],
+    ) -> Result<KernelNode> {
+        let mut node = ptr::null_mut();
+        hipGraphAddKernelNode(
+            &mut node,
+            self.raw,
+            dependencies.as_ptr() as *const hipGraphNode_t,
+            dependencies.len(),
+            params,
+        )
+        .into_result()?;
+        Ok(KernelNode(GraphNode { raw: node }))
+    }
+
+    /// Add a copy from `src` into `dst`. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn add_copy_from_host<T: Copy>(
+        &mut self,
+        mut dst: DeviceSliceMut<'a, T>,
+        src: &'a [T],
+        dependencies: &[GraphNode],
+    ) -> Result<CopyNode> {
+        check_len(dst.len(), src.len())?;
+        self.add_copy_raw(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(src),
+            hipMemcpyKind::hipMemcpyHostToDevice,
+            dependencies,
+        )
+    }
+
+    /// Add a copy from `src` into `dst`. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn add_copy_to_host<T: Copy>(
+        &mut self,
+        src: DeviceSlice<'a, T>,
+        dst: &'a mut [T],
+        dependencies: &[GraphNode],
+    ) -> Result<CopyNode> {
+        check_len(src.len(), dst.len())?;
+        self.add_copy_raw(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(dst),
+            hipMemcpyKind::hipMemcpyDeviceToHost,
+            dependencies,
+        )
+    }
+
+    /// Add a copy between device slices. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn add_copy<T: Copy>(
+        &mut self,
+        src: DeviceSlice<'a, T>,
+        mut dst: DeviceSliceMut<'a, T>,
+        dependencies: &[GraphNode],
+    ) -> Result<CopyNode> {
+        check_len(src.len(), dst.len())?;
+        self.add_copy_raw(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            src.len() * mem::size_of::<T>(),
+            hipMemcpyKind::hipMemcpyDeviceToDevice,
+            dependencies,
+        )
+    }
+
+    /// Add a node setting every byte of `dst` to `value`.
+    ///
+    /// # Safety
+    /// Values of `T` made of that byte must be valid.
+    pub unsafe fn add_memset<T: Copy>(
+        &mut self,
+        dst: DeviceSliceMut<'a, T>,
+        value: u8,
+        dependencies: &[GraphNode],
+    ) -> Result<MemsetNode> {
+        let mut node = ptr::null_mut();
+        hipGraphAddMemsetNode(
+            &mut node,
+            self.raw,
+            dependencies.as_ptr() as *const hipGraphNode_t,
+            dependencies.len(),
+            &memset_params(dst, value),
+        )
+        .into_result()?;
+        Ok(MemsetNode(GraphNode { raw: node }))
+    }
+
+    /// Add a node running `f` on a host thread, every time the graph is
+    /// launched. `f` must not call HIP functions. A panic in `f` is caught
+    /// rather than unwinding into the runtime; the panic hook still reports
+    /// it.
+    pub fn add_host<F>(&mut self, f: F, dependencies: &[GraphNode]) -> Result<HostNode>
+    where
+        F: Fn() + Send + Sync + 'a,
+    {
+        let f = Arc::new(HostFn(Box::new(f)));
+        let mut node = ptr::null_mut();
+        unsafe {
+            hipGraphAddHostNode(
+                &mut node,
+                self.raw,
+                dependencies.as_ptr() as *const hipGraphNode_t,
+                dependencies.len(),
+                &host_params(&f),
+            )
+        }
+        .into_result()?;
+        self.host_fns.push(f);
+        Ok(HostNode(GraphNode { raw: node }))
+    }
+
+    /// Add a node running a copy of `child`.
+    pub fn add_child(
+        &mut self,
+        child: &Graph<'a>,
+        dependencies: &[GraphNode],
+    ) -> Result<ChildGraphNode> {
+        let mut node = ptr::null_mut();
+        unsafe {
+            hipGraphAddChildGraphNode(
+                &mut node,
+                self.raw,
+                dependencies.as_ptr() as *const hipGraphNode_t,
+                dependencies.len(),
+                child.raw,
+            )
+        }
+        .into_result()?;
+        self.host_fns.extend(child.host_fns.iter().cloned());
+        Ok(ChildGraphNode(GraphNode { raw: node }))
+    }
+
+    /// Make `to` depend on `from`.
+    pub fn add_dependency(&mut self, from: GraphNode, to: GraphNode) -> Result<()> {
+        unsafe { hipGraphAddDependencies(self.raw, &from.raw, &to.raw, 1) }.into_result()
+    }
+
+    /// Instantiate the graph so it can be launched.
+    pub fn instantiate(&self) -> Result<ExecutableGraph<'a>> {
+        self.instantiate_with_flags(0)
+    }
+
+    /// Instantiate the graph with `hipGraphInstantiateFlags`.
+    pub fn instantiate_with_flags(&self, flags: u64) -> Result<ExecutableGraph<'a>> {
+        // Created first so that failing doesn't leave a graph to destroy.
+        let done = Event::with_flags(hipEventDisableTiming)?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipGraphInstantiateWithFlags(&mut raw, self.raw, flags) }.into_result()?;
+        Ok(ExecutableGraph {
+            raw,
+            host_fns: self.host_fns.clone(),
+            done,
+        })
+    }
+
+    /// A copy of the graph.
+    pub fn try_clone(&self) -> Result<Graph<'a>> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipGraphClone(&mut raw, self.raw) }.into_result()?;
+        Ok(Graph {
+            raw,
+            host_fns: self.host_fns.clone(),
+            _marker: PhantomData,
+        })
+    }
+
+    fn add_copy_raw(
+        &mut self,
+        dst: *mut c_void,
+        src: *const c_void,
+        bytes: usize,
+        kind: hipMemcpyKind,
+        dependencies: &[GraphNode],
+    ) -> Result<CopyNode> {
+        let mut node = ptr::null_mut();
+        unsafe {
+            hipGraphAddMemcpyNode1D(
+                &mut node,
+                self.raw,
+                dependencies.as_ptr() as *const hipGraphNode_t,
+                dependencies.len(),
+                dst,
+                src,
+                bytes,
+                kind,
+            )
+        }
+        .into_result()?;
+        Ok(CopyNode(GraphNode { raw: node }))
+    }
+}
+
+impl Drop for Graph<'_> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipGraphDestroy(self.raw) };
+    }
+}
+
+impl fmt::Debug for Graph<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Graph").field("raw", &self.raw).finish()
+    }
+}
+
+/// An instantiated [`Graph`], destroyed on drop. It keeps borrowing the
+/// memory the graph used for `'a`, and dropping it waits for its launches to
+/// finish.
+pub struct ExecutableGraph<'a> {
+    raw: hipGraphExec_t,
+    host_fns: Vec<Arc<HostFn<'a>>>,
+    // Recorded after every launch, which also waits for the last one.
+    done: Event,
+}
+
+// Safety: the executable graph is owned, and only modified or launched
+// through `&mut self`.
+unsafe impl Send for ExecutableGraph<'_> {}
+unsafe impl Sync for ExecutableGraph<'_> {}
+
+impl<'a> ExecutableGraph<'a> {
+    /// The raw executable graph, which remains owned by this
+    /// `ExecutableGraph`.
+    pub fn as_raw(&self) -> hipGraphExec_t {
+        self.raw
+    }
+
+    /// Queue the graph on `stream`, after any previous launch.
+    pub fn launch(&mut self, stream: &Stream) -> Result<()> {
+        stream.wait_event(&self.done)?;
+        unsafe { hipGraphLaunch(self.raw, stream.as_raw()) }.into_result()?;
+        if let Err(e) = self.done.record(stream) {
+            // Without the event there's no other way to know it's done.
+            let _ = stream.synchronize();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Wait for the last launch to finish.
+    pub fn synchronize(&self) -> Result<()> {
+        self.done.synchronize()
+    }
+
+    /// Update the parameters of every node to match `graph`, which must have
+    /// the same topology as the graph this was instantiated from.
+    pub fn update(&mut self, graph: &Graph<'a>) -> std::result::Result<(), GraphUpdateError> {
+        let mut node = ptr::null_mut();
+        let mut result = hipGraphExecUpdateResult::hipGraphExecUpdateSuccess;
+        let status = unsafe { hipGraphExecUpdate(self.raw, graph.raw, &mut node, &mut result) };
+        if status != hipError_t::hipSuccess
+            || result != hipGraphExecUpdateResult::hipGraphExecUpdateSuccess
+        {
+            // The update can be rejected without the call itself failing.
+            let status = if status == hipError_t::hipSuccess {
+                hipError_t::hipErrorGraphExecUpdateFailure
+            } else {
+                status
+            };
+            return Err(GraphUpdateError {
+                error: HipError::from(status),
+                result,
+                node: if node.is_null() {
+                    None
+                } else {
+                    Some(GraphNode { raw: node })
+                },
+            });
+        }
+        self.host_fns.extend(graph.host_fns.iter().cloned());
+        Ok(())
+    }
+
+    /// Change the launch of a kernel node.
+    ///
+    /// # Safety
+    /// `params` must describe a valid launch of the same kernel, which must
+    /// only access memory that stays valid for `'a`.
+    pub unsafe fn set_kernel(
+        &mut self,
+        node: KernelNode,
+        params: &hipKernelNodeParams,
+    ) -> Result<()> {
+        hipGraphExecKernelNodeSetParams(self.raw, node.0.raw, params).into_result()
+    }
+
+    /// Change a copy node added by [`Graph::add_copy_from_host`]. Fails with
+    /// `hipErrorInvalidValue` if the lengths differ.
+    pub fn set_copy_from_host<T: Copy>(
+        &mut self,
+        node: CopyNode,
+        mut dst: DeviceSliceMut<'a, T>,
+        src: &'a [T],
+    ) -> Result<()> {
+        check_len(dst.len(), src.len())?;
+        self.set_copy_raw(
+            node,
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(src),
+            hipMemcpyKind::hipMemcpyHostToDevice,
+        )
+    }
+
+    /// Change a copy node added by [`Graph::add_copy_to_host`]. Fails with
+    /// `hipErrorInvalidValue` if the lengths differ.
+    pub fn set_copy_to_host<T: Copy>(
+        &mut self,
+        node: CopyNode,
+        src: DeviceSlice<'a, T>,
+        dst: &'a mut [T],
+    ) -> Result<()> {
+        check_len(src.len(), dst.len())?;
+        self.set_copy_raw(
+            node,
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(dst),
+            hipMemcpyKind::hipMemcpyDeviceToHost,
+        )
+    }
+
+    /// Change a copy node added by [`Graph::add_copy`]. Fails with
+    /// `hipErrorInvalidValue` if the lengths differ.
+    pub fn set_copy<T: Copy>(
+        &mut self,
+        node: CopyNode,
+        src: DeviceSlice<'a, T>,
+        mut dst: DeviceSliceMut<'a, T>,
+    ) -> Result<()> {
+        check_len(src.len(), dst.len())?;
+        self.set_copy_raw(
+            node,
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            src.len() * mem::size_of::<T>(),
+            hipMemcpyKind::hipMemcpyDeviceToDevice,
+        )
+    }
+
+    /// Change the destination and value of a memset node.
+    ///
+    /// # Safety
+    /// Values of `T` made of that byte must be valid.
+    pub unsafe fn set_memset<T: Copy>(
+        &mut self,
+        node: MemsetNode,
+        dst: DeviceSliceMut<'a, T>,
+        value: u8,
+    ) -> Result<()> {
+        hipGraphExecMemsetNodeSetParams(self.raw, node.0.raw, &memset_params(dst, value))
+            .into_result()
+    }
+
+    /// Change the function a host node runs.
+    pub fn set_host<F>(&mut self, node: HostNode, f: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'a,
+    {
+        let f = Arc::new(HostFn(Box::new(f)));
+        unsafe { hipGraphExecHostNodeSetParams(self.raw, node.0.raw, &host_params(&f)) }
+            .into_result()?;
+        // The previous function is kept, since a launch may still call it.
+        self.host_fns.push(f);
+        Ok(())
+    }
+
+    /// Change the graph a child graph node runs. It must have the same
+    /// topology as the one it replaces.
+    pub fn set_child(&mut self, node: ChildGraphNode, child: &Graph<'a>) -> Result<()> {
+        unsafe { hipGraphExecChildGraphNodeSetParams(self.raw, node.0.raw, child.raw) }
+            .into_result()?;
+        self.host_fns.extend(child.host_fns.iter().cloned());
+        Ok(())
+    }
+
+    fn set_copy_raw(
+        &mut self,
+        node: CopyNode,
+        dst: *mut c_void,
+        src: *const c_void,
+        bytes: usize,
+        kind: hipMemcpyKind,
+    ) -> Result<()> {
+        unsafe { hipGraphExecMemcpyNodeSetParams1D(self.raw, node.0.raw, dst, src, bytes, kind) }
+            .into_result()
+    }
+}
+
+impl Drop for ExecutableGraph<'_> {
+    fn drop(&mut self) {
+        // The borrows end with the executable graph, so launches have to
+        // finish first.
+        let _ = self.done.synchronize();
+        let _ = unsafe { hipGraphExecDestroy(self.raw) };
+    }
+}
+
+impl fmt::Debug for ExecutableGraph<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecutableGraph")
+            .field("raw", &self.raw)
+            .finish()
+    }
+}
+
+/// Why [`ExecutableGraph::update`] couldn't update a graph in place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GraphUpdateError {
+    /// The error returned by `hipGraphExecUpdate`, or
+    /// `hipErrorGraphExecUpdateFailure` if it rejected the update without
+    /// returning one.
+    pub error: HipError,
+    /// What prevented the update.
+    pub result: hipGraphExecUpdateResult,
+    /// The node that prevented the update, if there was one.
+    pub node: Option<GraphNode>,
+}
+
+impl fmt::Display for GraphUpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "graph update failed with {:?}: {}",
+            self.result, self.error
+        )
+    }
+}
+
+impl std::error::Error for GraphUpdateError {}
+
+impl From<GraphUpdateError> for HipError {
+    fn from(e: GraphUpdateError) -> HipError {
+        e.error
+    }
+}
+
+impl Stream {
+    /// Capture the work `f` queues into a graph instead of running it. Memory
+    /// it uses is borrowed for as long as the graph, or any executable graph
+    /// instantiated from it, exists.
+    ///
+    /// Synchronising with the stream inside `f`, e.g. with
+    /// [`Stream::scope`], invalidates the capture, and the capture then
+    /// fails.
+    ///
+    /// ```no_run
+    /// # use hip_runtime_sys::{DeviceBuffer, Stream};
+    /// # fn main() -> hip_runtime_sys::Result<()> {
+    /// let stream = Stream::new()?;
+    /// let host = vec![1.0f32; 1024];
+    /// let mut buffer = unsafe { DeviceBuffer::<f32>::uninitialized(host.len())? };
+    /// let graph = stream.capture(|s| s.copy_from_host(buffer.as_slice_mut(), &host))?;
+    /// let mut exec = graph.instantiate()?;
+    /// exec.launch(&stream)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capture<'a, F>(&'a self, f: F) -> Result<Graph<'a>>
+    where
+        F: FnOnce(&StreamScope<'a, 'a>) -> Result<()>,
+    {
+        self.capture_with_mode(hipStreamCaptureMode::hipStreamCaptureModeGlobal, f)
+    }
+
+    /// Like [`Stream::capture`], with a capture mode controlling which HIP
+    /// calls other threads may make during the capture.
+    pub fn capture_with_mode<'a, F>(&'a self, mode: hipStreamCaptureMode, f: F) -> Result<Graph<'a>>
+    where
+        F: FnOnce(&StreamScope<'a, 'a>) -> Result<()>,
+    {
+        struct EndCapture<'a>(&'a Stream);
+        impl Drop for EndCapture<'_> {
+            fn drop(&mut self) {
+                let mut raw = ptr::null_mut();
+                if unsafe { hipStreamEndCapture(self.0.as_raw(), &mut raw) }
+                    .into_result()
+                    .is_ok()
+                    && !raw.is_null()
+                {
+                    let _ = unsafe { hipGraphDestroy(raw) };
+                }
+            }
+        }
+
+        unsafe { hipStreamBeginCapture(self.as_raw(), mode) }.into_result()?;
+        let guard = EndCapture(self);
+        // Safety: nothing queued runs until the graph is launched, and the
+        // graph borrows everything for `'a`.
+        let result = f(&unsafe { StreamScope::new(self) });
+        mem::forget(guard);
+        let mut raw = ptr::null_mut();
+        let end = unsafe { hipStreamEndCapture(self.as_raw(), &mut raw) }.into_result();
+        let graph = (!raw.is_null()).then(|| unsafe { Graph::from_raw(raw) });
+        result?;
+        end?;
+        graph.ok_or(HipError::from(hipError_t::hipErrorStreamCaptureInvalidated))
+    }
+}
+
+fn memset_params<T: Copy>(mut dst: DeviceSliceMut<'_, T>, value: u8) -> hipMemsetParams {
+    hipMemsetParams {
+        dst: dst.as_mut_ptr() as *mut c_void,
+        elementSize: 1,
+        height: 1,
+        pitch: 0,
+        value: value.into(),
+        width: dst.len() * mem::size_of::<T>(),
+    }
+}
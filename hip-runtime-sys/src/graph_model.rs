@@ -0,0 +1,446 @@
+//! Reading back the structure of a [`Graph`], and exporting it as DOT or
+//! JSON for debugging.
+//!
+//! A [`GraphModel`] is plain data, so it can be built by hand and exported
+//! without a device.
+
+use crate::{
+    hipError_t, hipGraphChildGraphNodeGetGraph, hipGraphEventRecordNodeGetEvent,
+    hipGraphEventWaitNodeGetEvent, hipGraphGetEdges, hipGraphGetNodes, hipGraphGetRootNodes,
+    hipGraphHostNodeGetParams, hipGraphKernelNodeGetParams, hipGraphMemcpyNodeGetParams,
+    hipGraphMemsetNodeGetParams, hipGraphNodeGetType, hipGraphNodeType, hipGraphNode_t, hipGraph_t,
+    hipMemcpyKind, Graph, GraphNode, Result,
+};
+use std::{collections::HashMap, fmt::Write, mem::MaybeUninit, os::raw::c_uint, ptr};
+
+/// The nodes of a graph and the dependencies between them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphModel {
+    /// The nodes, identified by their index.
+    pub nodes: Vec<NodeModel>,
+    /// Dependencies as `(from, to)` node indices: `to` runs after `from`.
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// A node of a [`GraphModel`] and its parameters. Pointers are recorded as
+/// addresses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NodeModel {
+    /// A kernel launch.
+    Kernel {
+        function: usize,
+        grid: [u32; 3],
+        block: [u32; 3],
+        shared_mem_bytes: u32,
+    },
+    /// A copy of `extent` (width in bytes, height, depth) from `src` to
+    /// `dst`.
+    Copy {
+        kind: hipMemcpyKind,
+        src: usize,
+        dst: usize,
+        extent: [usize; 3],
+    },
+    /// A fill of `height` rows of `width` elements, `pitch` bytes apart.
+    Memset {
+        dst: usize,
+        value: u32,
+        element_size: u32,
+        width: usize,
+        height: usize,
+        pitch: usize,
+    },
+    /// A host function call.
+    Host { function: usize, data: usize },
+    /// A copy of another graph.
+    ChildGraph(GraphModel),
+    /// An event record.
+    EventRecord { event: usize },
+    /// A wait for an event.
+    WaitEvent { event: usize },
+    /// A node that does nothing.
+    Empty,
+    /// A node type without a model, by its raw `hipGraphNodeType` value,
+    /// which may be newer than the bindings.
+    Other(u32),
+}
+
+impl Graph<'_> {
+    /// The graph's nodes.
+    pub fn nodes(&self) -> Result<Vec<GraphNode>> {
+        node_list(|nodes, count| unsafe { hipGraphGetNodes(self.as_raw(), nodes, count) })
+    }
+
+    /// The nodes without dependencies.
+    pub fn root_nodes(&self) -> Result<Vec<GraphNode>> {
+        node_list(|nodes, count| unsafe { hipGraphGetRootNodes(self.as_raw(), nodes, count) })
+    }
+
+    /// Dependencies as `(from, to)` pairs: `to` runs after `from`.
+    pub fn edges(&self) -> Result<Vec<(GraphNode, GraphNode)>> {
+        edges(self.as_raw())
+    }
+
+    /// Read back the graph's nodes, their parameters, and the dependencies
+    /// between them.
+    pub fn model(&self) -> Result<GraphModel> {
+        model(self.as_raw())
+    }
+}
+
+impl GraphModel {
+    /// The indices of the nodes without dependencies.
+    pub fn roots(&self) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&node| self.edges.iter().all(|&(_, to)| to != node))
+            .collect()
+    }
+
+    /// The graph in Graphviz DOT format. Child graphs are drawn as clusters,
+    /// with dashed edges from their nodes to the child's roots.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        self.write_dot(&mut dot, "n", 1);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The graph as a JSON object with `nodes` and `edges` arrays. Addresses
+    /// are hex strings, since they may not fit in a JSON number.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    fn write_dot(&self, dot: &mut String, prefix: &str, depth: usize) {
+        let indent = "    ".repeat(depth);
+        for (i, node) in self.nodes.iter().enumerate() {
+            let id = format!("{}{}", prefix, i);
+            let _ = writeln!(dot, "{}{} [label=\"{}\"];", indent, id, node.label(i));
+            if let NodeModel::ChildGraph(child) = node {
+                let _ = writeln!(dot, "{}subgraph cluster_{} {{", indent, id);
+                let _ = writeln!(dot, "{}    label=\"{}\";", indent, id);
+                let child_prefix = format!("{}_", id);
+                child.write_dot(dot, &child_prefix, depth + 1);
+                let _ = writeln!(dot, "{}}}", indent);
+                for root in child.roots() {
+                    let _ = writeln!(
+                        dot,
+                        "{}{} -> {}{} [style=dashed];",
+                        indent, id, child_prefix, root
+                    );
+                }
+            }
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(dot, "{}{}{} -> {}{};", indent, prefix, from, prefix, to);
+        }
+    }
+
+    fn write_json(&self, json: &mut String) {
+        json.push_str("{\"nodes\":[");
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "{{\"id\":{},", i);
+            node.write_json_fields(json);
+            json.push('}');
+        }
+        json.push_str("],\"edges\":[");
+        for (i, (from, to)) in self.edges.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "[{},{}]", from, to);
+        }
+        json.push_str("]}");
+    }
+}
+
+impl NodeModel {
+    /// The DOT label, with lines separated by `\n` escapes.
+    fn label(&self, index: usize) -> String {
+        match self {
+            NodeModel::Kernel {
+                function,
+                grid,
+                block,
+                shared_mem_bytes,
+            } => format!(
+                "{}: kernel {:#x}\\ngrid {}x{}x{}, block {}x{}x{}\\nshared {} bytes",
+                index,
+                function,
+                grid[0],
+                grid[1],
+                grid[2],
+                block[0],
+                block[1],
+                block[2],
+                shared_mem_bytes
+            ),
+            NodeModel::Copy {
+                kind,
+                src,
+                dst,
+                extent,
+            } => format!(
+                "{}: copy {:?}\\n{:#x} -> {:#x}\\n{}x{}x{} bytes",
+                index, kind, src, dst, extent[0], extent[1], extent[2]
+            ),
+            NodeModel::Memset {
+                dst,
+                value,
+                element_size,
+                width,
+                height,
+                ..
+            } => format!(
+                "{}: memset {:#x} = {:#x}\\n{}x{} x {} bytes",
+                index, dst, value, width, height, element_size
+            ),
+            NodeModel::Host { function, data } => {
+                format!("{}: host {:#x}\\ndata {:#x}", index, function, data)
+            }
+            NodeModel::ChildGraph(child) => {
+                format!("{}: child graph\\n{} nodes", index, child.nodes.len())
+            }
+            NodeModel::EventRecord { event } => format!("{}: record {:#x}", index, event),
+            NodeModel::WaitEvent { event } => format!("{}: wait {:#x}", index, event),
+            NodeModel::Empty => format!("{}: empty", index),
+            NodeModel::Other(node_type) => format!("{}: {}", index, node_type_name(*node_type)),
+        }
+    }
+
+    fn write_json_fields(&self, json: &mut String) {
+        let _ = match self {
+            NodeModel::Kernel {
+                function,
+                grid,
+                block,
+                shared_mem_bytes,
+            } => write!(
+                json,
+                "\"type\":\"kernel\",\"function\":\"{:#x}\",\"grid\":[{},{},{}],\
+                 \"block\":[{},{},{}],\"shared_mem_bytes\":{}",
+                function, grid[0], grid[1], grid[2], block[0], block[1], block[2], shared_mem_bytes
+            ),
+            NodeModel::Copy {
+                kind,
+                src,
+                dst,
+                extent,
+            } => write!(
+                json,
+                "\"type\":\"copy\",\"kind\":\"{:?}\",\"src\":\"{:#x}\",\"dst\":\"{:#x}\",\
+                 \"extent\":[{},{},{}]",
+                kind, src, dst, extent[0], extent[1], extent[2]
+            ),
+            NodeModel::Memset {
+                dst,
+                value,
+                element_size,
+                width,
+                height,
+                pitch,
+            } => write!(
+                json,
+                "\"type\":\"memset\",\"dst\":\"{:#x}\",\"value\":{},\"element_size\":{},\
+                 \"width\":{},\"height\":{},\"pitch\":{}",
+                dst, value, element_size, width, height, pitch
+            ),
+            NodeModel::Host { function, data } => write!(
+                json,
+                "\"type\":\"host\",\"function\":\"{:#x}\",\"data\":\"{:#x}\"",
+                function, data
+            ),
+            NodeModel::ChildGraph(child) => {
+                json.push_str("\"type\":\"child_graph\",\"graph\":");
+                child.write_json(json);
+                Ok(())
+            }
+            NodeModel::EventRecord { event } => {
+                write!(json, "\"type\":\"event_record\",\"event\":\"{:#x}\"", event)
+            }
+            NodeModel::WaitEvent { event } => {
+                write!(json, "\"type\":\"wait_event\",\"event\":\"{:#x}\"", event)
+            }
+            NodeModel::Empty => write!(json, "\"type\":\"empty\""),
+            NodeModel::Other(node_type) => {
+                write!(
+                    json,
+                    "\"type\":\"other\",\"node_type\":\"{}\"",
+                    node_type_name(*node_type)
+                )
+            }
+        };
+    }
+}
+
+fn model(graph: hipGraph_t) -> Result<GraphModel> {
+    let nodes = node_list(|nodes, count| unsafe { hipGraphGetNodes(graph, nodes, count) })?;
+    let index: HashMap<hipGraphNode_t, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.as_raw(), i))
+        .collect();
+    let edges = edges(graph)?
+        .into_iter()
+        .filter_map(|(from, to)| Some((index.get(&from.as_raw())?, index.get(&to.as_raw())?)))
+        .map(|(&from, &to)| (from, to))
+        .collect();
+    Ok(GraphModel {
+        nodes: nodes
+            .into_iter()
+            .map(|node| node_model(node.as_raw()))
+            .collect::<Result<_>>()?,
+        edges,
+    })
+}
+
+/// Every [`hipGraphNodeType`] in the bindings. The runtime may return newer
+/// types, such as memory allocation nodes, which a `hipGraphNodeType` can't
+/// hold.
+const NODE_TYPES: &[hipGraphNodeType] = &[
+    hipGraphNodeType::hipGraphNodeTypeKernel,
+    hipGraphNodeType::hipGraphNodeTypeMemcpy,
+    hipGraphNodeType::hipGraphNodeTypeMemset,
+    hipGraphNodeType::hipGraphNodeTypeHost,
+    hipGraphNodeType::hipGraphNodeTypeGraph,
+    hipGraphNodeType::hipGraphNodeTypeEmpty,
+    hipGraphNodeType::hipGraphNodeTypeWaitEvent,
+    hipGraphNodeType::hipGraphNodeTypeEventRecord,
+    hipGraphNodeType::hipGraphNodeTypeExtSemaphoreSignal,
+    hipGraphNodeType::hipGraphNodeTypeExtSemaphoreWait,
+    hipGraphNodeType::hipGraphNodeTypeMemcpyFromSymbol,
+    hipGraphNodeType::hipGraphNodeTypeMemcpyToSymbol,
+];
+
+fn known_node_type(raw: u32) -> Option<hipGraphNodeType> {
+    NODE_TYPES
+        .iter()
+        .copied()
+        .find(|&node_type| node_type as u32 == raw)
+}
+
+/// The name of a raw node type, if it's in the bindings.
+fn node_type_name(raw: u32) -> String {
+    match known_node_type(raw) {
+        Some(node_type) => format!("{:?}", node_type),
+        None => format!("hipGraphNodeType({})", raw),
+    }
+}
+
+fn node_model(node: hipGraphNode_t) -> Result<NodeModel> {
+    // Read into an integer, as the runtime may return a type the bindings'
+    // enum has no variant for.
+    let mut raw_type: c_uint = 0;
+    unsafe { hipGraphNodeGetType(node, &mut raw_type as *mut c_uint as *mut hipGraphNodeType) }
+        .into_result()?;
+    Ok(match known_node_type(raw_type) {
+        Some(hipGraphNodeType::hipGraphNodeTypeKernel) => {
+            let params = get_params(|params| unsafe { hipGraphKernelNodeGetParams(node, params) })?;
+            NodeModel::Kernel {
+                function: params.func as usize,
+                grid: [params.gridDim.x, params.gridDim.y, params.gridDim.z],
+                block: [params.blockDim.x, params.blockDim.y, params.blockDim.z],
+                shared_mem_bytes: params.sharedMemBytes,
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeMemcpy) => {
+            let params = get_params(|params| unsafe { hipGraphMemcpyNodeGetParams(node, params) })?;
+            let address = |ptr: usize, array: usize| if ptr != 0 { ptr } else { array };
+            NodeModel::Copy {
+                kind: params.kind,
+                src: address(params.srcPtr.ptr as usize, params.srcArray as usize),
+                dst: address(params.dstPtr.ptr as usize, params.dstArray as usize),
+                extent: [
+                    params.extent.width,
+                    params.extent.height,
+                    params.extent.depth,
+                ],
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeMemset) => {
+            let params = get_params(|params| unsafe { hipGraphMemsetNodeGetParams(node, params) })?;
+            NodeModel::Memset {
+                dst: params.dst as usize,
+                value: params.value,
+                element_size: params.elementSize,
+                width: params.width,
+                height: params.height,
+                pitch: params.pitch,
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeHost) => {
+            let params = get_params(|params| unsafe { hipGraphHostNodeGetParams(node, params) })?;
+            NodeModel::Host {
+                function: params.fn_.map_or(0, |f| f as usize),
+                data: params.userData as usize,
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeGraph) => {
+            // The child graph is owned by the node.
+            let mut child = ptr::null_mut();
+            unsafe { hipGraphChildGraphNodeGetGraph(node, &mut child) }.into_result()?;
+            NodeModel::ChildGraph(model(child)?)
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeEventRecord) => {
+            let mut event = ptr::null_mut();
+            unsafe { hipGraphEventRecordNodeGetEvent(node, &mut event) }.into_result()?;
+            NodeModel::EventRecord {
+                event: event as usize,
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeWaitEvent) => {
+            let mut event = ptr::null_mut();
+            unsafe { hipGraphEventWaitNodeGetEvent(node, &mut event) }.into_result()?;
+            NodeModel::WaitEvent {
+                event: event as usize,
+            }
+        }
+        Some(hipGraphNodeType::hipGraphNodeTypeEmpty) => NodeModel::Empty,
+        _ => NodeModel::Other(raw_type),
+    })
+}
+
+fn edges(graph: hipGraph_t) -> Result<Vec<(GraphNode, GraphNode)>> {
+    let mut count = 0;
+    unsafe { hipGraphGetEdges(graph, ptr::null_mut(), ptr::null_mut(), &mut count) }
+        .into_result()?;
+    let mut from = vec![ptr::null_mut(); count];
+    let mut to = vec![ptr::null_mut(); count];
+    unsafe { hipGraphGetEdges(graph, from.as_mut_ptr(), to.as_mut_ptr(), &mut count) }
+        .into_result()?;
+    Ok(from
+        .into_iter()
+        .zip(to)
+        .take(count)
+        .map(|(from, to)| unsafe { (GraphNode::from_raw(from), GraphNode::from_raw(to)) })
+        .collect())
+}
+
+/// Call a `hipGraph*` function that fills an array of nodes, first to get
+/// the count and then the nodes.
+fn node_list(
+    get: impl Fn(*mut hipGraphNode_t, *mut usize) -> hipError_t,
+) -> Result<Vec<GraphNode>> {
+    let mut count = 0;
+    get(ptr::null_mut(), &mut count).into_result()?;
+    let mut nodes = vec![ptr::null_mut(); count];
+    get(nodes.as_mut_ptr(), &mut count).into_result()?;
+    nodes.truncate(count);
+    Ok(nodes
+        .into_iter()
+        .map(|raw| unsafe { GraphNode::from_raw(raw) })
+        .collect())
+}
+
+/// Call a `hipGraph*NodeGetParams` function.
+fn get_params<P>(get: impl FnOnce(*mut P) -> hipError_t) -> Result<P> {
+    let mut params = MaybeUninit::<P>::zeroed();
+    get(params.as_mut_ptr()).into_result()?;
+    // Safety: the parameters are plain data, written by the runtime.
+    Ok(unsafe { params.assume_init() })
+}
@@ -0,0 +1,220 @@
+//! Page-locked host memory, which the device can access directly and which
+//! makes asynchronous copies truly asynchronous.
+
+use crate::memory::{byte_size, non_null};
+use crate::{
+    hipError_t, hipHostFree, hipHostGetDevicePointer, hipHostMalloc, hipHostRegister,
+    hipHostUnregister, HipError, Result,
+};
+use std::{
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+flags! {
+    /// Flags for allocating a [`PinnedHostBuffer`].
+    pub struct HostMallocFlags {
+        /// Usable by every device, not just the current one.
+        const PORTABLE = hipHostMallocPortable;
+        /// Mapped into the device address space; see
+        /// [`PinnedHostBuffer::device_ptr`].
+        const MAPPED = hipHostMallocMapped;
+        /// Write-combined: faster for the device to read and the host to
+        /// write, but slow for the host to read.
+        const WRITE_COMBINED = hipHostMallocWriteCombined;
+        /// Coherent with the device, without caching on it.
+        const COHERENT = hipHostMallocCoherent;
+        /// Cached on the device, without coherence.
+        const NON_COHERENT = hipHostMallocNonCoherent;
+    }
+}
+
+flags! {
+    /// Flags for pinning existing memory with [`HostRegistration`].
+    pub struct HostRegisterFlags {
+        /// Usable by every device, not just the current one.
+        const PORTABLE = hipHostRegisterPortable;
+        /// Mapped into the device address space; see
+        /// [`HostRegistration::device_ptr`].
+        const MAPPED = hipHostRegisterMapped;
+    }
+}
+
+/// An allocation of `len` values of `T` in page-locked host memory, freed on
+/// drop. Dereferences to `[T]`.
+pub struct PinnedHostBuffer<T: Copy> {
+    // Null if nothing was allocated, i.e. the buffer is zero-sized.
+    ptr: *mut T,
+    len: usize,
+    flags: HostMallocFlags,
+}
+
+// Safety: the buffer owns its allocation like a `Vec`.
+unsafe impl<T: Copy + Send> Send for PinnedHostBuffer<T> {}
+unsafe impl<T: Copy + Sync> Sync for PinnedHostBuffer<T> {}
+
+impl<T: Copy> PinnedHostBuffer<T> {
+    /// Allocate space for `len` values without initialising it.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The contents must be written before they're read.
+    pub unsafe fn uninitialized(len: usize, flags: HostMallocFlags) -> Result<PinnedHostBuffer<T>> {
+        let bytes = byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let mut ptr = ptr::null_mut();
+        if bytes > 0 {
+            hipHostMalloc(&mut ptr, bytes, flags.bits()).into_result()?;
+        }
+        Ok(PinnedHostBuffer {
+            ptr: ptr as *mut T,
+            len,
+            flags,
+        })
+    }
+
+    /// Allocate a buffer holding a copy of `values`.
+    pub fn from_slice(values: &[T], flags: HostMallocFlags) -> Result<PinnedHostBuffer<T>> {
+        // Safety: every element is written before the buffer is returned.
+        let mut buffer = unsafe { PinnedHostBuffer::uninitialized(values.len(), flags)? };
+        buffer.copy_from_slice(values);
+        Ok(buffer)
+    }
+
+    /// The flags the buffer was allocated with.
+    pub fn flags(&self) -> HostMallocFlags {
+        self.flags
+    }
+
+    /// The pointer through which the device can access the buffer. It must
+    /// have been allocated with [`HostMallocFlags::MAPPED`].
+    pub fn device_ptr(&self) -> Result<*mut T> {
+        device_ptr(self.ptr)
+    }
+}
+
+impl<T: Copy> Deref for PinnedHostBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(non_null(self.ptr), self.len) }
+    }
+}
+
+impl<T: Copy> DerefMut for PinnedHostBuffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(non_null(self.ptr), self.len) }
+    }
+}
+
+impl<T: Copy> Drop for PinnedHostBuffer<T> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            let _ = unsafe { hipHostFree(self.ptr as *mut c_void) };
+        }
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for PinnedHostBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Existing host memory pinned for as long as it's borrowed, and unpinned on
+/// drop. Dereferences to `[T]`.
+///
+/// ```no_run
+/// # use hip_runtime_sys::{DeviceBuffer, HostRegisterFlags, HostRegistration, Stream};
+/// # fn main() -> hip_runtime_sys::Result<()> {
+/// let mut values = vec![0u32; 1 << 20];
+/// let mut pinned = HostRegistration::new(&mut values, HostRegisterFlags::empty())?;
+/// let buffer = DeviceBuffer::from_slice(&[1; 1 << 20])?;
+/// let stream = Stream::new()?;
+/// stream.scope(|s| s.copy_to_host(buffer.as_slice(), &mut pinned))??;
+/// # Ok(())
+/// # }
+/// ```
+pub struct HostRegistration<'a, T: Copy> {
+    ptr: *mut T,
+    len: usize,
+    flags: HostRegisterFlags,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+// Safety: the registration is a unique borrow of the memory.
+unsafe impl<T: Copy + Send> Send for HostRegistration<'_, T> {}
+unsafe impl<T: Copy + Sync> Sync for HostRegistration<'_, T> {}
+
+impl<'a, T: Copy> HostRegistration<'a, T> {
+    /// Pin `values`, e.g. the contents of a `Vec`, until the registration
+    /// is dropped.
+    pub fn new(values: &'a mut [T], flags: HostRegisterFlags) -> Result<HostRegistration<'a, T>> {
+        let ptr = values.as_mut_ptr();
+        let bytes = std::mem::size_of_val(values);
+        if bytes > 0 {
+            unsafe { hipHostRegister(ptr as *mut c_void, bytes, flags.bits()) }.into_result()?;
+        }
+        Ok(HostRegistration {
+            ptr,
+            len: values.len(),
+            flags,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The flags the memory was registered with.
+    pub fn flags(&self) -> HostRegisterFlags {
+        self.flags
+    }
+
+    /// The pointer through which the device can access the memory. It must
+    /// have been registered with [`HostRegisterFlags::MAPPED`].
+    pub fn device_ptr(&self) -> Result<*mut T> {
+        device_ptr(self.ptr)
+    }
+
+    fn is_registered(&self) -> bool {
+        self.len * std::mem::size_of::<T>() > 0
+    }
+}
+
+impl<T: Copy> Deref for HostRegistration<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy> DerefMut for HostRegistration<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy> Drop for HostRegistration<'_, T> {
+    fn drop(&mut self) {
+        if self.is_registered() {
+            let _ = unsafe { hipHostUnregister(self.ptr as *mut c_void) };
+        }
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for HostRegistration<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+fn device_ptr<T>(host: *mut T) -> Result<*mut T> {
+    if host.is_null() {
+        return Err(HipError::from(hipError_t::hipErrorInvalidValue));
+    }
+    let mut ptr = ptr::null_mut();
+    unsafe { hipHostGetDevicePointer(&mut ptr, host as *mut c_void, 0) }.into_result()?;
+    Ok(ptr as *mut T)
+}
@@ -0,0 +1,389 @@
+//! Sharing device memory, events and memory pools with other processes.
+//!
+//! Handles are plain bytes that can be sent to another process however is
+//! convenient, and with the `serde` feature they can be serialised directly.
+//! File descriptors have to be passed over a Unix socket instead.
+
+use crate::memory::{byte_size, non_null};
+use crate::{
+    hipError_t, hipEvent_t, hipIpcCloseMemHandle, hipIpcEventHandle_t, hipIpcGetEventHandle,
+    hipIpcGetMemHandle, hipIpcMemHandle_t, hipIpcMemLazyEnablePeerAccess, hipIpcOpenEventHandle,
+    hipIpcOpenMemHandle, hipMemPoolExportPointer, hipMemPoolImportPointer, hipMemPoolPtrExportData,
+    Device, DeviceBuffer, DeviceSlice, DeviceSliceMut, Event, HipError, MemPool, PoolBuffer,
+    Result,
+};
+use std::{ffi::c_void, fmt, ptr};
+
+/// Define a copyable handle type over the 64 opaque bytes of a raw handle.
+macro_rules! ipc_handle {
+    ($(#[$meta:meta])* pub struct $name:ident($raw:ident);) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name([u8; 64]);
+
+        impl $name {
+            /// A handle from bytes received from another process.
+            pub const fn from_bytes(bytes: [u8; 64]) -> $name {
+                $name(bytes)
+            }
+
+            /// The bytes to send to another process.
+            pub const fn as_bytes(&self) -> &[u8; 64] {
+                &self.0
+            }
+        }
+
+        impl From<$raw> for $name {
+            fn from(raw: $raw) -> $name {
+                $name(raw.reserved.map(|byte| byte as u8))
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(handle: $name) -> $raw {
+                $raw {
+                    reserved: handle.0.map(|byte| byte as _),
+                }
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!(stringify!($name), "("))?;
+                for byte in &self.0 {
+                    write!(f, "{:02x}", byte)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(&self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<$name, D::Error> {
+                deserializer.deserialize_bytes(serde_bytes::HandleVisitor).map($name)
+            }
+        }
+    };
+}
+
+ipc_handle! {
+    /// A handle through which another process can map a [`DeviceBuffer`]
+    /// with [`IpcMappedBuffer::open`].
+    pub struct IpcMemHandle(hipIpcMemHandle_t);
+}
+
+ipc_handle! {
+    /// A handle through which another process can open an [`Event`] with
+    /// [`Event::open_ipc`].
+    pub struct IpcEventHandle(hipIpcEventHandle_t);
+}
+
+ipc_handle! {
+    /// A handle through which another process can import a [`PoolBuffer`]
+    /// with [`MemPool::import_pointer`], having imported its pool.
+    pub struct PoolPointerHandle(hipMemPoolPtrExportData);
+}
+
+impl<T: Copy> DeviceBuffer<T> {
+    /// A handle through which another process can map the buffer. The
+    /// buffer must outlive every mapping of it.
+    pub fn ipc_handle(&self) -> Result<IpcMemHandle> {
+        let mut raw = IpcMemHandle([0; 64]).into();
+        unsafe { hipIpcGetMemHandle(&mut raw, self.as_ptr() as *mut c_void) }.into_result()?;
+        Ok(raw.into())
+    }
+}
+
+impl Event {
+    /// A handle through which another process can open the event. It must
+    /// have been created with `hipEventInterprocess | hipEventDisableTiming`.
+    pub fn ipc_handle(&self) -> Result<IpcEventHandle> {
+        let mut raw = IpcEventHandle([0; 64]).into();
+        unsafe { hipIpcGetEventHandle(&mut raw, self.as_raw()) }.into_result()?;
+        Ok(raw.into())
+    }
+
+    /// Open an event shared by another process on the current device. The
+    /// event is destroyed in this process on drop, and must not be used
+    /// after the other process destroys it.
+    pub fn open_ipc(handle: &IpcEventHandle) -> Result<Event> {
+        let device = Device::current()?;
+        let mut raw: hipEvent_t = ptr::null_mut();
+        unsafe { hipIpcOpenEventHandle(&mut raw, (*handle).into()) }.into_result()?;
+        Ok(unsafe { Event::from_raw(raw, device) })
+    }
+}
+
+/// A [`DeviceBuffer`] shared by another process, mapped into this one until
+/// it's dropped.
+pub struct IpcMappedBuffer<T: Copy> {
+    ptr: *mut T,
+    len: usize,
+}
+
+// Safety: the mapping is owned, and device memory can be used from any
+// thread.
+unsafe impl<T: Copy + Send> Send for IpcMappedBuffer<T> {}
+unsafe impl<T: Copy + Sync> Sync for IpcMappedBuffer<T> {}
+
+impl<T: Copy> IpcMappedBuffer<T> {
+    /// Map `len` values of the buffer behind `handle`, enabling peer access
+    /// if it's on another device.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The shared buffer must hold at least `len` valid values of `T`, and
+    /// must not be freed by the other process until the mapping is dropped.
+    pub unsafe fn open(handle: &IpcMemHandle, len: usize) -> Result<IpcMappedBuffer<T>> {
+        byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let mut ptr = ptr::null_mut();
+        hipIpcOpenMemHandle(&mut ptr, (*handle).into(), hipIpcMemLazyEnablePeerAccess)
+            .into_result()?;
+        Ok(IpcMappedBuffer {
+            ptr: ptr as *mut T,
+            len,
+        })
+    }
+
+    /// The number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The device pointer to the start of the buffer.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// The mutable device pointer to the start of the buffer.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// A view of the whole buffer.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        unsafe { DeviceSlice::from_raw_parts(non_null(self.ptr), self.len, None) }
+    }
+
+    /// A mutable view of the whole buffer.
+    pub fn as_slice_mut(&mut self) -> DeviceSliceMut<'_, T> {
+        unsafe { DeviceSliceMut::from_raw_parts(non_null(self.ptr), self.len, None) }
+    }
+}
+
+impl<T: Copy> Drop for IpcMappedBuffer<T> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipIpcCloseMemHandle(self.ptr as *mut c_void) };
+    }
+}
+
+impl<T: Copy> fmt::Debug for IpcMappedBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IpcMappedBuffer")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T: Copy> PoolBuffer<'_, T> {
+    /// A handle through which another process that has imported the pool
+    /// can import the buffer. The pool must have been created with a
+    /// shareable handle type, and the buffer must outlive every import.
+    pub fn export_pointer(&self) -> Result<PoolPointerHandle> {
+        let mut raw = PoolPointerHandle([0; 64]).into();
+        unsafe { hipMemPoolExportPointer(&mut raw, self.as_ptr() as *mut c_void) }.into_result()?;
+        Ok(raw.into())
+    }
+}
+
+impl MemPool {
+    /// Import `len` values of a buffer allocated from this pool by another
+    /// process, freeing the import on drop.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The pool must have been imported from the other process, the shared
+    /// buffer must hold at least `len` valid values of `T`, and it must not
+    /// be freed by the other process until the import is dropped.
+    pub unsafe fn import_pointer<T: Copy>(
+        &self,
+        handle: &PoolPointerHandle,
+        len: usize,
+    ) -> Result<DeviceBuffer<T>> {
+        byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let device = Device::current()?;
+        let mut raw = (*handle).into();
+        let mut ptr = ptr::null_mut();
+        hipMemPoolImportPointer(&mut ptr, self.as_raw(), &mut raw).into_result()?;
+        Ok(DeviceBuffer::from_raw_parts(ptr as *mut T, len, device))
+    }
+}
+
+#[cfg(unix)]
+pub use self::fd::ShareableFd;
+
+#[cfg(unix)]
+mod fd {
+    use crate::{
+        hipMemAllocationHandleType, hipMemExportToShareableHandle, hipMemImportFromShareableHandle,
+        hipMemPoolExportToShareableHandle, hipMemPoolImportFromShareableHandle, MemPool,
+        PhysicalMemory, Result,
+    };
+    use std::{
+        ffi::c_void,
+        os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
+        ptr,
+    };
+
+    const POSIX_FD: hipMemAllocationHandleType =
+        hipMemAllocationHandleType::hipMemHandleTypePosixFileDescriptor;
+
+    /// A file descriptor for a [`MemPool`] or [`PhysicalMemory`], closed on
+    /// drop. Pass it to another process over a Unix socket.
+    #[derive(Debug)]
+    pub struct ShareableFd(RawFd);
+
+    impl AsRawFd for ShareableFd {
+        fn as_raw_fd(&self) -> RawFd {
+            self.0
+        }
+    }
+
+    impl IntoRawFd for ShareableFd {
+        fn into_raw_fd(self) -> RawFd {
+            let fd = self.0;
+            std::mem::forget(self);
+            fd
+        }
+    }
+
+    impl FromRawFd for ShareableFd {
+        unsafe fn from_raw_fd(fd: RawFd) -> ShareableFd {
+            ShareableFd(fd)
+        }
+    }
+
+    impl Drop for ShareableFd {
+        fn drop(&mut self) {
+            let _ = unsafe { libc::close(self.0) };
+        }
+    }
+
+    impl MemPool {
+        /// Export the pool as a file descriptor. It must have been created
+        /// with `hipMemHandleTypePosixFileDescriptor`.
+        pub fn export_fd(&self) -> Result<ShareableFd> {
+            let mut fd: RawFd = -1;
+            unsafe {
+                hipMemPoolExportToShareableHandle(
+                    &mut fd as *mut RawFd as *mut c_void,
+                    self.as_raw(),
+                    POSIX_FD,
+                    0,
+                )
+            }
+            .into_result()?;
+            Ok(ShareableFd(fd))
+        }
+
+        /// Import a pool exported by another process. The pool is destroyed
+        /// in this process on drop.
+        pub fn import_fd(fd: &impl AsRawFd) -> Result<MemPool> {
+            let mut raw = ptr::null_mut();
+            unsafe { hipMemPoolImportFromShareableHandle(&mut raw, fd_handle(fd), POSIX_FD, 0) }
+                .into_result()?;
+            Ok(unsafe { MemPool::from_raw(raw) })
+        }
+    }
+
+    impl PhysicalMemory {
+        /// Allocate at least `size` bytes of physical memory on `device`
+        /// that can be exported with [`PhysicalMemory::export_fd`].
+        pub fn shareable(device: crate::Device, size: usize) -> Result<PhysicalMemory> {
+            PhysicalMemory::with_handle_type(device, size, POSIX_FD)
+        }
+
+        /// Export the memory as a file descriptor.
+        pub fn export_fd(&self) -> Result<ShareableFd> {
+            let mut fd: RawFd = -1;
+            unsafe {
+                hipMemExportToShareableHandle(
+                    &mut fd as *mut RawFd as *mut c_void,
+                    self.as_raw(),
+                    POSIX_FD,
+                    0,
+                )
+            }
+            .into_result()?;
+            Ok(ShareableFd(fd))
+        }
+
+        /// Import `size` bytes of memory exported by another process, e.g.
+        /// to map into a [`VirtualRange`](crate::VirtualRange).
+        ///
+        /// # Safety
+        /// `size` must be the size of the exported memory.
+        pub unsafe fn import_fd(fd: &impl AsRawFd, size: usize) -> Result<PhysicalMemory> {
+            let mut handle = ptr::null_mut();
+            hipMemImportFromShareableHandle(&mut handle, fd_handle(fd), POSIX_FD).into_result()?;
+            Ok(PhysicalMemory::from_raw(handle, size))
+        }
+    }
+
+    /// File descriptors are passed to HIP by value, cast to a pointer.
+    fn fd_handle(fd: &impl AsRawFd) -> *mut c_void {
+        fd.as_raw_fd() as usize as *mut c_void
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_bytes {
+    use serde::de::{Error, SeqAccess, Visitor};
+    use std::{convert::TryInto, fmt};
+
+    /// Accepts the handle as bytes, or as a sequence for formats without a
+    /// byte type.
+    pub(super) struct HandleVisitor;
+
+    impl<'de> Visitor<'de> for HandleVisitor {
+        type Value = [u8; 64];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("64 bytes")
+        }
+
+        fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<[u8; 64], E> {
+            bytes
+                .try_into()
+                .map_err(|_| E::invalid_length(bytes.len(), &self))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; 64], A::Error> {
+            let mut bytes = [0; 64];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(A::Error::invalid_length(65, &self));
+            }
+            Ok(bytes)
+        }
+    }
+}
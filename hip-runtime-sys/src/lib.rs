@@ -1,6 +1,106 @@
+// bindgen derives comparisons for structs holding callbacks, and neither it
+// nor the dynamic loading shims are written to satisfy clippy.
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::missing_transmute_annotations,
+    clippy::useless_transmute,
+    clippy::type_complexity,
+    clippy::incompatible_msrv
+)]
+#[cfg(not(feature = "dynamic-loading"))]
 mod bindings;
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::missing_transmute_annotations,
+    clippy::useless_transmute,
+    clippy::type_complexity,
+    clippy::incompatible_msrv
+)]
+#[allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#[cfg(feature = "dynamic-loading")]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 pub use bindings::*;
 
+#[macro_use]
+mod flags;
+
+mod code_object;
+mod completion;
+mod context;
+mod cooperative;
+mod device;
+mod error;
+mod event;
+mod graph;
+mod graph_model;
+mod host;
+mod ipc;
+mod managed;
+mod memory;
+mod module;
+mod msgpack;
+mod occupancy;
+mod pool;
+mod stream;
+mod texture;
+mod topology;
+mod virtual_memory;
+pub use code_object::{
+    ArgKind, BundleEntry, CodeObject, CodeObjectError, KernelArgMetadata, KernelMetadata,
+    OffloadBundle, TargetId,
+};
+pub use completion::Completion;
+pub use context::{Context, ContextFlags, ContextGuard, PrimaryContext};
+pub use cooperative::{
+    HostKernel, MultiDeviceLaunch, MultiDeviceLaunchError, MultiDeviceLaunchFlags,
+};
+pub use device::{attribute, AttributeValue, Device, DeviceAttribute, DeviceGuard};
+pub use error::{HipError, Result};
+pub use event::Event;
+pub use graph::{
+    ChildGraphNode, CopyNode, EmptyNode, ExecutableGraph, Graph, GraphNode, GraphUpdateError,
+    HostNode, KernelNode, MemsetNode,
+};
+pub use graph_model::{GraphModel, NodeModel};
+pub use host::{HostMallocFlags, HostRegisterFlags, HostRegistration, PinnedHostBuffer};
+#[cfg(unix)]
+pub use ipc::ShareableFd;
+pub use ipc::{IpcEventHandle, IpcMappedBuffer, IpcMemHandle, PoolPointerHandle};
+pub use managed::{
+    AttachedBuffer, CoherencyMode, ManagedBuffer, MemoryAdvice, MemoryAttach, MemoryLocation,
+    RangeAttributes,
+};
+pub use memory::{DeviceBuffer, DeviceSlice, DeviceSliceMut};
+pub use module::{
+    ArgLayout, ArgMismatch, DeviceRepr, Function, KernelArg, KernelArgBuffer, KernelArgError,
+    KernelArgs, LaunchConfig, LaunchError, LaunchLimits, Module, ModuleLoadError,
+};
+pub use occupancy::{Occupancy, OccupancyLimiter, OccupancyModel};
+pub use pool::{
+    pool_attribute, MemAccess, MemPool, MemPoolAttribute, PoolAttributeValue, PoolBuffer,
+    SettableMemPoolAttribute,
+};
+pub use stream::{Stream, StreamScope};
+pub use texture::{
+    AddressMode, ArrayFlags, Channel, ChannelFormat, ChannelKind, FilterMode, HipArray, HipArray3D,
+    MipmappedArray, ReadMode, SurfaceObject, TextureBuilder, TextureObject, TextureResource,
+};
+pub use topology::{copy_peer, LinkType, PeerAccess, PeerLink, Topology};
+pub use virtual_memory::{Granularity, PhysicalMemory, VirtualRange};
+
+#[cfg(feature = "dynamic-loading")]
+mod dynamic;
+#[cfg(feature = "dynamic-loading")]
+pub use dynamic::{
+    available_symbols, check_symbol, is_available, load, missing_symbols, LoadError, LIBRARY_NAMES,
+};
+
 use std::{env::VarError, path::PathBuf};
 
 pub const DEFAULT_HIP_PATH: &str = "/opt/rocm/hip";
@@ -0,0 +1,504 @@
+//! Managed (unified) memory, which migrates between the host and devices on
+//! demand.
+
+use crate::memory::{byte_size, non_null};
+use crate::{
+    hipError_t, hipFree, hipMallocManaged, hipMemAdvise, hipMemAttachGlobal, hipMemAttachHost,
+    hipMemAttachSingle, hipMemPrefetchAsync, hipMemRangeAttribute, hipMemRangeCoherencyMode,
+    hipMemRangeGetAttribute, hipMemRangeGetAttributes, hipMemoryAdvise, hipStreamAttachMemAsync,
+    Device, DeviceSlice, DeviceSliceMut, HipError, Result, Stream,
+};
+use std::{ffi::c_void, fmt, mem, ptr, slice};
+
+// `hipCpuDeviceId` and `hipInvalidDeviceId`, which are defined as casts and
+// so missing from the bindings.
+const CPU_DEVICE_ID: i32 = -1;
+const INVALID_DEVICE_ID: i32 = -2;
+
+const FINE_GRAIN: i32 = hipMemRangeCoherencyMode::hipMemRangeCoherencyModeFineGrain as i32;
+
+/// Where managed memory resides or is accessed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryLocation {
+    /// The host.
+    Host,
+    /// A device.
+    Device(Device),
+}
+
+impl MemoryLocation {
+    fn to_raw(self) -> i32 {
+        match self {
+            MemoryLocation::Host => CPU_DEVICE_ID,
+            MemoryLocation::Device(device) => device.ordinal(),
+        }
+    }
+
+    /// `None` for `hipInvalidDeviceId`, i.e. no single location.
+    fn from_raw(raw: i32) -> Option<MemoryLocation> {
+        match raw {
+            CPU_DEVICE_ID => Some(MemoryLocation::Host),
+            INVALID_DEVICE_ID => None,
+            ordinal if ordinal >= 0 => Some(MemoryLocation::Device(Device::from_ordinal(ordinal))),
+            _ => None,
+        }
+    }
+}
+
+impl From<Device> for MemoryLocation {
+    fn from(device: Device) -> MemoryLocation {
+        MemoryLocation::Device(device)
+    }
+}
+
+/// Advice about how managed memory will be used, for
+/// [`ManagedBuffer::advise`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryAdvice {
+    /// The memory is mostly read, so may be duplicated where it's read.
+    SetReadMostly,
+    /// Undo [`MemoryAdvice::SetReadMostly`].
+    UnsetReadMostly,
+    /// Prefer to keep the memory at the given location.
+    SetPreferredLocation,
+    /// Undo [`MemoryAdvice::SetPreferredLocation`].
+    UnsetPreferredLocation,
+    /// The memory will be accessed from the given location, so keep it
+    /// mapped there.
+    SetAccessedBy,
+    /// Undo [`MemoryAdvice::SetAccessedBy`].
+    UnsetAccessedBy,
+    /// Use coarse-grained coherence, which is faster but only coherent at
+    /// synchronisation points.
+    SetCoarseGrain,
+    /// Undo [`MemoryAdvice::SetCoarseGrain`].
+    UnsetCoarseGrain,
+}
+
+impl From<MemoryAdvice> for hipMemoryAdvise {
+    fn from(advice: MemoryAdvice) -> hipMemoryAdvise {
+        match advice {
+            MemoryAdvice::SetReadMostly => hipMemoryAdvise::hipMemAdviseSetReadMostly,
+            MemoryAdvice::UnsetReadMostly => hipMemoryAdvise::hipMemAdviseUnsetReadMostly,
+            MemoryAdvice::SetPreferredLocation => hipMemoryAdvise::hipMemAdviseSetPreferredLocation,
+            MemoryAdvice::UnsetPreferredLocation => {
+                hipMemoryAdvise::hipMemAdviseUnsetPreferredLocation
+            }
+            MemoryAdvice::SetAccessedBy => hipMemoryAdvise::hipMemAdviseSetAccessedBy,
+            MemoryAdvice::UnsetAccessedBy => hipMemoryAdvise::hipMemAdviseUnsetAccessedBy,
+            MemoryAdvice::SetCoarseGrain => hipMemoryAdvise::hipMemAdviseSetCoarseGrain,
+            MemoryAdvice::UnsetCoarseGrain => hipMemoryAdvise::hipMemAdviseUnsetCoarseGrain,
+        }
+    }
+}
+
+/// The coherence of a range of managed memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CoherencyMode {
+    /// Coherent while kernels are running.
+    FineGrain,
+    /// Only coherent at synchronisation points.
+    CoarseGrain,
+    /// Parts of the range differ.
+    Indeterminate,
+}
+
+impl CoherencyMode {
+    fn from_raw(raw: u32) -> CoherencyMode {
+        match raw {
+            r if r == hipMemRangeCoherencyMode::hipMemRangeCoherencyModeFineGrain as u32 => {
+                CoherencyMode::FineGrain
+            }
+            r if r == hipMemRangeCoherencyMode::hipMemRangeCoherencyModeCoarseGrain as u32 => {
+                CoherencyMode::CoarseGrain
+            }
+            _ => CoherencyMode::Indeterminate,
+        }
+    }
+}
+
+/// Which streams can access managed memory, for allocation and
+/// [`ManagedBuffer::attach`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryAttach {
+    /// Any stream on any device.
+    Global,
+    /// Only the host, until attached to a stream.
+    Host,
+    /// Only the stream it's attached to.
+    Single,
+}
+
+impl MemoryAttach {
+    fn bits(self) -> u32 {
+        match self {
+            MemoryAttach::Global => hipMemAttachGlobal,
+            MemoryAttach::Host => hipMemAttachHost,
+            MemoryAttach::Single => hipMemAttachSingle,
+        }
+    }
+}
+
+/// The attributes of a range of managed memory, from
+/// [`ManagedBuffer::range_attributes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RangeAttributes {
+    /// Whether every page has [`MemoryAdvice::SetReadMostly`] set.
+    pub read_mostly: bool,
+    /// The preferred location of every page, if they agree.
+    pub preferred_location: Option<MemoryLocation>,
+    /// Where every page was last prefetched to, if they agree.
+    pub last_prefetch_location: Option<MemoryLocation>,
+    /// The coherence of the range.
+    pub coherency_mode: CoherencyMode,
+}
+
+/// An allocation of `len` values of `T` in managed memory, freed on drop.
+///
+/// The buffer can be used as device memory through [`ManagedBuffer::as_slice`],
+/// and read or written on the host through [`ManagedBuffer::attach_to`].
+pub struct ManagedBuffer<T: Copy> {
+    // Null if nothing was allocated, i.e. the buffer is zero-sized.
+    ptr: *mut T,
+    len: usize,
+}
+
+// Safety: the buffer owns its allocation, which can be used from any thread.
+unsafe impl<T: Copy + Send> Send for ManagedBuffer<T> {}
+unsafe impl<T: Copy + Sync> Sync for ManagedBuffer<T> {}
+
+impl<T: Copy> ManagedBuffer<T> {
+    /// Allocate space for `len` values without initialising it.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The contents must be written before they're read.
+    pub unsafe fn uninitialized(len: usize, attach: MemoryAttach) -> Result<ManagedBuffer<T>> {
+        let bytes = byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let mut ptr = ptr::null_mut();
+        if bytes > 0 {
+            hipMallocManaged(&mut ptr, bytes, attach.bits()).into_result()?;
+        }
+        Ok(ManagedBuffer {
+            ptr: ptr as *mut T,
+            len,
+        })
+    }
+
+    /// Allocate a buffer holding a copy of `values`, written from the host.
+    pub fn from_slice(values: &[T], attach: MemoryAttach) -> Result<ManagedBuffer<T>> {
+        // Safety: every element is written before the buffer is returned,
+        // and no device work can be using it yet.
+        unsafe {
+            let buffer = ManagedBuffer::uninitialized(values.len(), attach)?;
+            ptr::copy_nonoverlapping(values.as_ptr(), non_null(buffer.ptr), values.len());
+            Ok(buffer)
+        }
+    }
+
+    /// The number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The pointer to the start of the buffer, valid on the host and every
+    /// device. Null if the buffer is zero-sized.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// The mutable pointer to the start of the buffer. Null if the buffer is
+    /// zero-sized.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// A device view of the whole buffer.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        unsafe { DeviceSlice::from_raw_parts(self.ptr, self.len, None) }
+    }
+
+    /// A mutable device view of the whole buffer.
+    pub fn as_slice_mut(&mut self) -> DeviceSliceMut<'_, T> {
+        unsafe { DeviceSliceMut::from_raw_parts(self.ptr, self.len, None) }
+    }
+
+    /// Advise the runtime how the buffer will be used. `location` is ignored
+    /// by advice that doesn't take one.
+    pub fn advise<L: Into<MemoryLocation>>(&self, advice: MemoryAdvice, location: L) -> Result<()> {
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+        let bytes = self.len * mem::size_of::<T>();
+        unsafe {
+            hipMemAdvise(
+                self.ptr as *const c_void,
+                bytes,
+                advice.into(),
+                location.into().to_raw(),
+            )
+        }
+        .into_result()
+    }
+
+    /// Queue migrating the buffer to `location` on `stream`.
+    pub fn prefetch_to<L: Into<MemoryLocation>>(&self, location: L, stream: &Stream) -> Result<()> {
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+        let bytes = self.len * mem::size_of::<T>();
+        unsafe {
+            hipMemPrefetchAsync(
+                self.ptr as *const c_void,
+                bytes,
+                location.into().to_raw(),
+                stream.as_raw(),
+            )
+        }
+        .into_result()
+    }
+
+    /// Attach the buffer to `stream` alone, so it can be accessed on the host
+    /// once that one stream is synchronised.
+    ///
+    /// The buffer is attached globally again when the returned
+    /// [`AttachedBuffer`] is dropped.
+    pub fn attach_to<'a>(&'a mut self, stream: &'a Stream) -> Result<AttachedBuffer<'a, T>> {
+        self.attach(stream, MemoryAttach::Single)?;
+        Ok(AttachedBuffer {
+            buffer: self,
+            stream,
+        })
+    }
+
+    /// Queue changing which streams can access the buffer on `stream`.
+    pub fn attach(&self, stream: &Stream, attach: MemoryAttach) -> Result<()> {
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+        let bytes = self.len * mem::size_of::<T>();
+        unsafe {
+            hipStreamAttachMemAsync(
+                stream.as_raw(),
+                self.ptr as *mut c_void,
+                bytes,
+                attach.bits(),
+            )
+        }
+        .into_result()
+    }
+
+    /// Whether every page of the buffer has [`MemoryAdvice::SetReadMostly`]
+    /// set.
+    ///
+    /// Like [`ManagedBuffer::advise`], the range queries succeed for a
+    /// zero-sized buffer, which reports the attributes of a buffer that was
+    /// never advised: not read mostly, with no preferred or prefetch
+    /// location, accessed by nothing, and fine grained.
+    pub fn read_mostly(&self) -> Result<bool> {
+        self.range_attribute(hipMemRangeAttribute::hipMemRangeAttributeReadMostly, 0)
+            .map(|value| value != 0)
+    }
+
+    /// The preferred location of every page of the buffer, if they agree.
+    pub fn preferred_location(&self) -> Result<Option<MemoryLocation>> {
+        self.range_attribute(
+            hipMemRangeAttribute::hipMemRangeAttributePreferredLocation,
+            INVALID_DEVICE_ID,
+        )
+        .map(MemoryLocation::from_raw)
+    }
+
+    /// Where every page of the buffer was last prefetched to, if they agree.
+    pub fn last_prefetch_location(&self) -> Result<Option<MemoryLocation>> {
+        self.range_attribute(
+            hipMemRangeAttribute::hipMemRangeAttributeLastPrefetchLocation,
+            INVALID_DEVICE_ID,
+        )
+        .map(MemoryLocation::from_raw)
+    }
+
+    /// The coherence of the buffer.
+    pub fn coherency_mode(&self) -> Result<CoherencyMode> {
+        self.range_attribute(
+            hipMemRangeAttribute::hipMemRangeAttributeCoherencyMode,
+            FINE_GRAIN,
+        )
+        .map(|value| CoherencyMode::from_raw(value as u32))
+    }
+
+    /// The locations that every page of the buffer has
+    /// [`MemoryAdvice::SetAccessedBy`] set for.
+    pub fn accessed_by(&self) -> Result<Vec<MemoryLocation>> {
+        if self.ptr.is_null() {
+            return Ok(Vec::new());
+        }
+        // One entry per device, plus the host; unused entries are invalid.
+        let mut ids = vec![INVALID_DEVICE_ID; Device::count()? + 1];
+        unsafe {
+            hipMemRangeGetAttribute(
+                ids.as_mut_ptr() as *mut c_void,
+                mem::size_of_val(ids.as_slice()),
+                hipMemRangeAttribute::hipMemRangeAttributeAccessedBy,
+                self.ptr as *const c_void,
+                self.len * mem::size_of::<T>(),
+            )
+        }
+        .into_result()?;
+        Ok(ids
+            .into_iter()
+            .filter_map(MemoryLocation::from_raw)
+            .collect())
+    }
+
+    /// All of the buffer's scalar range attributes, queried at once.
+    pub fn range_attributes(&self) -> Result<RangeAttributes> {
+        let mut read_mostly = 0i32;
+        let mut preferred_location = INVALID_DEVICE_ID;
+        let mut last_prefetch_location = INVALID_DEVICE_ID;
+        let mut coherency_mode = FINE_GRAIN as u32;
+        if self.ptr.is_null() {
+            // A zero-sized buffer was never advised.
+            return Ok(RangeAttributes {
+                read_mostly: false,
+                preferred_location: None,
+                last_prefetch_location: None,
+                coherency_mode: CoherencyMode::FineGrain,
+            });
+        }
+        let mut data = [
+            &mut read_mostly as *mut i32 as *mut c_void,
+            &mut preferred_location as *mut i32 as *mut c_void,
+            &mut last_prefetch_location as *mut i32 as *mut c_void,
+            &mut coherency_mode as *mut u32 as *mut c_void,
+        ];
+        let mut sizes = [4; 4];
+        let mut attributes = [
+            hipMemRangeAttribute::hipMemRangeAttributeReadMostly,
+            hipMemRangeAttribute::hipMemRangeAttributePreferredLocation,
+            hipMemRangeAttribute::hipMemRangeAttributeLastPrefetchLocation,
+            hipMemRangeAttribute::hipMemRangeAttributeCoherencyMode,
+        ];
+        unsafe {
+            hipMemRangeGetAttributes(
+                data.as_mut_ptr(),
+                sizes.as_mut_ptr(),
+                attributes.as_mut_ptr(),
+                attributes.len(),
+                self.ptr as *const c_void,
+                self.len * mem::size_of::<T>(),
+            )
+        }
+        .into_result()?;
+        Ok(RangeAttributes {
+            read_mostly: read_mostly != 0,
+            preferred_location: MemoryLocation::from_raw(preferred_location),
+            last_prefetch_location: MemoryLocation::from_raw(last_prefetch_location),
+            coherency_mode: CoherencyMode::from_raw(coherency_mode),
+        })
+    }
+
+    /// Query `attribute`, or return `unallocated` if the buffer is
+    /// zero-sized.
+    fn range_attribute(&self, attribute: hipMemRangeAttribute, unallocated: i32) -> Result<i32> {
+        if self.ptr.is_null() {
+            return Ok(unallocated);
+        }
+        let mut value = 0i32;
+        unsafe {
+            hipMemRangeGetAttribute(
+                &mut value as *mut i32 as *mut c_void,
+                mem::size_of::<i32>(),
+                attribute,
+                self.ptr as *const c_void,
+                self.len * mem::size_of::<T>(),
+            )
+        }
+        .into_result()?;
+        Ok(value)
+    }
+}
+
+impl<T: Copy> Drop for ManagedBuffer<T> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            let _ = unsafe { hipFree(self.ptr as *mut c_void) };
+        }
+    }
+}
+
+impl<T: Copy> fmt::Debug for ManagedBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManagedBuffer")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// A [`ManagedBuffer`] attached to a single stream, from
+/// [`ManagedBuffer::attach_to`].
+///
+/// No other stream can access the buffer while it's attached, and the buffer
+/// is borrowed for as long as this lives, so synchronising the stream is
+/// enough for the host to access it.
+pub struct AttachedBuffer<'a, T: Copy> {
+    buffer: &'a mut ManagedBuffer<T>,
+    stream: &'a Stream,
+}
+
+impl<'a, T: Copy> AttachedBuffer<'a, T> {
+    /// The stream the buffer is attached to.
+    pub fn stream(&self) -> &'a Stream {
+        self.stream
+    }
+
+    /// A device view of the whole buffer, for work on the attached stream.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        self.buffer.as_slice()
+    }
+
+    /// A mutable device view of the whole buffer, for work on the attached
+    /// stream.
+    pub fn as_slice_mut(&mut self) -> DeviceSliceMut<'_, T> {
+        self.buffer.as_slice_mut()
+    }
+
+    /// Synchronise the stream, then read the buffer on the host.
+    pub fn host_slice(&self) -> Result<&[T]> {
+        self.stream.synchronize()?;
+        // Safety: only the attached stream can access the buffer, and it has
+        // finished. Work queued from here on can only read the buffer, as
+        // writing needs a mutable borrow.
+        Ok(unsafe { slice::from_raw_parts(non_null(self.buffer.ptr), self.buffer.len) })
+    }
+
+    /// Synchronise the stream, then access the buffer on the host.
+    pub fn host_slice_mut(&mut self) -> Result<&mut [T]> {
+        self.stream.synchronize()?;
+        // Safety: only the attached stream can access the buffer, it has
+        // finished, and the mutable borrow stops more work being queued.
+        Ok(unsafe { slice::from_raw_parts_mut(non_null(self.buffer.ptr), self.buffer.len) })
+    }
+}
+
+impl<T: Copy> Drop for AttachedBuffer<'_, T> {
+    fn drop(&mut self) {
+        let _ = self.buffer.attach(self.stream, MemoryAttach::Global);
+    }
+}
+
+impl<T: Copy> fmt::Debug for AttachedBuffer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachedBuffer")
+            .field("buffer", &self.buffer)
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
@@ -0,0 +1,453 @@
+//! Device memory allocations and views into them.
+
+use crate::{
+    hipError_t, hipFree, hipMalloc, hipMemcpyDtoH, hipMemcpyHtoD, hipMemset, Device, HipError,
+    Result,
+};
+use std::{
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::{Bound, Range, RangeBounds},
+    ptr,
+};
+
+/// An allocation of `len` values of `T` in device memory, freed on drop.
+///
+/// The buffer is on the device that was current when it was allocated, and
+/// makes that device current while copying to or from the host.
+pub struct DeviceBuffer<T: Copy> {
+    // Null if nothing was allocated, i.e. the buffer is zero-sized.
+    ptr: *mut T,
+    len: usize,
+    // `None` if nothing was allocated.
+    device: Option<Device>,
+}
+
+// Safety: the buffer owns its allocation, and device memory can be used from
+// any thread.
+unsafe impl<T: Copy + Send> Send for DeviceBuffer<T> {}
+unsafe impl<T: Copy + Sync> Sync for DeviceBuffer<T> {}
+
+impl<T: Copy> DeviceBuffer<T> {
+    /// Allocate space for `len` values on the current device without
+    /// initialising it.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The contents must be written before they're read back as `T`.
+    pub unsafe fn uninitialized(len: usize) -> Result<DeviceBuffer<T>> {
+        let bytes = byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let mut ptr = ptr::null_mut();
+        let mut device = None;
+        if bytes > 0 {
+            device = Some(Device::current()?);
+            hipMalloc(&mut ptr, bytes).into_result()?;
+        }
+        Ok(DeviceBuffer {
+            ptr: ptr as *mut T,
+            len,
+            device,
+        })
+    }
+
+    /// Allocate space for `len` values with every byte set to zero.
+    ///
+    /// # Safety
+    /// All-zeroes must be a valid `T`.
+    pub unsafe fn zeroed(len: usize) -> Result<DeviceBuffer<T>> {
+        let buffer = DeviceBuffer::<T>::uninitialized(len)?;
+        if !buffer.ptr.is_null() {
+            hipMemset(buffer.ptr as *mut _, 0, len * mem::size_of::<T>()).into_result()?;
+        }
+        Ok(buffer)
+    }
+
+    /// Take ownership of `len` values at `ptr` on `device`, which must be
+    /// freed with `hipFree`.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *mut T,
+        len: usize,
+        device: Device,
+    ) -> DeviceBuffer<T> {
+        DeviceBuffer {
+            ptr,
+            len,
+            device: Some(device),
+        }
+    }
+
+    /// Allocate a buffer holding a copy of `values`.
+    pub fn from_slice(values: &[T]) -> Result<DeviceBuffer<T>> {
+        // Safety: every element is written before the buffer is returned.
+        let mut buffer = unsafe { DeviceBuffer::uninitialized(values.len())? };
+        buffer.copy_from_host(values)?;
+        Ok(buffer)
+    }
+
+    /// The number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The device the buffer is on, or `None` if it's zero-sized, so
+    /// nothing was allocated.
+    pub fn device(&self) -> Option<Device> {
+        self.device
+    }
+
+    /// The device pointer to the start of the buffer. Null if the buffer is
+    /// zero-sized.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// The mutable device pointer to the start of the buffer. Null if the
+    /// buffer is zero-sized.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// A view of the whole buffer.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        DeviceSlice {
+            ptr: self.ptr,
+            len: self.len,
+            device: self.device,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A mutable view of the whole buffer.
+    pub fn as_slice_mut(&mut self) -> DeviceSliceMut<'_, T> {
+        DeviceSliceMut {
+            ptr: self.ptr,
+            len: self.len,
+            device: self.device,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A view of `range` within the buffer. Fails with
+    /// `hipErrorInvalidValue` if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<DeviceSlice<'_, T>> {
+        self.as_slice().slice(range)
+    }
+
+    /// A mutable view of `range` within the buffer. Fails with
+    /// `hipErrorInvalidValue` if the range is out of bounds.
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Result<DeviceSliceMut<'_, T>> {
+        self.as_slice_mut().slice_mut(range)
+    }
+
+    /// Copy `src` into the buffer. Fails with `hipErrorInvalidValue` if the
+    /// lengths differ.
+    pub fn copy_from_host(&mut self, src: &[T]) -> Result<()> {
+        on_device(self.device, || self.as_slice_mut().copy_from_host(src))
+    }
+
+    /// Copy the buffer into `dst`. Fails with `hipErrorInvalidValue` if the
+    /// lengths differ.
+    pub fn copy_to_host(&self, dst: &mut [T]) -> Result<()> {
+        on_device(self.device, || self.as_slice().copy_to_host(dst))
+    }
+
+    /// Copy the buffer into a new `Vec`.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        on_device(self.device, || self.as_slice().to_vec())
+    }
+}
+
+/// Run `f` with `device` current, if there is one.
+pub(crate) fn on_device<R>(device: Option<Device>, f: impl FnOnce() -> Result<R>) -> Result<R> {
+    match device {
+        Some(device) => device.scope(f)?,
+        None => f(),
+    }
+}
+
+impl<T: Copy> Drop for DeviceBuffer<T> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            // Errors can't be reported from drop, and the memory is gone
+            // either way.
+            let _ = unsafe { hipFree(self.ptr as *mut _) };
+        }
+    }
+}
+
+impl<T: Copy> fmt::Debug for DeviceBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceBuffer")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+/// A borrowed view of values in device memory.
+#[derive(Clone, Copy)]
+pub struct DeviceSlice<'a, T: Copy> {
+    ptr: *mut T,
+    len: usize,
+    // `None` if unknown, e.g. for managed memory.
+    device: Option<Device>,
+    _marker: PhantomData<&'a T>,
+}
+
+// Safety: the slice is a shared borrow of device memory.
+unsafe impl<T: Copy + Sync> Send for DeviceSlice<'_, T> {}
+unsafe impl<T: Copy + Sync> Sync for DeviceSlice<'_, T> {}
+
+impl<'a, T: Copy> DeviceSlice<'a, T> {
+    /// A view of `len` values at `ptr`, which must stay allocated for `'a`.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *mut T,
+        len: usize,
+        device: Option<Device>,
+    ) -> DeviceSlice<'a, T> {
+        DeviceSlice {
+            ptr,
+            len,
+            device,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of values in the slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the slice holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The device the slice is on, if it's known: `None` for memory that
+    /// isn't owned by one device, such as managed memory.
+    pub fn device(&self) -> Option<Device> {
+        self.device
+    }
+
+    /// The device pointer to the start of the slice.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// A view of `range` within this slice. Fails with
+    /// `hipErrorInvalidValue` if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<DeviceSlice<'a, T>> {
+        let range = checked_range(range, self.len)?;
+        Ok(DeviceSlice {
+            ptr: offset(self.ptr, range.start),
+            len: range.len(),
+            device: self.device,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Copy the slice into `dst`. Fails with `hipErrorInvalidValue` if the
+    /// lengths differ.
+    pub fn copy_to_host(&self, dst: &mut [T]) -> Result<()> {
+        check_len(self.len, dst.len())?;
+        let bytes = self.len * mem::size_of::<T>();
+        if bytes > 0 {
+            unsafe { hipMemcpyDtoH(dst.as_mut_ptr() as *mut _, self.ptr as *mut _, bytes) }
+                .into_result()?;
+        }
+        Ok(())
+    }
+
+    /// Copy the slice into a new `Vec`.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        let mut values = Vec::with_capacity(self.len);
+        let bytes = self.len * mem::size_of::<T>();
+        if bytes > 0 {
+            unsafe { hipMemcpyDtoH(values.as_mut_ptr() as *mut _, self.ptr as *mut _, bytes) }
+                .into_result()?;
+        }
+        // Safety: the copy initialised every element.
+        unsafe { values.set_len(self.len) };
+        Ok(values)
+    }
+}
+
+impl<T: Copy> fmt::Debug for DeviceSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceSlice")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// A mutably borrowed view of values in device memory.
+pub struct DeviceSliceMut<'a, T: Copy> {
+    ptr: *mut T,
+    len: usize,
+    // `None` if unknown, e.g. for managed memory.
+    device: Option<Device>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// Safety: the slice is a unique borrow of device memory.
+unsafe impl<T: Copy + Send> Send for DeviceSliceMut<'_, T> {}
+unsafe impl<T: Copy + Sync> Sync for DeviceSliceMut<'_, T> {}
+
+impl<'a, T: Copy> DeviceSliceMut<'a, T> {
+    /// A mutable view of `len` values at `ptr`, which must stay allocated
+    /// and unaliased for `'a`.
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *mut T,
+        len: usize,
+        device: Option<Device>,
+    ) -> DeviceSliceMut<'a, T> {
+        DeviceSliceMut {
+            ptr,
+            len,
+            device,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of values in the slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the slice holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The device the slice is on, if it's known: `None` for memory that
+    /// isn't owned by one device, such as managed memory.
+    pub fn device(&self) -> Option<Device> {
+        self.device
+    }
+
+    /// The device pointer to the start of the slice.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// The mutable device pointer to the start of the slice.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// A shared view of this slice.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        DeviceSlice {
+            ptr: self.ptr,
+            len: self.len,
+            device: self.device,
+            _marker: PhantomData,
+        }
+    }
+
+    /// A view of `range` within this slice. Fails with
+    /// `hipErrorInvalidValue` if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Result<DeviceSlice<'_, T>> {
+        self.as_slice().slice(range)
+    }
+
+    /// A mutable view of `range` within this slice, consuming it. Fails with
+    /// `hipErrorInvalidValue` if the range is out of bounds.
+    pub fn slice_mut<R: RangeBounds<usize>>(self, range: R) -> Result<DeviceSliceMut<'a, T>> {
+        let range = checked_range(range, self.len)?;
+        Ok(DeviceSliceMut {
+            ptr: offset(self.ptr, range.start),
+            len: range.len(),
+            device: self.device,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Copy `src` into the slice. Fails with `hipErrorInvalidValue` if the
+    /// lengths differ.
+    pub fn copy_from_host(&mut self, src: &[T]) -> Result<()> {
+        check_len(self.len, src.len())?;
+        let bytes = self.len * mem::size_of::<T>();
+        if bytes > 0 {
+            unsafe { hipMemcpyHtoD(self.ptr as *mut _, src.as_ptr() as *mut _, bytes) }
+                .into_result()?;
+        }
+        Ok(())
+    }
+
+    /// Copy the slice into `dst`. Fails with `hipErrorInvalidValue` if the
+    /// lengths differ.
+    pub fn copy_to_host(&self, dst: &mut [T]) -> Result<()> {
+        self.as_slice().copy_to_host(dst)
+    }
+
+    /// Copy the slice into a new `Vec`.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T: Copy> fmt::Debug for DeviceSliceMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceSliceMut")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// The size of `len` values of `T` in bytes, if it fits in a `usize`.
+pub(crate) fn byte_size<T>(len: usize) -> Option<usize> {
+    len.checked_mul(mem::size_of::<T>())
+}
+
+pub(crate) fn check_len(expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(HipError::from(hipError_t::hipErrorInvalidValue));
+    }
+    Ok(())
+}
+
+/// Resolve `range` against a slice of length `len`.
+pub(crate) fn checked_range<R: RangeBounds<usize>>(range: R, len: usize) -> Result<Range<usize>> {
+    let invalid = || HipError::from(hipError_t::hipErrorInvalidValue);
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1).ok_or_else(invalid)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1).ok_or_else(invalid)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return Err(invalid());
+    }
+    Ok(start..end)
+}
+
+/// Offset a device pointer, which may be null for an empty allocation.
+fn offset<T>(ptr: *mut T, count: usize) -> *mut T {
+    // The pointer isn't dereferenced on the host, so use wrapping arithmetic
+    // rather than asserting it's in bounds of a host allocation.
+    ptr.wrapping_add(count)
+}
+
+/// Slices can't be built from null pointers, even when empty.
+pub(crate) fn non_null<T>(ptr: *mut T) -> *mut T {
+    if ptr.is_null() {
+        ptr::NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    }
+}
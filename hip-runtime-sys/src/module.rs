@@ -0,0 +1,903 @@
+//! Code objects loaded at run time, and launching their kernels.
+
+use crate::code_object::{CodeObject, CodeObjectError, KernelMetadata, TargetId};
+use crate::{
+    attribute, dim3, hipError_t, hipFuncGetAttribute, hipFunction_attribute, hipFunction_t,
+    hipJitOption, hipModuleGetFunction, hipModuleLaunchKernel, hipModuleLoadData,
+    hipModuleLoadDataEx, hipModuleUnload, hipModule_t, Device, DeviceSlice, DeviceSliceMut,
+    HipError, Result, Stream,
+};
+use std::{
+    error::Error,
+    ffi::{c_void, CString},
+    fmt, fs, io,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    path::Path,
+    ptr,
+};
+
+// Markers in the `extra` argument of `hipModuleLaunchKernel`.
+const LAUNCH_PARAM_BUFFER_POINTER: usize = 0x01;
+const LAUNCH_PARAM_BUFFER_SIZE: usize = 0x02;
+const LAUNCH_PARAM_END: usize = 0x03;
+
+/// A code object loaded onto a device, unloaded on drop.
+///
+/// Before anything is handed to HIP, the code object or offload bundle is
+/// inspected, so that malformed images and ones without code for the device
+/// fail up front rather than inside the runtime.
+#[derive(Debug)]
+pub struct Module {
+    raw: hipModule_t,
+    device: Device,
+    code_object: Option<CodeObject>,
+}
+
+// Safety: HIP modules can be used from any thread.
+unsafe impl Send for Module {}
+unsafe impl Sync for Module {}
+
+impl Module {
+    /// Load the code object or offload bundle at `path` onto the current
+    /// device. Fails like [`Module::from_bytes`] if the file's contents
+    /// aren't suitable, or with the I/O error if it can't be read.
+    ///
+    /// The file is read once, and the bytes that were inspected are the
+    /// ones that are loaded.
+    pub fn load<P: AsRef<Path>>(path: P) -> std::result::Result<Module, ModuleLoadError> {
+        let image = fs::read(path).map_err(ModuleLoadError::Io)?;
+        Ok(Module::from_bytes(&image)?)
+    }
+
+    /// Load a code object or offload bundle from memory onto the current
+    /// device. Fails with `hipErrorInvalidImage` if `image` is neither, or
+    /// is malformed, and with `hipErrorNoBinaryForGpu` if it has no code for
+    /// the device.
+    pub fn from_bytes(image: &[u8]) -> Result<Module> {
+        let code_objects = inspect(image)?;
+        let device = Device::current()?;
+        let code_object = select_code_object(code_objects, device)?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipModuleLoadData(&mut raw, image.as_ptr() as *const c_void) }.into_result()?;
+        Ok(Module {
+            raw,
+            device,
+            code_object,
+        })
+    }
+
+    /// Load a code object or offload bundle from memory onto the current
+    /// device, passing JIT options to the runtime.
+    ///
+    /// # Safety
+    /// Each value must be what the runtime expects for the option at the
+    /// same index.
+    pub unsafe fn from_bytes_with_options(
+        image: &[u8],
+        options: &mut [hipJitOption],
+        values: &mut [*mut c_void],
+    ) -> Result<Module> {
+        let code_objects = inspect(image)?;
+        if options.len() != values.len() {
+            return Err(HipError::from(hipError_t::hipErrorInvalidValue));
+        }
+        let device = Device::current()?;
+        let code_object = select_code_object(code_objects, device)?;
+        let mut raw = ptr::null_mut();
+        hipModuleLoadDataEx(
+            &mut raw,
+            image.as_ptr() as *const c_void,
+            options.len() as u32,
+            options.as_mut_ptr(),
+            values.as_mut_ptr(),
+        )
+        .into_result()?;
+        Ok(Module {
+            raw,
+            device,
+            code_object,
+        })
+    }
+
+    /// Take ownership of a raw module loaded onto `device`.
+    ///
+    /// # Safety
+    /// `raw` must be a valid module loaded onto `device` that nothing else
+    /// will unload.
+    pub unsafe fn from_raw(raw: hipModule_t, device: Device) -> Module {
+        Module {
+            raw,
+            device,
+            code_object: None,
+        }
+    }
+
+    /// The raw module, which remains owned by this `Module`.
+    pub fn as_raw(&self) -> hipModule_t {
+        self.raw
+    }
+
+    /// Give up ownership of the raw module without unloading it.
+    pub fn into_raw(self) -> hipModule_t {
+        let raw = self.raw;
+        mem::forget(self);
+        raw
+    }
+
+    /// The device the module was loaded onto.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The code object that was loaded for the device, if it could be
+    /// inspected: not for modules from [`Module::from_raw`] or compressed
+    /// offload bundles.
+    pub fn code_object(&self) -> Option<&CodeObject> {
+        self.code_object.as_ref()
+    }
+
+    /// The kernel called `name`. Fails with `hipErrorNotFound` if there's no
+    /// such kernel.
+    pub fn function(&self, name: &str) -> Result<Function<'_>> {
+        let c_name =
+            CString::new(name).map_err(|_| HipError::from(hipError_t::hipErrorNotFound))?;
+        let mut raw = ptr::null_mut();
+        self.device
+            .scope(|| unsafe { hipModuleGetFunction(&mut raw, self.raw, c_name.as_ptr()) })?
+            .into_result()?;
+        let limits = LaunchLimits::of_function(self.device, raw)?;
+        Ok(Function {
+            raw,
+            device: self.device,
+            limits,
+            metadata: self.code_object().and_then(|code| code.kernel(name)),
+            _module: PhantomData,
+        })
+    }
+}
+
+impl Drop for Module {
+    fn drop(&mut self) {
+        let _ = unsafe { hipModuleUnload(self.raw) };
+    }
+}
+
+/// A kernel in a [`Module`]. Its module's device is made current to launch
+/// it.
+#[derive(Clone, Copy, Debug)]
+pub struct Function<'m> {
+    raw: hipFunction_t,
+    device: Device,
+    limits: LaunchLimits,
+    metadata: Option<&'m KernelMetadata>,
+    _module: PhantomData<&'m Module>,
+}
+
+// Safety: the function is only used while its module is loaded, and HIP
+// functions can be used from any thread.
+unsafe impl Send for Function<'_> {}
+unsafe impl Sync for Function<'_> {}
+
+impl<'m> Function<'m> {
+    /// The raw function, which is valid while the module is loaded.
+    pub fn as_raw(&self) -> hipFunction_t {
+        self.raw
+    }
+
+    /// The device the function's module was loaded onto.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Query an attribute of the function.
+    pub fn attribute(&self, attribute: hipFunction_attribute) -> Result<i32> {
+        function_attribute(self.raw, attribute)
+    }
+
+    /// The largest launch this function accepts on its module's device.
+    pub fn limits(&self) -> &LaunchLimits {
+        &self.limits
+    }
+
+    /// What the code object's metadata says about the kernel, if the
+    /// module's code object could be inspected.
+    pub fn metadata(&self) -> Option<&'m KernelMetadata> {
+        self.metadata
+    }
+
+    /// Check `args` against the kernel's metadata, if there is any. See
+    /// [`KernelMetadata::check_args`].
+    pub fn check_args<A: KernelArgs>(&self, args: &A) -> std::result::Result<(), KernelArgError> {
+        let mut buffer = KernelArgBuffer::new();
+        args.push_to(&mut buffer);
+        self.check_buffer(&buffer)
+    }
+
+    /// Launch the function with `args`, after checking `config` against
+    /// [`Function::limits`], and `args` against the kernel's metadata if
+    /// there is any. Fails with `hipErrorInvalidConfiguration` if the
+    /// configuration is invalid, and with a [`KernelArgError`] naming the
+    /// argument if the arguments don't match.
+    ///
+    /// The [`launch!`](crate::launch) macro is a shorthand for this.
+    ///
+    /// # Safety
+    /// `args` must match the kernel's parameters, and the kernel must only
+    /// access memory that stays valid, and isn't used by anything else,
+    /// until it completes. Checking the arguments catches mistakes in their
+    /// layout, not in their values or types.
+    pub unsafe fn launch<A: KernelArgs>(
+        &self,
+        config: &LaunchConfig<'_>,
+        args: A,
+    ) -> std::result::Result<(), LaunchError> {
+        config.check(&self.limits)?;
+        let mut buffer = KernelArgBuffer::new();
+        args.push_to(&mut buffer);
+        self.check_buffer(&buffer)?;
+        let mut size = buffer.len();
+        let mut extra = [
+            LAUNCH_PARAM_BUFFER_POINTER as *mut c_void,
+            buffer.as_mut_ptr(),
+            LAUNCH_PARAM_BUFFER_SIZE as *mut c_void,
+            &mut size as *mut usize as *mut c_void,
+            LAUNCH_PARAM_END as *mut c_void,
+        ];
+        self.device
+            .scope(|| {
+                hipModuleLaunchKernel(
+                    self.raw,
+                    config.grid.x,
+                    config.grid.y,
+                    config.grid.z,
+                    config.block.x,
+                    config.block.y,
+                    config.block.z,
+                    config.shared_mem,
+                    config.stream.map_or(ptr::null_mut(), Stream::as_raw),
+                    ptr::null_mut(),
+                    extra.as_mut_ptr(),
+                )
+            })?
+            .into_result()?;
+        Ok(())
+    }
+
+    fn check_buffer(&self, buffer: &KernelArgBuffer) -> std::result::Result<(), KernelArgError> {
+        match self.metadata {
+            Some(metadata) => metadata.check_args(buffer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl KernelMetadata {
+    /// Check that `args` has as many arguments as the kernel declares, each
+    /// the declared size, at the declared offset, and a pointer exactly
+    /// when the kernel declares a pointer.
+    pub fn check_args(&self, args: &KernelArgBuffer) -> std::result::Result<(), KernelArgError> {
+        let error = |index, mismatch| KernelArgError {
+            kernel: self.name.clone(),
+            index,
+            mismatch,
+        };
+        let declared: Vec<_> = self.explicit_args().collect();
+        for (index, (declared, supplied)) in declared.iter().zip(args.layout()).enumerate() {
+            let expected_pointer = declared.kind.is_pointer();
+            if supplied.pointer != expected_pointer {
+                return Err(error(
+                    Some(index),
+                    ArgMismatch::Pointer {
+                        expected: expected_pointer,
+                    },
+                ));
+            }
+            if supplied.size != declared.size as usize {
+                return Err(error(
+                    Some(index),
+                    ArgMismatch::Size {
+                        expected: declared.size as usize,
+                        supplied: supplied.size,
+                    },
+                ));
+            }
+            if supplied.offset != declared.offset as usize {
+                return Err(error(
+                    Some(index),
+                    ArgMismatch::Offset {
+                        expected: declared.offset as usize,
+                        supplied: supplied.offset,
+                    },
+                ));
+            }
+        }
+        if declared.len() != args.layout().len() {
+            return Err(error(
+                None,
+                ArgMismatch::Count {
+                    expected: declared.len(),
+                    supplied: args.layout().len(),
+                },
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// How supplied kernel arguments differ from what the kernel declares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArgMismatch {
+    /// The wrong number of arguments.
+    Count { expected: usize, supplied: usize },
+    /// A pointer where the kernel takes a value, or the other way round.
+    Pointer {
+        /// Whether the kernel takes a pointer.
+        expected: bool,
+    },
+    /// An argument of the wrong size, in bytes.
+    Size { expected: usize, supplied: usize },
+    /// An argument at the wrong offset, in bytes, e.g. because of an
+    /// earlier argument's alignment.
+    Offset { expected: usize, supplied: usize },
+}
+
+/// Kernel arguments that don't match the kernel's metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KernelArgError {
+    /// The kernel's name.
+    pub kernel: String,
+    /// The index of the offending argument, or `None` if the number of
+    /// arguments is wrong.
+    pub index: Option<usize>,
+    /// What's wrong.
+    pub mismatch: ArgMismatch,
+}
+
+impl fmt::Display for KernelArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "kernel `{}`: ", self.kernel)?;
+        if let Some(index) = self.index {
+            write!(f, "argument {index} ")?;
+        }
+        match self.mismatch {
+            ArgMismatch::Count { expected, supplied } => {
+                write!(
+                    f,
+                    "takes {expected} arguments, but {supplied} were supplied"
+                )
+            }
+            ArgMismatch::Pointer { expected: true } => {
+                write!(f, "is a pointer, but a value was supplied")
+            }
+            ArgMismatch::Pointer { expected: false } => {
+                write!(f, "is a value, but a pointer was supplied")
+            }
+            ArgMismatch::Size { expected, supplied } => {
+                write!(f, "is {expected} bytes, but {supplied} were supplied")
+            }
+            ArgMismatch::Offset { expected, supplied } => write!(
+                f,
+                "is at offset {expected}, but was supplied at offset {supplied}"
+            ),
+        }
+    }
+}
+
+impl Error for KernelArgError {}
+
+impl From<KernelArgError> for HipError {
+    fn from(_: KernelArgError) -> HipError {
+        HipError::from(hipError_t::hipErrorInvalidValue)
+    }
+}
+
+/// An error from [`Module::load`].
+#[derive(Debug)]
+pub enum ModuleLoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file's contents aren't suitable, or HIP failed to load them.
+    Hip(HipError),
+}
+
+impl fmt::Display for ModuleLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModuleLoadError::Io(e) => e.fmt(f),
+            ModuleLoadError::Hip(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ModuleLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ModuleLoadError::Io(e) => Some(e),
+            ModuleLoadError::Hip(e) => Some(e),
+        }
+    }
+}
+
+impl From<HipError> for ModuleLoadError {
+    fn from(e: HipError) -> ModuleLoadError {
+        ModuleLoadError::Hip(e)
+    }
+}
+
+/// Files that can't be read become `hipErrorFileNotFound`, as they would
+/// from `hipModuleLoad`.
+impl From<ModuleLoadError> for HipError {
+    fn from(e: ModuleLoadError) -> HipError {
+        match e {
+            ModuleLoadError::Io(_) => HipError::from(hipError_t::hipErrorFileNotFound),
+            ModuleLoadError::Hip(e) => e,
+        }
+    }
+}
+
+/// An error from [`Function::launch`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LaunchError {
+    /// The arguments don't match the kernel's metadata.
+    Args(KernelArgError),
+    /// The configuration is invalid, or HIP failed to launch the kernel.
+    Hip(HipError),
+}
+
+impl fmt::Display for LaunchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LaunchError::Args(e) => e.fmt(f),
+            LaunchError::Hip(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for LaunchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LaunchError::Args(e) => Some(e),
+            LaunchError::Hip(e) => Some(e),
+        }
+    }
+}
+
+impl From<HipError> for LaunchError {
+    fn from(e: HipError) -> LaunchError {
+        LaunchError::Hip(e)
+    }
+}
+
+impl From<KernelArgError> for LaunchError {
+    fn from(e: KernelArgError) -> LaunchError {
+        LaunchError::Args(e)
+    }
+}
+
+/// Arguments that don't match become `hipErrorInvalidValue`.
+impl From<LaunchError> for HipError {
+    fn from(e: LaunchError) -> HipError {
+        match e {
+            LaunchError::Args(e) => e.into(),
+            LaunchError::Hip(e) => e,
+        }
+    }
+}
+
+/// The largest launch a device, or a function on it, accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LaunchLimits {
+    /// The most threads in a block.
+    pub max_threads_per_block: u32,
+    /// The most threads in a block along each dimension.
+    pub max_block: [u32; 3],
+    /// The most blocks in a grid along each dimension.
+    pub max_grid: [u32; 3],
+    /// The most dynamic shared memory per block, in bytes.
+    pub max_shared_mem: u32,
+}
+
+impl LaunchLimits {
+    /// The limits of any kernel on `device`.
+    pub fn of(device: Device) -> Result<LaunchLimits> {
+        let limit = |value: i32| value.max(0) as u32;
+        Ok(LaunchLimits {
+            max_threads_per_block: limit(device.attribute::<attribute::MaxThreadsPerBlock>()?),
+            max_block: [
+                limit(device.attribute::<attribute::MaxBlockDimX>()?),
+                limit(device.attribute::<attribute::MaxBlockDimY>()?),
+                limit(device.attribute::<attribute::MaxBlockDimZ>()?),
+            ],
+            max_grid: [
+                limit(device.attribute::<attribute::MaxGridDimX>()?),
+                limit(device.attribute::<attribute::MaxGridDimY>()?),
+                limit(device.attribute::<attribute::MaxGridDimZ>()?),
+            ],
+            max_shared_mem: limit(device.attribute::<attribute::MaxSharedMemoryPerBlock>()?),
+        })
+    }
+
+    /// The device's limits, narrowed by the function's own block size limit
+    /// and static shared memory.
+    fn of_function(device: Device, raw: hipFunction_t) -> Result<LaunchLimits> {
+        let mut limits = LaunchLimits::of(device)?;
+        let max_threads = function_attribute(
+            raw,
+            hipFunction_attribute::HIP_FUNC_ATTRIBUTE_MAX_THREADS_PER_BLOCK,
+        )?;
+        if max_threads > 0 {
+            limits.max_threads_per_block = limits.max_threads_per_block.min(max_threads as u32);
+        }
+        let static_shared = function_attribute(
+            raw,
+            hipFunction_attribute::HIP_FUNC_ATTRIBUTE_SHARED_SIZE_BYTES,
+        )?;
+        limits.max_shared_mem = limits
+            .max_shared_mem
+            .saturating_sub(static_shared.max(0) as u32);
+        Ok(limits)
+    }
+}
+
+/// The shape of a kernel launch and the stream to launch on.
+#[derive(Clone, Copy, Debug)]
+pub struct LaunchConfig<'s> {
+    /// The number of blocks along each dimension.
+    pub grid: dim3,
+    /// The number of threads in a block along each dimension.
+    pub block: dim3,
+    /// The dynamic shared memory per block, in bytes.
+    pub shared_mem: u32,
+    /// The stream to launch on, or `None` for the null stream.
+    pub stream: Option<&'s Stream>,
+}
+
+impl<'s> LaunchConfig<'s> {
+    /// A launch of `grid` blocks of `block` threads on the null stream,
+    /// without dynamic shared memory.
+    pub fn new<G: Into<dim3>, B: Into<dim3>>(grid: G, block: B) -> LaunchConfig<'s> {
+        LaunchConfig {
+            grid: grid.into(),
+            block: block.into(),
+            shared_mem: 0,
+            stream: None,
+        }
+    }
+
+    /// A one-dimensional launch with enough blocks of `block` threads to
+    /// cover `len` elements.
+    pub fn for_len(len: u32, block: u32) -> LaunchConfig<'s> {
+        let blocks = match block {
+            0 => 0,
+            // No larger than `len`, so it fits.
+            block => div_ceil(len as usize, block as usize) as u32,
+        };
+        LaunchConfig::new(blocks, block)
+    }
+
+    /// The same launch with `bytes` of dynamic shared memory per block.
+    pub fn with_shared_mem(self, bytes: u32) -> LaunchConfig<'s> {
+        LaunchConfig {
+            shared_mem: bytes,
+            ..self
+        }
+    }
+
+    /// The same launch on `stream`.
+    pub fn on_stream(self, stream: &'s Stream) -> LaunchConfig<'s> {
+        LaunchConfig {
+            stream: Some(stream),
+            ..self
+        }
+    }
+
+    /// The total number of threads in a block.
+    pub fn threads_per_block(&self) -> u64 {
+        u64::from(self.block.x) * u64::from(self.block.y) * u64::from(self.block.z)
+    }
+
+    /// Check the launch against `limits`. Fails with
+    /// `hipErrorInvalidConfiguration` if any dimension is zero or too large,
+    /// or there are too many threads per block or too much shared memory.
+    pub fn check(&self, limits: &LaunchLimits) -> Result<()> {
+        let block = [self.block.x, self.block.y, self.block.z];
+        let grid = [self.grid.x, self.grid.y, self.grid.z];
+        let fits = |dims: [u32; 3], max: [u32; 3]| {
+            dims.iter()
+                .zip(&max)
+                .all(|(&dim, &max)| dim > 0 && dim <= max)
+        };
+        if !fits(block, limits.max_block)
+            || !fits(grid, limits.max_grid)
+            || self.threads_per_block() > u64::from(limits.max_threads_per_block)
+            || self.shared_mem > limits.max_shared_mem
+        {
+            return Err(HipError::from(hipError_t::hipErrorInvalidConfiguration));
+        }
+        Ok(())
+    }
+}
+
+impl From<u32> for dim3 {
+    fn from(x: u32) -> dim3 {
+        dim3 { x, y: 1, z: 1 }
+    }
+}
+
+impl From<(u32, u32)> for dim3 {
+    fn from((x, y): (u32, u32)) -> dim3 {
+        dim3 { x, y, z: 1 }
+    }
+}
+
+impl From<(u32, u32, u32)> for dim3 {
+    fn from((x, y, z): (u32, u32, u32)) -> dim3 {
+        dim3 { x, y, z }
+    }
+}
+
+/// Types whose bytes can be passed to a kernel as they are.
+///
+/// # Safety
+/// The type must have the same layout in Rust as the kernel's parameter,
+/// e.g. a primitive or a `#[repr(C)]` struct of them, and must not hold
+/// host references.
+pub unsafe trait DeviceRepr: Copy {
+    /// Whether the value is a device pointer, which a kernel declares as a
+    /// `global_buffer` argument.
+    const POINTER: bool = false;
+}
+
+macro_rules! device_repr {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl DeviceRepr for $ty {})*
+    };
+}
+
+device_repr!(bool, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize, f32, f64, dim3);
+
+unsafe impl<T> DeviceRepr for *const T {
+    const POINTER: bool = true;
+}
+unsafe impl<T> DeviceRepr for *mut T {
+    const POINTER: bool = true;
+}
+unsafe impl<T: DeviceRepr, const N: usize> DeviceRepr for [T; N] {}
+
+/// A kernel argument.
+pub trait KernelArg {
+    /// Append the value the kernel receives to `buffer`, with a single
+    /// [`KernelArgBuffer::push`].
+    fn push_to(&self, buffer: &mut KernelArgBuffer);
+}
+
+impl<T: DeviceRepr> KernelArg for T {
+    fn push_to(&self, buffer: &mut KernelArgBuffer) {
+        buffer.push(self);
+    }
+}
+
+/// Passed as a pointer to the start of the slice.
+impl<T: Copy> KernelArg for DeviceSlice<'_, T> {
+    fn push_to(&self, buffer: &mut KernelArgBuffer) {
+        buffer.push(&self.as_ptr());
+    }
+}
+
+/// Passed as a pointer to the start of the slice.
+impl<T: Copy> KernelArg for DeviceSliceMut<'_, T> {
+    fn push_to(&self, buffer: &mut KernelArgBuffer) {
+        buffer.push(&(self.as_ptr() as *mut T));
+    }
+}
+
+/// The whole argument list of a kernel, as a tuple of [`KernelArg`]s.
+pub trait KernelArgs {
+    /// Append each argument to `buffer` in order.
+    fn push_to(&self, buffer: &mut KernelArgBuffer);
+}
+
+macro_rules! kernel_args {
+    ($($arg:ident),*) => {
+        impl<$($arg: KernelArg),*> KernelArgs for ($($arg,)*) {
+            #[allow(non_snake_case, unused_variables)]
+            fn push_to(&self, buffer: &mut KernelArgBuffer) {
+                let ($($arg,)*) = self;
+                $($arg.push_to(buffer);)*
+            }
+        }
+    };
+}
+
+kernel_args!();
+kernel_args!(A);
+kernel_args!(A, B);
+kernel_args!(A, B, C);
+kernel_args!(A, B, C, D);
+kernel_args!(A, B, C, D, E);
+kernel_args!(A, B, C, D, E, F);
+kernel_args!(A, B, C, D, E, F, G);
+kernel_args!(A, B, C, D, E, F, G, H);
+kernel_args!(A, B, C, D, E, F, G, H, I);
+kernel_args!(A, B, C, D, E, F, G, H, I, J);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K, L);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K, L, M);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+kernel_args!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// Where an argument is in a [`KernelArgBuffer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArgLayout {
+    /// The offset from the start of the arguments, in bytes.
+    pub offset: usize,
+    /// The size, in bytes.
+    pub size: usize,
+    /// Whether the argument is a device pointer.
+    pub pointer: bool,
+}
+
+// Aligned for any argument a kernel can take.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct Word([MaybeUninit<u8>; 16]);
+
+/// Kernel arguments laid out as the kernel expects them: each at the next
+/// offset aligned for its type.
+#[derive(Clone, Default)]
+pub struct KernelArgBuffer {
+    words: Vec<Word>,
+    len: usize,
+    layout: Vec<ArgLayout>,
+}
+
+impl KernelArgBuffer {
+    /// An empty argument list.
+    pub fn new() -> KernelArgBuffer {
+        KernelArgBuffer::default()
+    }
+
+    /// Append `value` as the next argument, at the next offset aligned for
+    /// `T`, returning that offset.
+    pub fn push<T: DeviceRepr>(&mut self, value: &T) -> usize {
+        let align = mem::align_of::<T>();
+        let offset = div_ceil(self.len, align) * align;
+        let end = offset + mem::size_of::<T>();
+        let words = div_ceil(end, mem::size_of::<Word>());
+        self.words.resize(words, Word([MaybeUninit::uninit(); 16]));
+        // Safety: the words hold at least `end` bytes, and copying doesn't
+        // read any padding in `value`.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                value as *const T as *const u8,
+                (self.words.as_mut_ptr() as *mut u8).add(offset),
+                mem::size_of::<T>(),
+            )
+        };
+        self.len = end;
+        self.layout.push(ArgLayout {
+            offset,
+            size: mem::size_of::<T>(),
+            pointer: T::POINTER,
+        });
+        offset
+    }
+
+    /// Where each argument is, in order.
+    pub fn layout(&self) -> &[ArgLayout] {
+        &self.layout
+    }
+
+    /// The size of the arguments, in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut c_void {
+        self.words.as_mut_ptr() as *mut c_void
+    }
+
+    /// A pointer to each argument, as launches that take `kernelParams`
+    /// expect. They're valid until the buffer is changed or dropped.
+    pub(crate) fn param_pointers(&mut self) -> Vec<*mut c_void> {
+        let base = self.words.as_mut_ptr() as *mut u8;
+        self.layout
+            .iter()
+            // Safety: every argument lies within the words.
+            .map(|arg| unsafe { base.add(arg.offset) } as *mut c_void)
+            .collect()
+    }
+}
+
+impl fmt::Debug for KernelArgBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KernelArgBuffer")
+            .field("len", &self.len)
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+/// Launch a [`Function`] with a [`LaunchConfig`] and the kernel's arguments,
+/// e.g. `launch!(function, config, input, output, len)`. Each argument is a
+/// [`KernelArg`]: a [`DeviceRepr`] value, or device memory passed as a
+/// pointer.
+///
+/// This is [`Function::launch`], so it has to be called in an `unsafe`
+/// block, and returns a [`Result`].
+///
+/// ```no_run
+/// use hip_runtime_sys::{launch, DeviceBuffer, LaunchConfig, Module, Stream};
+///
+/// # fn main() -> hip_runtime_sys::Result<()> {
+/// let module = Module::load("kernels.hsaco")?;
+/// let scale = module.function("scale")?;
+/// let mut values = DeviceBuffer::from_slice(&[1.0f32, 2.0, 3.0])?;
+/// let stream = Stream::new()?;
+/// let config = LaunchConfig::for_len(values.len() as u32, 256).on_stream(&stream);
+/// // Safety: `scale(float* values, float factor, unsigned len)` stays in
+/// // bounds, and `values` outlives the kernel.
+/// unsafe { launch!(scale, config, values.as_slice_mut(), 2.0f32, 3u32)? };
+/// stream.synchronize()?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! launch {
+    ($function:expr, $config:expr $(, $arg:expr)* $(,)?) => {
+        $function.launch(&$config, ($($arg,)*))
+    };
+}
+
+fn function_attribute(raw: hipFunction_t, attribute: hipFunction_attribute) -> Result<i32> {
+    let mut value = 0;
+    unsafe { hipFuncGetAttribute(&mut value, attribute, raw) }.into_result()?;
+    Ok(value)
+}
+
+/// The code objects in `image`, or `None` for a compressed offload bundle,
+/// which is taken on trust. Fails with `hipErrorInvalidImage` if `image`
+/// isn't a well-formed code object or offload bundle.
+fn inspect(image: &[u8]) -> Result<Option<Vec<CodeObject>>> {
+    match CodeObject::from_image(image) {
+        Ok(code_objects) => Ok(Some(code_objects)),
+        Err(CodeObjectError::CompressedBundle) => Ok(None),
+        Err(_) => Err(HipError::from(hipError_t::hipErrorInvalidImage)),
+    }
+}
+
+/// The code object HIP will load onto `device`: the most specific one whose
+/// target is compatible. Fails with `hipErrorNoBinaryForGpu` if there isn't
+/// one.
+fn select_code_object(
+    code_objects: Option<Vec<CodeObject>>,
+    device: Device,
+) -> Result<Option<CodeObject>> {
+    let code_objects = match code_objects {
+        Some(code_objects) => code_objects,
+        None => return Ok(None),
+    };
+    // A device target this crate can't parse can't be checked either.
+    let target = match device.gcn_arch_name()?.parse::<TargetId>() {
+        Ok(target) => target,
+        Err(_) => return Ok(None),
+    };
+    code_objects
+        .into_iter()
+        .filter(|code_object| code_object.target.is_compatible_with(&target))
+        .max_by_key(|code_object| code_object.target.features().len())
+        .map(Some)
+        .ok_or(HipError::from(hipError_t::hipErrorNoBinaryForGpu))
+}
+
+/// `a / b`, rounded up.
+fn div_ceil(a: usize, b: usize) -> usize {
+    a / b + usize::from(a % b != 0)
+}
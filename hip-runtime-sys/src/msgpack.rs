@@ -0,0 +1,174 @@
+//! A MessagePack reader, just enough for AMDGPU code-object metadata.
+
+use std::convert::TryInto;
+
+/// A decoded MessagePack value.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Nil,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bin(Vec<u8>),
+    Array(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+}
+
+impl Value {
+    /// The value for the string key `key`, if this is a map that has it.
+    pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, Value::Str(k) if k == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Value::Int(i) => i.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// Decode one value from the start of `bytes`. Fails with a description of
+/// the problem if the data is malformed or uses extension types.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Value, String> {
+    Reader {
+        bytes,
+        pos: 0,
+        depth: 0,
+    }
+    .value()
+}
+
+// Deep enough for any real metadata, shallow enough not to overflow the
+// stack on hostile input.
+const MAX_DEPTH: usize = 64;
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| format!("truncated at offset {}", self.pos))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn uint(&mut self, len: usize) -> Result<u64, String> {
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0, |value, &byte| value << 8 | u64::from(byte)))
+    }
+
+    fn int(&mut self, len: usize) -> Result<i64, String> {
+        // Sign-extend from the top bit of the big-endian value.
+        let shift = 64 - 8 * len as u32;
+        Ok(((self.uint(len)? << shift) as i64) >> shift)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        let marker = self.take(1)?[0];
+        Ok(match marker {
+            0x00..=0x7f => Value::Int(i128::from(marker)),
+            0x80..=0x8f => self.map(usize::from(marker & 0x0f))?,
+            0x90..=0x9f => self.array(usize::from(marker & 0x0f))?,
+            0xa0..=0xbf => self.str(usize::from(marker & 0x1f))?,
+            0xc0 => Value::Nil,
+            0xc2 => Value::Bool(false),
+            0xc3 => Value::Bool(true),
+            0xc4..=0xc6 => {
+                let len = self.uint(1 << (marker - 0xc4))? as usize;
+                Value::Bin(self.take(len)?.to_vec())
+            }
+            0xca => Value::Float(f64::from(f32::from_bits(self.uint(4)? as u32))),
+            0xcb => Value::Float(f64::from_bits(self.uint(8)?)),
+            0xcc..=0xcf => Value::Int(i128::from(self.uint(1 << (marker - 0xcc))?)),
+            0xd0..=0xd3 => Value::Int(i128::from(self.int(1 << (marker - 0xd0))?)),
+            0xd9..=0xdb => {
+                let len = self.uint(1 << (marker - 0xd9))? as usize;
+                self.str(len)?
+            }
+            0xdc | 0xdd => {
+                let len = self.uint(2 << (marker - 0xdc))? as usize;
+                self.array(len)?
+            }
+            0xde | 0xdf => {
+                let len = self.uint(2 << (marker - 0xde))? as usize;
+                self.map(len)?
+            }
+            0xe0..=0xff => Value::Int(i128::from(marker as i8)),
+            _ => return Err(format!("unsupported marker {:#04x}", marker)),
+        })
+    }
+
+    fn str(&mut self, len: usize) -> Result<Value, String> {
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec())
+            .map(Value::Str)
+            .map_err(|_| "string isn't UTF-8".to_owned())
+    }
+
+    fn array(&mut self, len: usize) -> Result<Value, String> {
+        self.nested(|reader| {
+            // Every element takes at least a byte, so this can't be made to
+            // allocate more than the input.
+            let mut values = Vec::with_capacity(len.min(reader.bytes.len() - reader.pos));
+            for _ in 0..len {
+                values.push(reader.value()?);
+            }
+            Ok(Value::Array(values))
+        })
+    }
+
+    fn map(&mut self, len: usize) -> Result<Value, String> {
+        self.nested(|reader| {
+            let mut entries = Vec::with_capacity(len.min(reader.bytes.len() - reader.pos));
+            for _ in 0..len {
+                entries.push((reader.value()?, reader.value()?));
+            }
+            Ok(Value::Map(entries))
+        })
+    }
+
+    fn nested(
+        &mut self,
+        f: impl FnOnce(&mut Reader<'a>) -> Result<Value, String>,
+    ) -> Result<Value, String> {
+        if self.depth == MAX_DEPTH {
+            return Err("nested too deeply".to_owned());
+        }
+        self.depth += 1;
+        let value = f(self);
+        self.depth -= 1;
+        value
+    }
+}
@@ -0,0 +1,253 @@
+//! How many blocks of a kernel fit on a compute unit at once, and launch
+//! configurations that make the most of it.
+
+use crate::{
+    hipModuleOccupancyMaxActiveBlocksPerMultiprocessorWithFlags,
+    hipModuleOccupancyMaxPotentialBlockSizeWithFlags, hipOccupancyDefault, Function,
+    KernelMetadata, LaunchConfig, Result, TargetId,
+};
+use std::convert::TryFrom;
+
+/// The resources of a compute unit that limit how many waves run on it at
+/// once, for estimating occupancy without a GPU.
+///
+/// The estimate is the one the HIP runtime makes: the waves a SIMD can hold
+/// given the kernel's registers, times the SIMDs in a compute unit, in whole
+/// blocks, as far as LDS allows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OccupancyModel {
+    /// The number of work-items in a wave.
+    pub wavefront_size: u32,
+    /// The number of SIMDs in a compute unit.
+    pub simds_per_cu: u32,
+    /// The most waves a SIMD can hold.
+    pub max_waves_per_simd: u32,
+    /// The vector registers a SIMD has for each work-item.
+    pub vgprs_per_simd: u32,
+    /// The number of vector registers allocated at a time.
+    pub vgpr_granule: u32,
+    /// The scalar registers a SIMD has, or `None` if they don't limit
+    /// occupancy.
+    pub sgprs_per_simd: Option<u32>,
+    /// The number of scalar registers allocated at a time.
+    pub sgpr_granule: u32,
+    /// The LDS (shared memory) in a compute unit, in bytes.
+    pub lds_per_cu: u32,
+    /// The most work-items in a block.
+    pub max_threads_per_block: u32,
+}
+
+impl OccupancyModel {
+    /// The model for `target`'s processor, or `None` if this crate doesn't
+    /// know it. GCN5 (`gfx900` to `gfx90c`) and CDNA (`gfx908`, `gfx90a`,
+    /// `gfx940` to `gfx942`) processors are known.
+    pub fn for_target(target: &TargetId) -> Option<OccupancyModel> {
+        let gcn5 = OccupancyModel {
+            wavefront_size: 64,
+            simds_per_cu: 4,
+            max_waves_per_simd: 10,
+            vgprs_per_simd: 256,
+            vgpr_granule: 4,
+            sgprs_per_simd: Some(800),
+            sgpr_granule: 16,
+            lds_per_cu: 64 * 1024,
+            max_threads_per_block: 1024,
+        };
+        match target.processor() {
+            // gfx908's accumulation registers are a separate file the same
+            // size as the vector registers.
+            "gfx900" | "gfx902" | "gfx904" | "gfx906" | "gfx908" | "gfx909" | "gfx90c" => {
+                Some(gcn5)
+            }
+            // Vector and accumulation registers share a unified file.
+            "gfx90a" | "gfx940" | "gfx941" | "gfx942" => Some(OccupancyModel {
+                max_waves_per_simd: 8,
+                vgprs_per_simd: 512,
+                vgpr_granule: 8,
+                ..gcn5
+            }),
+            _ => None,
+        }
+    }
+
+    /// How many blocks of `block_size` work-items of `kernel`, each with
+    /// `dynamic_lds` bytes of dynamic LDS, fit on a compute unit at once.
+    pub fn occupancy(
+        &self,
+        kernel: &KernelMetadata,
+        block_size: u32,
+        dynamic_lds: u32,
+    ) -> Occupancy {
+        let none = |limiter| Occupancy {
+            blocks_per_cu: 0,
+            waves_per_cu: 0,
+            limiter,
+        };
+        // A model without waves or SIMDs has nowhere to put a block.
+        if block_size == 0
+            || block_size > self.max_threads_per_block
+            || block_size > kernel.max_flat_workgroup_size
+            || self.wavefront_size == 0
+            || self.simds_per_cu == 0
+        {
+            return none(OccupancyLimiter::BlockSize);
+        }
+
+        // Waves per SIMD, as limited by each resource. Counts are widened
+        // to `u64`, as register counts come from untrusted code objects.
+        let mut limiter = OccupancyLimiter::Waves;
+        let mut waves_per_simd = u64::from(self.max_waves_per_simd);
+        // Accumulation registers are either counted in the vector registers
+        // or come from a separate file of the same size. No limit if the
+        // kernel doesn't use any.
+        let vgprs = round_up(kernel.vgpr_count.max(kernel.agpr_count), self.vgpr_granule);
+        if let Some(vgpr_waves) = u64::from(self.vgprs_per_simd).checked_div(vgprs) {
+            if vgpr_waves < waves_per_simd {
+                waves_per_simd = vgpr_waves;
+                limiter = OccupancyLimiter::Vgprs;
+            }
+        }
+        if let Some(sgprs_per_simd) = self.sgprs_per_simd {
+            let sgprs = round_up(kernel.sgpr_count, self.sgpr_granule);
+            if let Some(sgpr_waves) = u64::from(sgprs_per_simd).checked_div(sgprs) {
+                if sgpr_waves < waves_per_simd {
+                    waves_per_simd = sgpr_waves;
+                    limiter = OccupancyLimiter::Sgprs;
+                }
+            }
+        }
+
+        let waves_per_block =
+            round_up(block_size, self.wavefront_size) / u64::from(self.wavefront_size);
+        let mut blocks = waves_per_simd * u64::from(self.simds_per_cu) / waves_per_block;
+        let lds = u64::from(kernel.lds_size) + u64::from(dynamic_lds);
+        // No limit if the kernel doesn't use LDS.
+        if let Some(lds_blocks) = u64::from(self.lds_per_cu).checked_div(lds) {
+            if lds_blocks < blocks {
+                blocks = lds_blocks;
+                limiter = OccupancyLimiter::Lds;
+            }
+        }
+        Occupancy {
+            blocks_per_cu: saturate(blocks),
+            waves_per_cu: saturate(blocks * waves_per_block),
+            limiter,
+        }
+    }
+
+    /// The block size that keeps the most waves of `kernel` running on a
+    /// compute unit, preferring larger blocks on ties, or `None` if no block
+    /// fits.
+    pub fn optimal_block_size(&self, kernel: &KernelMetadata, dynamic_lds: u32) -> Option<u32> {
+        let largest = self
+            .max_threads_per_block
+            .min(kernel.max_flat_workgroup_size);
+        let mut best: Option<(u32, u32)> = None;
+        // No block fits a model without waves.
+        let largest_waves = largest.checked_div(self.wavefront_size).unwrap_or(0);
+        for waves in (1..=largest_waves).rev() {
+            let block_size = waves * self.wavefront_size;
+            let occupancy = self.occupancy(kernel, block_size, dynamic_lds);
+            if occupancy.waves_per_cu > best.map_or(0, |(_, waves)| waves) {
+                best = Some((block_size, occupancy.waves_per_cu));
+            }
+        }
+        best.map(|(block_size, _)| block_size)
+    }
+}
+
+/// How many blocks fit on a compute unit at once, from
+/// [`OccupancyModel::occupancy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Occupancy {
+    /// The number of blocks.
+    pub blocks_per_cu: u32,
+    /// The number of waves in those blocks.
+    pub waves_per_cu: u32,
+    /// What stops more blocks from fitting.
+    pub limiter: OccupancyLimiter,
+}
+
+/// The resource that limits occupancy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OccupancyLimiter {
+    /// The most waves a SIMD can hold.
+    Waves,
+    /// Vector (or accumulation) registers.
+    Vgprs,
+    /// Scalar registers.
+    Sgprs,
+    /// LDS.
+    Lds,
+    /// The block size is zero or larger than the kernel or device allows.
+    BlockSize,
+}
+
+impl Function<'_> {
+    /// How many blocks of `block_size` threads, each with `shared_mem` bytes
+    /// of dynamic shared memory, fit on a compute unit at once, according
+    /// to the runtime.
+    pub fn max_active_blocks(&self, block_size: u32, shared_mem: u32) -> Result<u32> {
+        let mut blocks = 0;
+        self.device()
+            .scope(|| unsafe {
+                hipModuleOccupancyMaxActiveBlocksPerMultiprocessorWithFlags(
+                    &mut blocks,
+                    self.as_raw(),
+                    block_size as i32,
+                    shared_mem as usize,
+                    hipOccupancyDefault,
+                )
+            })?
+            .into_result()?;
+        Ok(blocks.max(0) as u32)
+    }
+
+    /// The block size with the highest occupancy when each block has
+    /// `shared_mem` bytes of dynamic shared memory, and the smallest grid
+    /// that fills the device with such blocks, as `(grid, block)`.
+    pub fn potential_block_size(&self, shared_mem: u32) -> Result<(u32, u32)> {
+        let mut grid = 0;
+        let mut block = 0;
+        self.device()
+            .scope(|| unsafe {
+                hipModuleOccupancyMaxPotentialBlockSizeWithFlags(
+                    &mut grid,
+                    &mut block,
+                    self.as_raw(),
+                    shared_mem as usize,
+                    self.limits().max_threads_per_block as i32,
+                    hipOccupancyDefault,
+                )
+            })?
+            .into_result()?;
+        Ok((grid.max(0) as u32, block.max(0) as u32))
+    }
+
+    /// A launch of one thread per element over `len` elements, in blocks of
+    /// the size with the highest occupancy, each with `shared_mem` bytes of
+    /// dynamic shared memory.
+    pub fn optimal_launch_config(
+        &self,
+        len: u32,
+        shared_mem: u32,
+    ) -> Result<LaunchConfig<'static>> {
+        let (_, block) = self.potential_block_size(shared_mem)?;
+        Ok(LaunchConfig::for_len(len, block).with_shared_mem(shared_mem))
+    }
+}
+
+/// `value` rounded up to a multiple of `granule`, which can't overflow in a
+/// `u64`.
+fn round_up(value: u32, granule: u32) -> u64 {
+    let value = u64::from(value);
+    match u64::from(granule) {
+        0 => value,
+        granule => (value + granule - 1) / granule * granule,
+    }
+}
+
+/// `value`, or `u32::MAX` if it doesn't fit.
+fn saturate(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
@@ -0,0 +1,370 @@
+//! Memory pools for stream-ordered allocation.
+
+use crate::memory::byte_size;
+use crate::{
+    hipDeviceGetDefaultMemPool, hipDeviceGetMemPool, hipDeviceSetMemPool, hipError_t, hipFreeAsync,
+    hipMallocFromPoolAsync, hipMemAccessDesc, hipMemAccessFlags, hipMemAllocationHandleType,
+    hipMemAllocationType, hipMemLocation, hipMemLocationType, hipMemPoolAttr, hipMemPoolCreate,
+    hipMemPoolDestroy, hipMemPoolGetAccess, hipMemPoolGetAttribute, hipMemPoolProps,
+    hipMemPoolSetAccess, hipMemPoolSetAttribute, hipMemPoolTrimTo, hipMemPool_t, Device,
+    DeviceSlice, DeviceSliceMut, HipError, Result, Stream,
+};
+use std::{ffi::c_void, fmt, mem::MaybeUninit, ptr};
+
+/// A HIP memory pool. Pools created with [`MemPool::new`] are destroyed on
+/// drop; a device's default or current pool is not.
+///
+/// Destroying a pool with outstanding allocations is fine: the runtime
+/// releases it once they've all been freed.
+#[derive(Debug)]
+pub struct MemPool {
+    raw: hipMemPool_t,
+    owned: bool,
+}
+
+// Safety: HIP memory pools can be used from any thread.
+unsafe impl Send for MemPool {}
+unsafe impl Sync for MemPool {}
+
+impl MemPool {
+    /// Create a pool of pinned memory on `device`.
+    pub fn new(device: Device) -> Result<MemPool> {
+        MemPool::with_handle_type(device, hipMemAllocationHandleType::hipMemHandleTypeNone)
+    }
+
+    /// Create a pool of pinned memory on `device` whose allocations can be
+    /// exported as `handle_type`.
+    pub fn with_handle_type(
+        device: Device,
+        handle_type: hipMemAllocationHandleType,
+    ) -> Result<MemPool> {
+        // Safety: the struct is plain data, and zeroes are required for the
+        // reserved bytes.
+        let mut props: hipMemPoolProps = unsafe { MaybeUninit::zeroed().assume_init() };
+        props.allocType = hipMemAllocationType::hipMemAllocationTypePinned;
+        props.handleTypes = handle_type;
+        props.location = device_location(device);
+        MemPool::from_props(&props)
+    }
+
+    /// Create a pool with the given properties.
+    pub fn from_props(props: &hipMemPoolProps) -> Result<MemPool> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipMemPoolCreate(&mut raw, props) }.into_result()?;
+        Ok(MemPool { raw, owned: true })
+    }
+
+    /// The pool `device` starts out allocating from.
+    pub fn default_for(device: Device) -> Result<MemPool> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipDeviceGetDefaultMemPool(&mut raw, device.ordinal()) }.into_result()?;
+        Ok(MemPool { raw, owned: false })
+    }
+
+    /// The pool `device` currently allocates from with `hipMallocAsync`.
+    pub fn current_for(device: Device) -> Result<MemPool> {
+        let mut raw = ptr::null_mut();
+        unsafe { hipDeviceGetMemPool(&mut raw, device.ordinal()) }.into_result()?;
+        Ok(MemPool { raw, owned: false })
+    }
+
+    /// Make `device` allocate from this pool with `hipMallocAsync`. The pool
+    /// must outlive that use.
+    pub fn set_current_for(&self, device: Device) -> Result<()> {
+        unsafe { hipDeviceSetMemPool(device.ordinal(), self.raw) }.into_result()
+    }
+
+    /// Take ownership of a raw pool.
+    ///
+    /// # Safety
+    /// `raw` must be a valid pool that nothing else will destroy.
+    pub unsafe fn from_raw(raw: hipMemPool_t) -> MemPool {
+        MemPool { raw, owned: true }
+    }
+
+    /// The raw pool, which remains owned by this `MemPool`.
+    pub fn as_raw(&self) -> hipMemPool_t {
+        self.raw
+    }
+
+    /// Query a pool attribute, e.g.
+    /// `pool.attribute::<pool_attribute::UsedMemCurrent>()`.
+    pub fn attribute<A: MemPoolAttribute>(&self) -> Result<A::Value> {
+        let mut raw = <A::Value as PoolAttributeValue>::Raw::default();
+        unsafe {
+            hipMemPoolGetAttribute(self.raw, A::ATTRIBUTE, &mut raw as *mut _ as *mut c_void)
+        }
+        .into_result()?;
+        Ok(A::Value::from_raw(raw))
+    }
+
+    /// Set a pool attribute, e.g.
+    /// `pool.set_attribute::<pool_attribute::ReleaseThreshold>(u64::MAX)`.
+    /// The high-water marks can only be reset, by setting them to zero.
+    pub fn set_attribute<A: SettableMemPoolAttribute>(&self, value: A::Value) -> Result<()> {
+        let mut raw = value.to_raw();
+        unsafe { hipMemPoolSetAttribute(self.raw, A::ATTRIBUTE, &mut raw as *mut _ as *mut c_void) }
+            .into_result()
+    }
+
+    /// Release unused memory until the pool holds at most `min_bytes`, or
+    /// only memory backing live allocations.
+    pub fn trim_to(&self, min_bytes: usize) -> Result<()> {
+        unsafe { hipMemPoolTrimTo(self.raw, min_bytes) }.into_result()
+    }
+
+    /// Set how `device` can access memory from the pool.
+    pub fn set_access(&self, device: Device, access: MemAccess) -> Result<()> {
+        let desc = hipMemAccessDesc {
+            location: device_location(device),
+            flags: access.into(),
+        };
+        unsafe { hipMemPoolSetAccess(self.raw, &desc, 1) }.into_result()
+    }
+
+    /// How `device` can access memory from the pool.
+    pub fn access(&self, device: Device) -> Result<MemAccess> {
+        let mut flags = hipMemAccessFlags::hipMemAccessFlagsProtNone;
+        let mut location = device_location(device);
+        unsafe { hipMemPoolGetAccess(&mut flags, self.raw, &mut location) }.into_result()?;
+        Ok(MemAccess::from_raw(flags))
+    }
+
+    /// Allocate space for `len` values from the pool, in stream order on
+    /// `stream`. The memory is freed, again in stream order, when the buffer
+    /// is dropped.
+    ///
+    /// Fails with `hipErrorOutOfMemory` if the size in bytes overflows.
+    ///
+    /// # Safety
+    /// The contents must be written before they're read, and the buffer may
+    /// only be used by work queued on `stream` (or other streams made to wait
+    /// for it) until `stream` has been synchronised.
+    pub unsafe fn alloc<'s, T: Copy>(
+        &self,
+        len: usize,
+        stream: &'s Stream,
+    ) -> Result<PoolBuffer<'s, T>> {
+        let bytes = byte_size::<T>(len).ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))?;
+        let mut ptr = ptr::null_mut();
+        if bytes > 0 {
+            hipMallocFromPoolAsync(&mut ptr, bytes, self.raw, stream.as_raw()).into_result()?;
+        }
+        Ok(PoolBuffer {
+            ptr: ptr as *mut T,
+            len,
+            stream,
+        })
+    }
+}
+
+impl Drop for MemPool {
+    fn drop(&mut self) {
+        if self.owned {
+            let _ = unsafe { hipMemPoolDestroy(self.raw) };
+        }
+    }
+}
+
+pub(crate) fn device_location(device: Device) -> hipMemLocation {
+    hipMemLocation {
+        type_: hipMemLocationType::hipMemLocationTypeDevice,
+        id: device.ordinal(),
+    }
+}
+
+/// How a device can access memory, for [`MemPool::set_access`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemAccess {
+    /// No access.
+    None,
+    /// Read-only access.
+    Read,
+    /// Read and write access.
+    ReadWrite,
+}
+
+impl MemAccess {
+    fn from_raw(flags: hipMemAccessFlags) -> MemAccess {
+        match flags {
+            hipMemAccessFlags::hipMemAccessFlagsProtRead => MemAccess::Read,
+            hipMemAccessFlags::hipMemAccessFlagsProtReadWrite => MemAccess::ReadWrite,
+            _ => MemAccess::None,
+        }
+    }
+}
+
+impl From<MemAccess> for hipMemAccessFlags {
+    fn from(access: MemAccess) -> hipMemAccessFlags {
+        match access {
+            MemAccess::None => hipMemAccessFlags::hipMemAccessFlagsProtNone,
+            MemAccess::Read => hipMemAccessFlags::hipMemAccessFlagsProtRead,
+            MemAccess::ReadWrite => hipMemAccessFlags::hipMemAccessFlagsProtReadWrite,
+        }
+    }
+}
+
+/// An allocation of `len` values of `T` from a [`MemPool`], freed in stream
+/// order on the stream it was allocated on when dropped.
+pub struct PoolBuffer<'s, T: Copy> {
+    // Null if nothing was allocated, i.e. the buffer is zero-sized.
+    ptr: *mut T,
+    len: usize,
+    stream: &'s Stream,
+}
+
+// Safety: the buffer owns its allocation, and device memory can be used from
+// any thread.
+unsafe impl<T: Copy + Send> Send for PoolBuffer<'_, T> {}
+unsafe impl<T: Copy + Sync> Sync for PoolBuffer<'_, T> {}
+
+impl<'s, T: Copy> PoolBuffer<'s, T> {
+    /// The number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The stream the buffer was allocated on, and will be freed on.
+    pub fn stream(&self) -> &'s Stream {
+        self.stream
+    }
+
+    /// The device pointer to the start of the buffer. Null if the buffer is
+    /// zero-sized.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// The mutable device pointer to the start of the buffer. Null if the
+    /// buffer is zero-sized.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// A view of the whole buffer.
+    pub fn as_slice(&self) -> DeviceSlice<'_, T> {
+        unsafe { DeviceSlice::from_raw_parts(self.ptr, self.len, None) }
+    }
+
+    /// A mutable view of the whole buffer.
+    pub fn as_slice_mut(&mut self) -> DeviceSliceMut<'_, T> {
+        unsafe { DeviceSliceMut::from_raw_parts(self.ptr, self.len, None) }
+    }
+}
+
+impl<T: Copy> Drop for PoolBuffer<'_, T> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            let _ = unsafe { hipFreeAsync(self.ptr as *mut c_void, self.stream.as_raw()) };
+        }
+    }
+}
+
+impl<T: Copy> fmt::Debug for PoolBuffer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolBuffer")
+            .field("ptr", &self.ptr)
+            .field("len", &self.len)
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+/// A memory pool attribute that can be queried with [`MemPool::attribute`].
+pub trait MemPoolAttribute {
+    /// The raw attribute identifier.
+    const ATTRIBUTE: hipMemPoolAttr;
+    /// The type of the attribute's value.
+    type Value: PoolAttributeValue;
+}
+
+/// A memory pool attribute that can be set with [`MemPool::set_attribute`].
+pub trait SettableMemPoolAttribute: MemPoolAttribute {}
+
+/// A type that memory pool attribute values can be converted to.
+pub trait PoolAttributeValue {
+    /// The type HIP reads or writes the value as.
+    type Raw: Copy + Default;
+    /// Convert the value reported by `hipMemPoolGetAttribute`.
+    fn from_raw(raw: Self::Raw) -> Self;
+    /// Convert the value for `hipMemPoolSetAttribute`.
+    fn to_raw(self) -> Self::Raw;
+}
+
+impl PoolAttributeValue for bool {
+    type Raw = i32;
+
+    fn from_raw(raw: i32) -> bool {
+        raw != 0
+    }
+
+    fn to_raw(self) -> i32 {
+        i32::from(self)
+    }
+}
+
+impl PoolAttributeValue for u64 {
+    type Raw = u64;
+
+    fn from_raw(raw: u64) -> u64 {
+        raw
+    }
+
+    fn to_raw(self) -> u64 {
+        self
+    }
+}
+
+macro_rules! pool_attributes {
+    ($($(#[$doc:meta])* $name:ident => $raw:ident: $ty:ty $(, $settable:ident)?;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Copy, Debug)]
+            pub enum $name {}
+
+            impl MemPoolAttribute for $name {
+                const ATTRIBUTE: hipMemPoolAttr = hipMemPoolAttr::$raw;
+                type Value = $ty;
+            }
+
+            $(impl $settable for $name {})?
+        )*
+    };
+}
+
+/// Typed memory pool attributes for [`MemPool::attribute`] and
+/// [`MemPool::set_attribute`], one per [`hipMemPoolAttr`].
+pub mod pool_attribute {
+    use super::{MemPoolAttribute, SettableMemPoolAttribute};
+    use crate::hipMemPoolAttr;
+
+    pool_attributes! {
+        /// Whether freed memory can be reused by a stream that waits on an
+        /// event recorded after the free.
+        ReuseFollowEventDependencies => hipMemPoolReuseFollowEventDependencies: bool, SettableMemPoolAttribute;
+        /// Whether freed memory can be reused by any stream once the free
+        /// has completed.
+        ReuseAllowOpportunistic => hipMemPoolReuseAllowOpportunistic: bool, SettableMemPoolAttribute;
+        /// Whether the runtime may insert dependencies between streams to
+        /// reuse freed memory.
+        ReuseAllowInternalDependencies => hipMemPoolReuseAllowInternalDependencies: bool, SettableMemPoolAttribute;
+        /// How many bytes of unused memory the pool keeps when a stream is
+        /// synchronised, rather than releasing to the system.
+        ReleaseThreshold => hipMemPoolAttrReleaseThreshold: u64, SettableMemPoolAttribute;
+        /// The bytes of memory the pool currently holds.
+        ReservedMemCurrent => hipMemPoolAttrReservedMemCurrent: u64;
+        /// The high-water mark of [`ReservedMemCurrent`], which can be reset
+        /// to zero.
+        ReservedMemHigh => hipMemPoolAttrReservedMemHigh: u64, SettableMemPoolAttribute;
+        /// The bytes of memory currently allocated from the pool.
+        UsedMemCurrent => hipMemPoolAttrUsedMemCurrent: u64;
+        /// The high-water mark of [`UsedMemCurrent`], which can be reset to
+        /// zero.
+        UsedMemHigh => hipMemPoolAttrUsedMemHigh: u64, SettableMemPoolAttribute;
+    }
+}
@@ -0,0 +1,379 @@
+//! Streams of asynchronous device work.
+
+use crate::memory::check_len;
+use crate::{
+    hipDeviceGetStreamPriorityRange, hipError_t, hipLaunchHostFunc, hipMemcpyAsync, hipMemcpyKind,
+    hipMemcpyPeerAsync, hipMemsetAsync, hipStreamAddCallback, hipStreamCaptureStatus,
+    hipStreamCreateWithFlags, hipStreamCreateWithPriority, hipStreamDefault, hipStreamDestroy,
+    hipStreamGetFlags, hipStreamGetPriority, hipStreamIsCapturing, hipStreamQuery,
+    hipStreamSynchronize, hipStreamWaitEvent, hipStream_t, Device, DeviceSlice, DeviceSliceMut,
+    Event, HipError, Result,
+};
+use std::{
+    ffi::c_void,
+    marker::PhantomData,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+/// A HIP stream, destroyed on drop. Work queued on it runs on the device
+/// that was current when it was created.
+#[derive(Debug)]
+pub struct Stream {
+    raw: hipStream_t,
+    device: Device,
+}
+
+// Safety: HIP streams can be used from any thread.
+unsafe impl Send for Stream {}
+unsafe impl Sync for Stream {}
+
+impl Stream {
+    /// Create a stream with the default flags and priority.
+    pub fn new() -> Result<Stream> {
+        Stream::with_flags(hipStreamDefault)
+    }
+
+    /// Create a stream with `hipStream*` flags, e.g. `hipStreamNonBlocking`.
+    pub fn with_flags(flags: u32) -> Result<Stream> {
+        let device = Device::current()?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipStreamCreateWithFlags(&mut raw, flags) }.into_result()?;
+        Ok(Stream { raw, device })
+    }
+
+    /// Create a stream with `hipStream*` flags and a priority within
+    /// [`Stream::priority_range`].
+    pub fn with_priority(flags: u32, priority: i32) -> Result<Stream> {
+        let device = Device::current()?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipStreamCreateWithPriority(&mut raw, flags, priority) }.into_result()?;
+        Ok(Stream { raw, device })
+    }
+
+    /// The stream priorities supported by the current device, as
+    /// `(least, greatest)`. Lower numbers are higher priorities.
+    pub fn priority_range() -> Result<(i32, i32)> {
+        let mut least = 0;
+        let mut greatest = 0;
+        unsafe { hipDeviceGetStreamPriorityRange(&mut least, &mut greatest) }.into_result()?;
+        Ok((least, greatest))
+    }
+
+    /// Take ownership of a raw stream.
+    ///
+    /// # Safety
+    /// `raw` must be a valid stream on `device` that nothing else will
+    /// destroy.
+    pub unsafe fn from_raw(raw: hipStream_t, device: Device) -> Stream {
+        Stream { raw, device }
+    }
+
+    /// The raw stream, which remains owned by this `Stream`.
+    pub fn as_raw(&self) -> hipStream_t {
+        self.raw
+    }
+
+    /// Give up ownership of the raw stream without destroying it.
+    pub fn into_raw(self) -> hipStream_t {
+        let raw = self.raw;
+        mem::forget(self);
+        raw
+    }
+
+    /// The device the stream's work runs on.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The flags the stream was created with.
+    pub fn flags(&self) -> Result<u32> {
+        let mut flags = 0;
+        unsafe { hipStreamGetFlags(self.raw, &mut flags) }.into_result()?;
+        Ok(flags)
+    }
+
+    /// The stream's priority.
+    pub fn priority(&self) -> Result<i32> {
+        let mut priority = 0;
+        unsafe { hipStreamGetPriority(self.raw, &mut priority) }.into_result()?;
+        Ok(priority)
+    }
+
+    /// Block until all work queued on the stream completes.
+    pub fn synchronize(&self) -> Result<()> {
+        unsafe { hipStreamSynchronize(self.raw) }.into_result()
+    }
+
+    /// Whether all work queued on the stream has completed, without
+    /// blocking.
+    pub fn query(&self) -> Result<bool> {
+        match unsafe { hipStreamQuery(self.raw) } {
+            hipError_t::hipErrorNotReady => Ok(false),
+            e => e.into_result().map(|()| true),
+        }
+    }
+
+    /// Whether work queued on the stream is being captured into a graph
+    /// rather than run.
+    pub fn is_capturing(&self) -> Result<bool> {
+        let mut status = hipStreamCaptureStatus::hipStreamCaptureStatusNone;
+        unsafe { hipStreamIsCapturing(self.raw, &mut status) }.into_result()?;
+        Ok(status != hipStreamCaptureStatus::hipStreamCaptureStatusNone)
+    }
+
+    /// Make work queued on the stream from now on wait for `event`.
+    pub fn wait_event(&self, event: &Event) -> Result<()> {
+        unsafe { hipStreamWaitEvent(self.raw, event.as_raw(), 0) }.into_result()
+    }
+
+    /// Queue asynchronous copies and fills that borrow host and device
+    /// memory, then wait for the stream to finish. Everything `f` borrows
+    /// stays borrowed until then, even if `f` panics or the wait fails.
+    ///
+    /// ```no_run
+    /// # use hip_runtime_sys::{DeviceBuffer, Stream};
+    /// # fn main() -> hip_runtime_sys::Result<()> {
+    /// let stream = Stream::new()?;
+    /// let host = vec![1.0f32; 1024];
+    /// let mut buffer = unsafe { DeviceBuffer::<f32>::uninitialized(host.len())? };
+    /// stream.scope(|s| s.copy_from_host(buffer.as_slice_mut(), &host))??;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scope<'env, F, R>(&self, f: F) -> Result<R>
+    where
+        F: for<'scope> FnOnce(&'scope StreamScope<'scope, 'env>) -> R,
+    {
+        struct Synchronize<'a>(&'a Stream);
+        impl Drop for Synchronize<'_> {
+            fn drop(&mut self) {
+                let _ = self.0.synchronize();
+            }
+        }
+
+        // Safety: the stream is synchronised before the borrows end.
+        let scope = unsafe { StreamScope::new(self) };
+        let guard = Synchronize(self);
+        let result = f(&scope);
+        mem::forget(guard);
+        self.synchronize()?;
+        Ok(result)
+    }
+
+    /// Run `f` on a host thread once the work queued on the stream so far
+    /// completes. `f` must not call HIP functions. A panic in `f` is caught
+    /// rather than unwinding into the runtime; the panic hook still reports
+    /// it.
+    ///
+    /// Fails with `hipErrorStreamCaptureUnsupported` while the stream is
+    /// being captured, since a graph could call `f` more than once; use
+    /// [`Graph::add_host`](crate::Graph::add_host) instead.
+    pub fn launch_host_func<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.check_not_capturing()?;
+        unsafe extern "C" fn trampoline<F: FnOnce()>(data: *mut c_void) {
+            let f = Box::from_raw(data as *mut F);
+            let _ = panic::catch_unwind(AssertUnwindSafe(f));
+        }
+
+        let data = Box::into_raw(Box::new(f)) as *mut c_void;
+        let result = unsafe { hipLaunchHostFunc(self.raw, Some(trampoline::<F>), data) };
+        if result != hipError_t::hipSuccess {
+            // The runtime didn't take the closure, so it won't be called.
+            drop(unsafe { Box::from_raw(data as *mut F) });
+        }
+        result.into_result()
+    }
+
+    /// Run `f` on a host thread once the work queued on the stream so far
+    /// completes, passing it the stream's status. `f` must not call HIP
+    /// functions. A panic in `f` is caught rather than unwinding into the
+    /// runtime; the panic hook still reports it.
+    ///
+    /// Fails with `hipErrorStreamCaptureUnsupported` while the stream is
+    /// being captured.
+    pub fn add_callback<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(Result<()>) + Send + 'static,
+    {
+        self.check_not_capturing()?;
+        unsafe extern "C" fn trampoline<F: FnOnce(Result<()>)>(
+            _stream: hipStream_t,
+            status: hipError_t,
+            data: *mut c_void,
+        ) {
+            let f = Box::from_raw(data as *mut F);
+            let _ = panic::catch_unwind(AssertUnwindSafe(move || f(status.into_result())));
+        }
+
+        let data = Box::into_raw(Box::new(f)) as *mut c_void;
+        let result = unsafe { hipStreamAddCallback(self.raw, Some(trampoline::<F>), data, 0) };
+        if result != hipError_t::hipSuccess {
+            drop(unsafe { Box::from_raw(data as *mut F) });
+        }
+        result.into_result()
+    }
+}
+
+impl Stream {
+    fn check_not_capturing(&self) -> Result<()> {
+        if self.is_capturing()? {
+            return Err(HipError::from(hipError_t::hipErrorStreamCaptureUnsupported));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        let _ = unsafe { hipStreamDestroy(self.raw) };
+    }
+}
+
+/// Queues asynchronous operations on a stream for [`Stream::scope`].
+///
+/// Memory passed to these operations is borrowed for `'scope`, which lasts
+/// until the stream has been synchronised, so a buffer written by one
+/// operation can't be read by another in the same scope.
+pub struct StreamScope<'scope, 'env: 'scope> {
+    stream: &'scope Stream,
+    // Invariant, like `std::thread::Scope`, so borrows can't be shortened.
+    _scope: PhantomData<&'scope mut &'scope ()>,
+    _env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> StreamScope<'scope, 'env> {
+    /// A scope queueing on `stream`.
+    ///
+    /// # Safety
+    /// Nothing borrowed for `'scope` may be released until the work queued
+    /// through the scope is done with it.
+    pub(crate) unsafe fn new(stream: &'scope Stream) -> StreamScope<'scope, 'env> {
+        StreamScope {
+            stream,
+            _scope: PhantomData,
+            _env: PhantomData,
+        }
+    }
+
+    /// The stream operations are queued on.
+    pub fn stream(&self) -> &'scope Stream {
+        self.stream
+    }
+
+    /// Queue a copy from `src` into `dst`. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn copy_from_host<T: Copy>(
+        &self,
+        mut dst: DeviceSliceMut<'scope, T>,
+        src: &'scope [T],
+    ) -> Result<()> {
+        check_len(dst.len(), src.len())?;
+        self.copy(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(src),
+            hipMemcpyKind::hipMemcpyHostToDevice,
+        )
+    }
+
+    /// Queue a copy from `src` into `dst`. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn copy_to_host<T: Copy>(
+        &self,
+        src: DeviceSlice<'scope, T>,
+        dst: &'scope mut [T],
+    ) -> Result<()> {
+        check_len(src.len(), dst.len())?;
+        self.copy(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            mem::size_of_val(dst),
+            hipMemcpyKind::hipMemcpyDeviceToHost,
+        )
+    }
+
+    /// Queue a copy between device slices. Fails with `hipErrorInvalidValue`
+    /// if the lengths differ.
+    pub fn copy_device<T: Copy>(
+        &self,
+        src: DeviceSlice<'scope, T>,
+        mut dst: DeviceSliceMut<'scope, T>,
+    ) -> Result<()> {
+        check_len(src.len(), dst.len())?;
+        self.copy(
+            dst.as_mut_ptr() as *mut c_void,
+            src.as_ptr() as *const c_void,
+            src.len() * mem::size_of::<T>(),
+            hipMemcpyKind::hipMemcpyDeviceToDevice,
+        )
+    }
+
+    /// Queue a copy from `src` on `src_device` to `dst` on `dst_device`,
+    /// without staging through the host if they're linked. Fails with
+    /// `hipErrorInvalidValue` if the lengths differ.
+    #[doc(alias = "copy_peer_async")]
+    pub fn copy_peer<T: Copy>(
+        &self,
+        src: DeviceSlice<'scope, T>,
+        src_device: Device,
+        mut dst: DeviceSliceMut<'scope, T>,
+        dst_device: Device,
+    ) -> Result<()> {
+        check_len(src.len(), dst.len())?;
+        let bytes = src.len() * mem::size_of::<T>();
+        if bytes == 0 {
+            return Ok(());
+        }
+        unsafe {
+            hipMemcpyPeerAsync(
+                dst.as_mut_ptr() as *mut c_void,
+                dst_device.ordinal(),
+                src.as_ptr() as *const c_void,
+                src_device.ordinal(),
+                bytes,
+                self.stream.raw,
+            )
+        }
+        .into_result()
+    }
+
+    /// Queue setting every byte of `dst` to `value`.
+    ///
+    /// # Safety
+    /// Values of `T` made of that byte must be valid.
+    pub unsafe fn memset<T: Copy>(
+        &self,
+        mut dst: DeviceSliceMut<'scope, T>,
+        value: u8,
+    ) -> Result<()> {
+        let bytes = dst.len() * mem::size_of::<T>();
+        if bytes == 0 {
+            return Ok(());
+        }
+        hipMemsetAsync(
+            dst.as_mut_ptr() as *mut c_void,
+            i32::from(value),
+            bytes,
+            self.stream.raw,
+        )
+        .into_result()
+    }
+
+    fn copy(
+        &self,
+        dst: *mut c_void,
+        src: *const c_void,
+        bytes: usize,
+        kind: hipMemcpyKind,
+    ) -> Result<()> {
+        if bytes == 0 {
+            return Ok(());
+        }
+        unsafe { hipMemcpyAsync(dst, src, bytes, kind, self.stream.raw) }.into_result()
+    }
+}
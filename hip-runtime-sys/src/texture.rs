@@ -0,0 +1,994 @@
+//! Arrays laid out for texture hardware, texture objects that sample them,
+//! and surface objects that kernels read and write them through.
+
+use crate::memory::{check_len, on_device};
+use crate::{
+    hipArray_t, hipChannelFormatDesc, hipChannelFormatKind, hipCreateSurfaceObject,
+    hipCreateTextureObject, hipDestroySurfaceObject, hipDestroyTextureObject, hipError_t,
+    hipExtent, hipFreeArray, hipFreeMipmappedArray, hipGetMipmappedArrayLevel,
+    hipGetTextureObjectResourceDesc, hipMalloc3DArray, hipMallocArray, hipMallocMipmappedArray,
+    hipMemcpy3D, hipMemcpy3DParms, hipMemcpyKind, hipMipmappedArray_t, hipPitchedPtr,
+    hipResourceDesc, hipResourceType, hipSurfaceObject_t, hipTextureAddressMode, hipTextureDesc,
+    hipTextureFilterMode, hipTextureObject_t, hipTextureReadMode, Device, DeviceSlice, HipError,
+    Result,
+};
+use std::{convert::TryFrom, ffi::c_void, fmt, marker::PhantomData, mem, ptr};
+
+/// How the channels of a texel are interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelKind {
+    /// Signed integers.
+    Signed,
+    /// Unsigned integers.
+    Unsigned,
+    /// Floating-point numbers.
+    Float,
+}
+
+impl From<ChannelKind> for hipChannelFormatKind {
+    fn from(kind: ChannelKind) -> hipChannelFormatKind {
+        match kind {
+            ChannelKind::Signed => hipChannelFormatKind::hipChannelFormatKindSigned,
+            ChannelKind::Unsigned => hipChannelFormatKind::hipChannelFormatKindUnsigned,
+            ChannelKind::Float => hipChannelFormatKind::hipChannelFormatKindFloat,
+        }
+    }
+}
+
+/// The layout of a texel: the bits in each of up to four channels, all of
+/// one kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChannelFormat {
+    /// The bits in the x, y, z and w channels. Unused channels have none.
+    pub bits: [u32; 4],
+    /// How the channels are interpreted.
+    pub kind: ChannelKind,
+}
+
+impl ChannelFormat {
+    /// The format with `bits` in each channel, of `kind`.
+    pub const fn new(bits: [u32; 4], kind: ChannelKind) -> ChannelFormat {
+        ChannelFormat { bits, kind }
+    }
+
+    /// The format of `T`, e.g. `ChannelFormat::of::<[u8; 4]>()` for RGBA
+    /// bytes.
+    pub fn of<T: Channel>() -> ChannelFormat {
+        T::FORMAT
+    }
+
+    /// The number of channels with any bits.
+    pub fn channels(&self) -> usize {
+        self.bits.iter().filter(|&&bits| bits > 0).count()
+    }
+
+    /// The size of a texel, in bytes.
+    pub fn texel_size(&self) -> usize {
+        self.bits.iter().map(|&bits| bits as usize).sum::<usize>() / 8
+    }
+
+    /// The format as HIP describes it, as `hipCreateChannelDesc` would.
+    pub fn to_raw(self) -> hipChannelFormatDesc {
+        let [x, y, z, w] = self.bits;
+        hipChannelFormatDesc {
+            x: x as i32,
+            y: y as i32,
+            z: z as i32,
+            w: w as i32,
+            f: self.kind.into(),
+        }
+    }
+
+    /// The format HIP describes as `desc`, or `None` if its kind is
+    /// `hipChannelFormatKindNone` or its sizes are negative.
+    pub fn from_raw(desc: &hipChannelFormatDesc) -> Option<ChannelFormat> {
+        let kind = match desc.f {
+            hipChannelFormatKind::hipChannelFormatKindSigned => ChannelKind::Signed,
+            hipChannelFormatKind::hipChannelFormatKindUnsigned => ChannelKind::Unsigned,
+            hipChannelFormatKind::hipChannelFormatKindFloat => ChannelKind::Float,
+            _ => return None,
+        };
+        let bits = |bits: i32| u32::try_from(bits).ok();
+        Some(ChannelFormat {
+            bits: [bits(desc.x)?, bits(desc.y)?, bits(desc.z)?, bits(desc.w)?],
+            kind,
+        })
+    }
+}
+
+/// A texel type, which arrays and textures can hold.
+///
+/// # Safety
+/// `FORMAT` must describe the type exactly, so that its texel size is
+/// `size_of::<Self>()`, and every bit pattern must be a valid value.
+pub unsafe trait Channel: Copy {
+    /// The type's format.
+    const FORMAT: ChannelFormat;
+}
+
+macro_rules! channel {
+    ($($t:ty => $kind:ident),*) => {
+        $(
+            unsafe impl Channel for $t {
+                const FORMAT: ChannelFormat = ChannelFormat::new(
+                    [8 * mem::size_of::<$t>() as u32, 0, 0, 0],
+                    ChannelKind::$kind,
+                );
+            }
+
+            unsafe impl Channel for [$t; 2] {
+                const FORMAT: ChannelFormat = ChannelFormat::new(
+                    [8 * mem::size_of::<$t>() as u32, 8 * mem::size_of::<$t>() as u32, 0, 0],
+                    ChannelKind::$kind,
+                );
+            }
+
+            unsafe impl Channel for [$t; 4] {
+                const FORMAT: ChannelFormat = ChannelFormat::new(
+                    [8 * mem::size_of::<$t>() as u32; 4],
+                    ChannelKind::$kind,
+                );
+            }
+        )*
+    };
+}
+
+channel!(
+    i8 => Signed, i16 => Signed, i32 => Signed,
+    u8 => Unsigned, u16 => Unsigned, u32 => Unsigned,
+    f32 => Float
+);
+
+flags! {
+    /// Flags for allocating a [`HipArray`], [`HipArray3D`] or
+    /// [`MipmappedArray`].
+    pub struct ArrayFlags {
+        /// The array is a stack of layers, the last dimension counting them.
+        const LAYERED = hipArrayLayered;
+        /// Kernels can read and write the array through surface objects.
+        const SURFACE_LOAD_STORE = hipArraySurfaceLoadStore;
+        /// The array holds the six faces of a cube.
+        const CUBEMAP = hipArrayCubemap;
+        /// Textures of the array can gather texels.
+        const TEXTURE_GATHER = hipArrayTextureGather;
+    }
+}
+
+/// A one- or two-dimensional array of `T` texels, laid out for texture
+/// hardware, freed on drop.
+pub struct HipArray<T: Channel> {
+    raw: hipArray_t,
+    width: usize,
+    height: usize,
+    device: Device,
+    _marker: PhantomData<T>,
+}
+
+// Safety: the array owns its allocation, which can be used from any thread.
+unsafe impl<T: Channel + Send> Send for HipArray<T> {}
+unsafe impl<T: Channel + Sync> Sync for HipArray<T> {}
+
+impl<T: Channel> HipArray<T> {
+    /// Allocate an array of `width` by `height` texels on the current
+    /// device, or of `width` texels if `height` is zero. The contents are
+    /// unspecified until written, but any bits are a valid texel.
+    pub fn new(width: usize, height: usize, flags: ArrayFlags) -> Result<HipArray<T>> {
+        let device = Device::current()?;
+        let mut raw = ptr::null_mut();
+        unsafe { hipMallocArray(&mut raw, &T::FORMAT.to_raw(), width, height, flags.bits()) }
+            .into_result()?;
+        Ok(HipArray {
+            raw,
+            width,
+            height,
+            device,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The raw array, which remains owned by this `HipArray`.
+    pub fn as_raw(&self) -> hipArray_t {
+        self.raw
+    }
+
+    /// The width, in texels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height, in texels. Zero for a one-dimensional array.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of texels.
+    pub fn len(&self) -> usize {
+        self.width * self.height.max(1)
+    }
+
+    /// Whether the array holds no texels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The device the array is on.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Copy `src`, in row-major order, into the array. Fails with
+    /// `hipErrorInvalidValue` if its length isn't [`HipArray::len`].
+    pub fn copy_from_host(&mut self, src: &[T]) -> Result<()> {
+        let extent = extent(self.width, self.height, 0);
+        unsafe {
+            copy_array(
+                self.device,
+                self.raw,
+                extent,
+                src.as_ptr() as *mut T,
+                src.len(),
+                hipMemcpyKind::hipMemcpyHostToDevice,
+            )
+        }
+    }
+
+    /// Copy the array, in row-major order, into `dst`. Fails with
+    /// `hipErrorInvalidValue` if its length isn't [`HipArray::len`].
+    pub fn copy_to_host(&self, dst: &mut [T]) -> Result<()> {
+        let extent = extent(self.width, self.height, 0);
+        unsafe {
+            copy_array(
+                self.device,
+                self.raw,
+                extent,
+                dst.as_mut_ptr(),
+                dst.len(),
+                hipMemcpyKind::hipMemcpyDeviceToHost,
+            )
+        }
+    }
+
+    /// Copy the array into a new `Vec`, in row-major order.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        array_to_vec(self.device, self.raw, extent(self.width, self.height, 0))
+    }
+}
+
+impl<T: Channel> Drop for HipArray<T> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipFreeArray(self.raw) };
+    }
+}
+
+impl<T: Channel> fmt::Debug for HipArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HipArray")
+            .field("raw", &self.raw)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+/// A one-, two- or three-dimensional array of `T` texels, laid out for
+/// texture hardware, freed on drop.
+pub struct HipArray3D<T: Channel> {
+    raw: hipArray_t,
+    extent: hipExtent,
+    device: Device,
+    _marker: PhantomData<T>,
+}
+
+// Safety: the array owns its allocation, which can be used from any thread.
+unsafe impl<T: Channel + Send> Send for HipArray3D<T> {}
+unsafe impl<T: Channel + Sync> Sync for HipArray3D<T> {}
+
+impl<T: Channel> HipArray3D<T> {
+    /// Allocate an array of `width` by `height` by `depth` texels on the
+    /// current device. A zero `depth`, or zero `height` and `depth`, makes
+    /// a two- or one-dimensional array. With [`ArrayFlags::LAYERED`],
+    /// `depth` counts layers. The contents are unspecified until written,
+    /// but any bits are a valid texel.
+    pub fn new(
+        width: usize,
+        height: usize,
+        depth: usize,
+        flags: ArrayFlags,
+    ) -> Result<HipArray3D<T>> {
+        let device = Device::current()?;
+        let extent = extent(width, height, depth);
+        let mut raw = ptr::null_mut();
+        unsafe { hipMalloc3DArray(&mut raw, &T::FORMAT.to_raw(), extent, flags.bits()) }
+            .into_result()?;
+        Ok(HipArray3D {
+            raw,
+            extent,
+            device,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The raw array, which remains owned by this `HipArray3D`.
+    pub fn as_raw(&self) -> hipArray_t {
+        self.raw
+    }
+
+    /// The width, height and depth, in texels. Unused dimensions are zero.
+    pub fn extent(&self) -> hipExtent {
+        self.extent
+    }
+
+    /// The number of texels.
+    pub fn len(&self) -> usize {
+        texels(self.extent)
+    }
+
+    /// Whether the array holds no texels.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The device the array is on.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// Copy `src`, in row-major order with each slice after the last, into
+    /// the array. Fails with `hipErrorInvalidValue` if its length isn't
+    /// [`HipArray3D::len`].
+    pub fn copy_from_host(&mut self, src: &[T]) -> Result<()> {
+        unsafe {
+            copy_array(
+                self.device,
+                self.raw,
+                self.extent,
+                src.as_ptr() as *mut T,
+                src.len(),
+                hipMemcpyKind::hipMemcpyHostToDevice,
+            )
+        }
+    }
+
+    /// Copy the array, in row-major order with each slice after the last,
+    /// into `dst`. Fails with `hipErrorInvalidValue` if its length isn't
+    /// [`HipArray3D::len`].
+    pub fn copy_to_host(&self, dst: &mut [T]) -> Result<()> {
+        unsafe {
+            copy_array(
+                self.device,
+                self.raw,
+                self.extent,
+                dst.as_mut_ptr(),
+                dst.len(),
+                hipMemcpyKind::hipMemcpyDeviceToHost,
+            )
+        }
+    }
+
+    /// Copy the array into a new `Vec`, in the order
+    /// [`HipArray3D::copy_to_host`] uses.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        array_to_vec(self.device, self.raw, self.extent)
+    }
+}
+
+impl<T: Channel> Drop for HipArray3D<T> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipFreeArray(self.raw) };
+    }
+}
+
+impl<T: Channel> fmt::Debug for HipArray3D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HipArray3D")
+            .field("raw", &self.raw)
+            .field("extent", &self.extent)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+/// An array of `T` texels with a chain of mipmap levels, each half the
+/// size of the one before, freed on drop.
+pub struct MipmappedArray<T: Channel> {
+    raw: hipMipmappedArray_t,
+    extent: hipExtent,
+    levels: u32,
+    device: Device,
+    _marker: PhantomData<T>,
+}
+
+// Safety: the array owns its allocation, which can be used from any thread.
+unsafe impl<T: Channel + Send> Send for MipmappedArray<T> {}
+unsafe impl<T: Channel + Sync> Sync for MipmappedArray<T> {}
+
+impl<T: Channel> MipmappedArray<T> {
+    /// Allocate `levels` levels on the current device, the first
+    /// `width` by `height` by `depth` texels, with unused dimensions zero
+    /// as for [`HipArray3D::new`]. The contents are unspecified until
+    /// written, but any bits are a valid texel.
+    pub fn new(
+        width: usize,
+        height: usize,
+        depth: usize,
+        levels: u32,
+        flags: ArrayFlags,
+    ) -> Result<MipmappedArray<T>> {
+        let device = Device::current()?;
+        let extent = extent(width, height, depth);
+        let mut raw = ptr::null_mut();
+        unsafe {
+            hipMallocMipmappedArray(&mut raw, &T::FORMAT.to_raw(), extent, levels, flags.bits())
+        }
+        .into_result()?;
+        Ok(MipmappedArray {
+            raw,
+            extent,
+            levels,
+            device,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The raw array, which remains owned by this `MipmappedArray`.
+    pub fn as_raw(&self) -> hipMipmappedArray_t {
+        self.raw
+    }
+
+    /// The number of levels.
+    pub fn levels(&self) -> u32 {
+        self.levels
+    }
+
+    /// The device the array is on.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The size of `level`, in texels: the first level's halved `level`
+    /// times, but at least one in each used dimension. `None` if there's
+    /// no such level.
+    pub fn level_extent(&self, level: u32) -> Option<hipExtent> {
+        if level >= self.levels {
+            return None;
+        }
+        let halve = |size: usize| match size {
+            0 => 0,
+            size => (size >> level.min(usize::BITS - 1)).max(1),
+        };
+        Some(hipExtent {
+            width: halve(self.extent.width),
+            height: halve(self.extent.height),
+            depth: halve(self.extent.depth),
+        })
+    }
+
+    /// Copy `src` into `level`, in the order [`HipArray3D::copy_from_host`]
+    /// uses. Fails with `hipErrorInvalidValue` if there's no such level or
+    /// the length isn't the level's number of texels.
+    pub fn copy_level_from_host(&mut self, level: u32, src: &[T]) -> Result<()> {
+        let (raw, extent) = self.level(level)?;
+        unsafe {
+            copy_array(
+                self.device,
+                raw,
+                extent,
+                src.as_ptr() as *mut T,
+                src.len(),
+                hipMemcpyKind::hipMemcpyHostToDevice,
+            )
+        }
+    }
+
+    /// Copy `level` into `dst`, in the order [`HipArray3D::copy_to_host`]
+    /// uses. Fails with `hipErrorInvalidValue` if there's no such level or
+    /// the length isn't the level's number of texels.
+    pub fn copy_level_to_host(&self, level: u32, dst: &mut [T]) -> Result<()> {
+        let (raw, extent) = self.level(level)?;
+        unsafe {
+            copy_array(
+                self.device,
+                raw,
+                extent,
+                dst.as_mut_ptr(),
+                dst.len(),
+                hipMemcpyKind::hipMemcpyDeviceToHost,
+            )
+        }
+    }
+
+    fn level(&self, level: u32) -> Result<(hipArray_t, hipExtent)> {
+        let extent = self
+            .level_extent(level)
+            .ok_or(HipError::from(hipError_t::hipErrorInvalidValue))?;
+        let mut raw = ptr::null_mut();
+        self.device
+            .scope(|| unsafe { hipGetMipmappedArrayLevel(&mut raw, self.raw, level) })?
+            .into_result()?;
+        Ok((raw, extent))
+    }
+}
+
+impl<T: Channel> Drop for MipmappedArray<T> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipFreeMipmappedArray(self.raw) };
+    }
+}
+
+impl<T: Channel> fmt::Debug for MipmappedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MipmappedArray")
+            .field("raw", &self.raw)
+            .field("extent", &self.extent)
+            .field("levels", &self.levels)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+/// What a texture samples: an array, or linear or pitched device memory,
+/// borrowed for `'a`.
+#[derive(Clone, Copy)]
+pub struct TextureResource<'a> {
+    desc: hipResourceDesc,
+    // The device the memory is on, if it's known.
+    device: Option<Device>,
+    _borrow: PhantomData<&'a ()>,
+}
+
+impl<'a> TextureResource<'a> {
+    /// The texels of `slice`, as a one-dimensional texture.
+    pub fn linear<T: Channel>(slice: DeviceSlice<'a, T>) -> TextureResource<'a> {
+        let mut desc = hipResourceDesc {
+            resType: hipResourceType::hipResourceTypeLinear,
+            ..Default::default()
+        };
+        desc.res.linear.devPtr = slice.as_ptr() as *mut c_void;
+        desc.res.linear.desc = T::FORMAT.to_raw();
+        desc.res.linear.sizeInBytes = slice.len() * mem::size_of::<T>();
+        TextureResource::new(desc, slice.device())
+    }
+
+    /// `height` rows of `width` texels in `slice`, each starting `pitch`
+    /// bytes after the one before, as a two-dimensional texture. Fails with
+    /// `hipErrorInvalidValue` if the rows don't fit in the slice or in their
+    /// pitch, or the pitch isn't a whole number of texels.
+    pub fn pitch_2d<T: Channel>(
+        slice: DeviceSlice<'a, T>,
+        width: usize,
+        height: usize,
+        pitch: usize,
+    ) -> Result<TextureResource<'a>> {
+        let texel = mem::size_of::<T>();
+        let fits = pitch % texel == 0
+            && matches!(width.checked_mul(texel), Some(row) if row <= pitch)
+            && (height == 0
+                || matches!(
+                    (height - 1)
+                        .checked_mul(pitch / texel)
+                        .and_then(|start| start.checked_add(width)),
+                    Some(len) if len <= slice.len()
+                ));
+        if !fits {
+            return Err(HipError::from(hipError_t::hipErrorInvalidValue));
+        }
+        let mut desc = hipResourceDesc {
+            resType: hipResourceType::hipResourceTypePitch2D,
+            ..Default::default()
+        };
+        desc.res.pitch2D.devPtr = slice.as_ptr() as *mut c_void;
+        desc.res.pitch2D.desc = T::FORMAT.to_raw();
+        desc.res.pitch2D.width = width;
+        desc.res.pitch2D.height = height;
+        desc.res.pitch2D.pitchInBytes = pitch;
+        Ok(TextureResource::new(desc, slice.device()))
+    }
+
+    /// The description HIP is given.
+    pub fn as_raw(&self) -> &hipResourceDesc {
+        &self.desc
+    }
+
+    /// The device the resource is on, if it's known. Textures of it are
+    /// created with that device current.
+    pub fn device(&self) -> Option<Device> {
+        self.device
+    }
+
+    fn new(desc: hipResourceDesc, device: Option<Device>) -> TextureResource<'a> {
+        TextureResource {
+            desc,
+            device,
+            _borrow: PhantomData,
+        }
+    }
+
+    fn array(raw: hipArray_t, device: Device) -> TextureResource<'a> {
+        let mut desc = hipResourceDesc {
+            resType: hipResourceType::hipResourceTypeArray,
+            ..Default::default()
+        };
+        desc.res.array.array = raw;
+        TextureResource::new(desc, Some(device))
+    }
+}
+
+impl<'a, T: Channel> From<&'a HipArray<T>> for TextureResource<'a> {
+    fn from(array: &'a HipArray<T>) -> TextureResource<'a> {
+        TextureResource::array(array.raw, array.device)
+    }
+}
+
+impl<'a, T: Channel> From<&'a HipArray3D<T>> for TextureResource<'a> {
+    fn from(array: &'a HipArray3D<T>) -> TextureResource<'a> {
+        TextureResource::array(array.raw, array.device)
+    }
+}
+
+impl<'a, T: Channel> From<&'a MipmappedArray<T>> for TextureResource<'a> {
+    fn from(array: &'a MipmappedArray<T>) -> TextureResource<'a> {
+        let mut desc = hipResourceDesc {
+            resType: hipResourceType::hipResourceTypeMipmappedArray,
+            ..Default::default()
+        };
+        desc.res.mipmap.mipmap = array.raw;
+        TextureResource::new(desc, Some(array.device))
+    }
+}
+
+impl<'a, T: Channel> From<DeviceSlice<'a, T>> for TextureResource<'a> {
+    fn from(slice: DeviceSlice<'a, T>) -> TextureResource<'a> {
+        TextureResource::linear(slice)
+    }
+}
+
+impl fmt::Debug for TextureResource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureResource")
+            .field("type", &self.desc.resType)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+/// What a texture returns for coordinates outside it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressMode {
+    /// Wrap around to the other side.
+    Wrap,
+    /// Repeat the edge texel.
+    Clamp,
+    /// Reflect back into the texture.
+    Mirror,
+    /// Return the border color.
+    Border,
+}
+
+impl From<AddressMode> for hipTextureAddressMode {
+    fn from(mode: AddressMode) -> hipTextureAddressMode {
+        match mode {
+            AddressMode::Wrap => hipTextureAddressMode::hipAddressModeWrap,
+            AddressMode::Clamp => hipTextureAddressMode::hipAddressModeClamp,
+            AddressMode::Mirror => hipTextureAddressMode::hipAddressModeMirror,
+            AddressMode::Border => hipTextureAddressMode::hipAddressModeBorder,
+        }
+    }
+}
+
+/// How a texture samples between texels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilterMode {
+    /// Take the nearest texel.
+    Point,
+    /// Interpolate linearly between the nearest texels.
+    Linear,
+}
+
+impl From<FilterMode> for hipTextureFilterMode {
+    fn from(mode: FilterMode) -> hipTextureFilterMode {
+        match mode {
+            FilterMode::Point => hipTextureFilterMode::hipFilterModePoint,
+            FilterMode::Linear => hipTextureFilterMode::hipFilterModeLinear,
+        }
+    }
+}
+
+/// What a texture returns for integer texels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReadMode {
+    /// The texel's own type.
+    ElementType,
+    /// A float from 0 to 1, or -1 to 1 for signed texels.
+    NormalizedFloat,
+}
+
+impl From<ReadMode> for hipTextureReadMode {
+    fn from(mode: ReadMode) -> hipTextureReadMode {
+        match mode {
+            ReadMode::ElementType => hipTextureReadMode::hipReadModeElementType,
+            ReadMode::NormalizedFloat => hipTextureReadMode::hipReadModeNormalizedFloat,
+        }
+    }
+}
+
+/// A texture object, which kernels sample a resource through, destroyed on
+/// drop. The resource is borrowed for `'a`.
+#[derive(Debug)]
+pub struct TextureObject<'a> {
+    raw: hipTextureObject_t,
+    _resource: PhantomData<&'a ()>,
+}
+
+// Safety: texture objects can be used and destroyed from any thread, and
+// the resource is only borrowed.
+unsafe impl Send for TextureObject<'_> {}
+unsafe impl Sync for TextureObject<'_> {}
+
+impl<'a> TextureObject<'a> {
+    /// A builder for a texture that clamps, samples the nearest texel and
+    /// reads texels as their own type, at unnormalized coordinates.
+    pub fn builder() -> TextureBuilder {
+        TextureBuilder {
+            desc: hipTextureDesc {
+                addressMode: [hipTextureAddressMode::hipAddressModeClamp; 3],
+                filterMode: hipTextureFilterMode::hipFilterModePoint,
+                readMode: hipTextureReadMode::hipReadModeElementType,
+                mipmapFilterMode: hipTextureFilterMode::hipFilterModePoint,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The raw texture object, which remains owned by this `TextureObject`.
+    /// Pass it to kernels that sample the texture.
+    pub fn as_raw(&self) -> hipTextureObject_t {
+        self.raw
+    }
+
+    /// The description of the resource the runtime holds for the texture.
+    pub fn resource_desc(&self) -> Result<hipResourceDesc> {
+        let mut desc = hipResourceDesc::default();
+        unsafe { hipGetTextureObjectResourceDesc(&mut desc, self.raw) }.into_result()?;
+        Ok(desc)
+    }
+}
+
+impl Drop for TextureObject<'_> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipDestroyTextureObject(self.raw) };
+    }
+}
+
+/// A surface object, which kernels read and write an array through,
+/// destroyed on drop. The array is mutably borrowed for `'a`.
+#[derive(Debug)]
+pub struct SurfaceObject<'a> {
+    raw: hipSurfaceObject_t,
+    _array: PhantomData<&'a mut ()>,
+}
+
+// Safety: surface objects can be used and destroyed from any thread, and
+// the array is only borrowed.
+unsafe impl Send for SurfaceObject<'_> {}
+unsafe impl Sync for SurfaceObject<'_> {}
+
+impl<'a> SurfaceObject<'a> {
+    /// Create a surface of `array`, which must have been allocated with
+    /// [`ArrayFlags::SURFACE_LOAD_STORE`].
+    pub fn new<T: Channel>(array: &'a mut HipArray<T>) -> Result<SurfaceObject<'a>> {
+        SurfaceObject::of_array(array.raw, array.device)
+    }
+
+    /// Create a surface of a three-dimensional `array`, which must have
+    /// been allocated with [`ArrayFlags::SURFACE_LOAD_STORE`].
+    pub fn new_3d<T: Channel>(array: &'a mut HipArray3D<T>) -> Result<SurfaceObject<'a>> {
+        SurfaceObject::of_array(array.raw, array.device)
+    }
+
+    /// The raw surface object, which remains owned by this `SurfaceObject`.
+    /// Pass it to kernels that access the surface.
+    pub fn as_raw(&self) -> hipSurfaceObject_t {
+        self.raw
+    }
+
+    /// Create a surface of `array` with `device`, which it's on, current.
+    fn of_array(array: hipArray_t, device: Device) -> Result<SurfaceObject<'a>> {
+        let resource = TextureResource::array(array, device);
+        let mut raw = ptr::null_mut();
+        device
+            .scope(|| unsafe { hipCreateSurfaceObject(&mut raw, &resource.desc) })?
+            .into_result()?;
+        Ok(SurfaceObject {
+            raw,
+            _array: PhantomData,
+        })
+    }
+}
+
+impl Drop for SurfaceObject<'_> {
+    fn drop(&mut self) {
+        let _ = unsafe { hipDestroySurfaceObject(self.raw) };
+    }
+}
+
+/// How a [`TextureObject`] samples its resource, from
+/// [`TextureObject::builder`].
+#[derive(Clone, Copy, Debug)]
+pub struct TextureBuilder {
+    desc: hipTextureDesc,
+}
+
+impl TextureBuilder {
+    /// Use `mode` for coordinates outside the texture in every dimension.
+    pub fn address_mode(self, mode: AddressMode) -> TextureBuilder {
+        self.address_modes([mode; 3])
+    }
+
+    /// Use a mode for coordinates outside the texture in each dimension.
+    pub fn address_modes(mut self, modes: [AddressMode; 3]) -> TextureBuilder {
+        self.desc.addressMode = modes.map(Into::into);
+        self
+    }
+
+    /// Sample between texels with `mode`.
+    pub fn filter_mode(mut self, mode: FilterMode) -> TextureBuilder {
+        self.desc.filterMode = mode.into();
+        self
+    }
+
+    /// Read integer texels with `mode`.
+    pub fn read_mode(mut self, mode: ReadMode) -> TextureBuilder {
+        self.desc.readMode = mode.into();
+        self
+    }
+
+    /// Address the texture with coordinates from 0 to 1 rather than in
+    /// texels.
+    pub fn normalized_coords(mut self, normalized: bool) -> TextureBuilder {
+        self.desc.normalizedCoords = normalized as i32;
+        self
+    }
+
+    /// Convert sRGB texels to linear color when reading.
+    pub fn srgb(mut self, srgb: bool) -> TextureBuilder {
+        self.desc.sRGB = srgb as i32;
+        self
+    }
+
+    /// The color for coordinates outside the texture with
+    /// [`AddressMode::Border`].
+    pub fn border_color(mut self, color: [f32; 4]) -> TextureBuilder {
+        self.desc.borderColor = color;
+        self
+    }
+
+    /// The most anisotropic filtering to use.
+    pub fn max_anisotropy(mut self, max: u32) -> TextureBuilder {
+        self.desc.maxAnisotropy = max;
+        self
+    }
+
+    /// Sample between mipmap levels with `mode`.
+    pub fn mipmap_filter_mode(mut self, mode: FilterMode) -> TextureBuilder {
+        self.desc.mipmapFilterMode = mode.into();
+        self
+    }
+
+    /// Add `bias` to the mipmap level chosen.
+    pub fn mipmap_level_bias(mut self, bias: f32) -> TextureBuilder {
+        self.desc.mipmapLevelBias = bias;
+        self
+    }
+
+    /// Only sample mipmap levels from `min` to `max`.
+    pub fn mipmap_level_clamp(mut self, min: f32, max: f32) -> TextureBuilder {
+        self.desc.minMipmapLevelClamp = min;
+        self.desc.maxMipmapLevelClamp = max;
+        self
+    }
+
+    /// The description HIP will be given.
+    pub fn as_raw(&self) -> &hipTextureDesc {
+        &self.desc
+    }
+
+    /// Create a texture of `resource` on the device it's on, or on the
+    /// current device if that isn't known.
+    pub fn build<'a>(&self, resource: impl Into<TextureResource<'a>>) -> Result<TextureObject<'a>> {
+        let resource = resource.into();
+        let mut raw = ptr::null_mut();
+        on_device(resource.device, || {
+            unsafe { hipCreateTextureObject(&mut raw, &resource.desc, &self.desc, ptr::null()) }
+                .into_result()
+        })?;
+        Ok(TextureObject {
+            raw,
+            _resource: PhantomData,
+        })
+    }
+}
+
+/// Copy between `len` texels at `host` and `extent` texels of `array`,
+/// with `device` current.
+///
+/// # Safety
+/// `host` must be valid for `len` texels, for writes if copying from the
+/// array.
+unsafe fn copy_array<T: Channel>(
+    device: Device,
+    array: hipArray_t,
+    extent: hipExtent,
+    host: *mut T,
+    len: usize,
+    kind: hipMemcpyKind,
+) -> Result<()> {
+    check_len(texels(extent), len)?;
+    if len == 0 {
+        return Ok(());
+    }
+    // The copy covers at least one texel in every dimension.
+    let extent = hipExtent {
+        width: extent.width.max(1),
+        height: extent.height.max(1),
+        depth: extent.depth.max(1),
+    };
+    let host = hipPitchedPtr {
+        ptr: host as *mut c_void,
+        pitch: extent.width * mem::size_of::<T>(),
+        xsize: extent.width,
+        ysize: extent.height,
+    };
+    let mut params = hipMemcpy3DParms {
+        extent,
+        kind,
+        ..Default::default()
+    };
+    if kind == hipMemcpyKind::hipMemcpyHostToDevice {
+        params.srcPtr = host;
+        params.dstArray = array;
+    } else {
+        params.srcArray = array;
+        params.dstPtr = host;
+    }
+    device.scope(|| hipMemcpy3D(&params))?.into_result()
+}
+
+/// Copy `extent` texels of `array` into a new `Vec`, with `device` current.
+fn array_to_vec<T: Channel>(
+    device: Device,
+    array: hipArray_t,
+    extent: hipExtent,
+) -> Result<Vec<T>> {
+    let len = texels(extent);
+    let mut values = Vec::with_capacity(len);
+    unsafe {
+        copy_array(
+            device,
+            array,
+            extent,
+            values.as_mut_ptr(),
+            len,
+            hipMemcpyKind::hipMemcpyDeviceToHost,
+        )?;
+        // Safety: the copy initialised every element.
+        values.set_len(len);
+    }
+    Ok(values)
+}
+
+fn extent(width: usize, height: usize, depth: usize) -> hipExtent {
+    hipExtent {
+        width,
+        height,
+        depth,
+    }
+}
+
+/// The number of texels in `extent`, whose unused dimensions are zero.
+fn texels(extent: hipExtent) -> usize {
+    if extent.width == 0 {
+        return 0;
+    }
+    extent.width * extent.height.max(1) * extent.depth.max(1)
+}
@@ -0,0 +1,271 @@
+//! Peer-to-peer links between devices, and copies across them.
+
+use crate::memory::check_len;
+use crate::{
+    hipDeviceCanAccessPeer, hipDeviceDisablePeerAccess, hipDeviceEnablePeerAccess,
+    hipDeviceGetP2PAttribute, hipDeviceP2PAttr, hipError_t, hipExtGetLinkTypeAndHopCount,
+    hipMemcpyPeer, Device, DeviceSlice, DeviceSliceMut, Result,
+};
+use once_cell::sync::Lazy;
+use std::{cmp::Reverse, collections::HashMap, ffi::c_void, mem, sync::Mutex};
+
+/// The number of live [`PeerAccess`] guards that enabled, or share, access
+/// from each device to each peer, by ordinal.
+static PEER_ACCESS: Lazy<Mutex<HashMap<(i32, i32), usize>>> = Lazy::new(Default::default);
+
+/// The kind of interconnect between two devices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LinkType {
+    /// HyperTransport.
+    HyperTransport,
+    /// Intel QuickPath Interconnect.
+    Qpi,
+    /// PCI Express.
+    Pcie,
+    /// InfiniBand.
+    InfiniBand,
+    /// AMD Infinity Fabric.
+    Xgmi,
+    /// A link type this crate doesn't know about, by its raw value.
+    Other(u32),
+}
+
+impl LinkType {
+    fn from_raw(raw: u32) -> LinkType {
+        // Values of `hsa_amd_link_info_type_t`.
+        match raw {
+            0 => LinkType::HyperTransport,
+            1 => LinkType::Qpi,
+            2 => LinkType::Pcie,
+            3 => LinkType::InfiniBand,
+            4 => LinkType::Xgmi,
+            raw => LinkType::Other(raw),
+        }
+    }
+}
+
+/// How one device can reach another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PeerLink {
+    /// Whether the device can access the peer's memory directly.
+    pub can_access: bool,
+    /// The kind of interconnect between them.
+    pub link_type: LinkType,
+    /// The number of hops between them.
+    pub hop_count: u32,
+    /// The relative performance of the link; lower is better.
+    pub performance_rank: i32,
+    /// Whether atomics on the peer's memory are native.
+    pub native_atomics: bool,
+    /// Whether the peer's HIP arrays can be accessed.
+    pub array_access: bool,
+}
+
+impl PeerLink {
+    /// Query the link from `device` to `peer`, which must be distinct.
+    pub fn between(device: Device, peer: Device) -> Result<PeerLink> {
+        let mut link_type = 0;
+        let mut hop_count = 0;
+        unsafe {
+            hipExtGetLinkTypeAndHopCount(
+                device.ordinal(),
+                peer.ordinal(),
+                &mut link_type,
+                &mut hop_count,
+            )
+        }
+        .into_result()?;
+        let attribute = |attr| -> Result<i32> {
+            let mut value = 0;
+            unsafe { hipDeviceGetP2PAttribute(&mut value, attr, device.ordinal(), peer.ordinal()) }
+                .into_result()?;
+            Ok(value)
+        };
+        Ok(PeerLink {
+            can_access: device.can_access_peer(peer)?,
+            link_type: LinkType::from_raw(link_type),
+            hop_count,
+            performance_rank: attribute(hipDeviceP2PAttr::hipDevP2PAttrPerformanceRank)?,
+            native_atomics: attribute(hipDeviceP2PAttr::hipDevP2PAttrNativeAtomicSupported)? != 0,
+            array_access: attribute(hipDeviceP2PAttr::hipDevP2PAttrHipArrayAccessSupported)? != 0,
+        })
+    }
+}
+
+/// The links between every pair of devices.
+#[derive(Clone, Debug)]
+pub struct Topology {
+    devices: Vec<Device>,
+    // Row-major by source device, with nothing on the diagonal.
+    links: Vec<Option<PeerLink>>,
+}
+
+impl Topology {
+    /// Query the links between all devices.
+    pub fn discover() -> Result<Topology> {
+        let devices = Device::all()?;
+        let mut links = Vec::with_capacity(devices.len() * devices.len());
+        for &device in &devices {
+            for &peer in &devices {
+                links.push(if device == peer {
+                    None
+                } else {
+                    Some(PeerLink::between(device, peer)?)
+                });
+            }
+        }
+        Ok(Topology { devices, links })
+    }
+
+    /// The devices, in ordinal order.
+    pub fn devices(&self) -> &[Device] {
+        &self.devices
+    }
+
+    /// The link from `device` to `peer`. `None` if they're the same device,
+    /// or either wasn't present when the topology was discovered.
+    pub fn link(&self, device: Device, peer: Device) -> Option<&PeerLink> {
+        let count = self.devices.len();
+        let (device, peer) = (device.ordinal() as usize, peer.ordinal() as usize);
+        if device >= count || peer >= count {
+            return None;
+        }
+        self.links[device * count + peer].as_ref()
+    }
+
+    /// The peers `device` can access directly, closest first: by hop count,
+    /// then Infinity Fabric before other links, then performance rank.
+    pub fn nearest_peers(&self, device: Device) -> Vec<Device> {
+        let mut peers: Vec<(Device, &PeerLink)> = self
+            .devices
+            .iter()
+            .filter_map(|&peer| Some((peer, self.link(device, peer)?)))
+            .filter(|(_, link)| link.can_access)
+            .collect();
+        peers.sort_by_key(|(peer, link)| {
+            (
+                link.hop_count,
+                Reverse(link.link_type == LinkType::Xgmi),
+                link.performance_rank,
+                peer.ordinal(),
+            )
+        });
+        peers.into_iter().map(|(peer, _)| peer).collect()
+    }
+}
+
+impl Device {
+    /// Whether this device can access `peer`'s memory directly.
+    pub fn can_access_peer(self, peer: Device) -> Result<bool> {
+        let mut can_access = 0;
+        unsafe { hipDeviceCanAccessPeer(&mut can_access, self.ordinal(), peer.ordinal()) }
+            .into_result()?;
+        Ok(can_access != 0)
+    }
+
+    /// Let this device access `peer`'s memory until the returned guard, and
+    /// every other guard for the same pair of devices, has been dropped. If
+    /// access was enabled by other means, it stays enabled.
+    pub fn enable_peer_access(self, peer: Device) -> Result<PeerAccess> {
+        let key = (self.ordinal(), peer.ordinal());
+        let mut counts = PEER_ACCESS.lock().unwrap_or_else(|e| e.into_inner());
+        let owned = match counts.get_mut(&key) {
+            Some(count) => {
+                *count += 1;
+                true
+            }
+            None => {
+                let result = self.scope(|| {
+                    unsafe { hipDeviceEnablePeerAccess(peer.ordinal(), 0) }.into_result()
+                })?;
+                match result {
+                    Ok(()) => {
+                        counts.insert(key, 1);
+                        true
+                    }
+                    Err(e) if e.kind() == Some(hipError_t::hipErrorPeerAccessAlreadyEnabled) => {
+                        false
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        };
+        Ok(PeerAccess {
+            device: self,
+            peer,
+            owned,
+        })
+    }
+}
+
+/// Access from one device to another's memory. Guards for the same pair of
+/// devices share the access, which is disabled when the last of them is
+/// dropped, unless it was enabled before any of them.
+#[derive(Debug)]
+#[must_use = "peer access is disabled when the guard is dropped"]
+pub struct PeerAccess {
+    device: Device,
+    peer: Device,
+    owned: bool,
+}
+
+impl PeerAccess {
+    /// The device that can access the peer.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The device whose memory can be accessed.
+    pub fn peer(&self) -> Device {
+        self.peer
+    }
+}
+
+impl Drop for PeerAccess {
+    fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
+        let peer = self.peer.ordinal();
+        let key = (self.device.ordinal(), peer);
+        let mut counts = PEER_ACCESS.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(count) = counts.get_mut(&key) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&key);
+                let _ = self
+                    .device
+                    .scope(|| unsafe { hipDeviceDisablePeerAccess(peer) });
+            }
+        }
+    }
+}
+
+/// Copy `src` on `src_device` to `dst` on `dst_device`, without staging
+/// through the host if they're linked. Fails with `hipErrorInvalidValue` if
+/// the lengths differ.
+///
+/// Use [`StreamScope::copy_peer`](crate::StreamScope::copy_peer) to queue the
+/// copy on a stream instead.
+pub fn copy_peer<T: Copy>(
+    src: DeviceSlice<'_, T>,
+    src_device: Device,
+    mut dst: DeviceSliceMut<'_, T>,
+    dst_device: Device,
+) -> Result<()> {
+    check_len(src.len(), dst.len())?;
+    let bytes = src.len() * mem::size_of::<T>();
+    if bytes == 0 {
+        return Ok(());
+    }
+    unsafe {
+        hipMemcpyPeer(
+            dst.as_mut_ptr() as *mut c_void,
+            dst_device.ordinal(),
+            src.as_ptr() as *const c_void,
+            src_device.ordinal(),
+            bytes,
+        )
+    }
+    .into_result()
+}
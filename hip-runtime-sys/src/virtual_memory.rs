@@ -0,0 +1,294 @@
+//! Reserved device address ranges backed by physical memory on demand.
+
+use crate::pool::device_location;
+use crate::{
+    hipError_t, hipMemAccessDesc, hipMemAccessFlags, hipMemAddressFree, hipMemAddressReserve,
+    hipMemAllocationGranularity_flags, hipMemAllocationHandleType, hipMemAllocationProp,
+    hipMemAllocationType, hipMemCreate, hipMemGenericAllocationHandle_t,
+    hipMemGetAllocationGranularity, hipMemMap, hipMemRelease, hipMemSetAccess, hipMemUnmap, Device,
+    DeviceSlice, DeviceSliceMut, HipError, Result,
+};
+use std::{ffi::c_void, mem, ptr};
+
+/// Which allocation granularity to query with
+/// [`VirtualRange::allocation_granularity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// The granularity every size and offset must be a multiple of.
+    Minimum,
+    /// The granularity that gives the best performance.
+    Recommended,
+}
+
+/// Physical device memory that can be mapped into a [`VirtualRange`],
+/// released on drop.
+#[derive(Debug)]
+pub struct PhysicalMemory {
+    handle: hipMemGenericAllocationHandle_t,
+    size: usize,
+}
+
+// Safety: the handle is owned, and can be used from any thread.
+unsafe impl Send for PhysicalMemory {}
+unsafe impl Sync for PhysicalMemory {}
+
+impl PhysicalMemory {
+    /// Allocate at least `size` bytes of physical memory on `device`,
+    /// rounded up to the minimum allocation granularity.
+    pub fn allocate(device: Device, size: usize) -> Result<PhysicalMemory> {
+        PhysicalMemory::with_handle_type(
+            device,
+            size,
+            hipMemAllocationHandleType::hipMemHandleTypeNone,
+        )
+    }
+
+    /// Allocate at least `size` bytes of physical memory on `device` that
+    /// can be exported as `handle_type`.
+    pub fn with_handle_type(
+        device: Device,
+        size: usize,
+        handle_type: hipMemAllocationHandleType,
+    ) -> Result<PhysicalMemory> {
+        let granularity = VirtualRange::allocation_granularity(device, Granularity::Minimum)?;
+        let size = round_up(size, granularity)?;
+        let mut handle = ptr::null_mut();
+        unsafe { hipMemCreate(&mut handle, size, &allocation_prop(device, handle_type), 0) }
+            .into_result()?;
+        Ok(PhysicalMemory { handle, size })
+    }
+
+    /// Take ownership of a raw handle to `size` bytes of physical memory.
+    ///
+    /// # Safety
+    /// `handle` must be valid, hold `size` bytes, and not be released by
+    /// anything else.
+    pub unsafe fn from_raw(handle: hipMemGenericAllocationHandle_t, size: usize) -> PhysicalMemory {
+        PhysicalMemory { handle, size }
+    }
+
+    /// The raw handle, which remains owned by this `PhysicalMemory`.
+    pub fn as_raw(&self) -> hipMemGenericAllocationHandle_t {
+        self.handle
+    }
+
+    /// The size of the memory, in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Drop for PhysicalMemory {
+    fn drop(&mut self) {
+        // Mapped memory stays alive until it's unmapped, but a range only
+        // drops memory after unmapping it anyway.
+        let _ = unsafe { hipMemRelease(self.handle) };
+    }
+}
+
+/// A span of device address space that physical memory is committed to as
+/// it grows, so that its contents never move.
+///
+/// Physical memory is mapped at the end of the committed part of the range,
+/// in multiples of the allocation granularity. The range owns everything
+/// mapped into it, so memory is always unmapped before it's released, and
+/// before the address space itself is freed.
+#[derive(Debug)]
+pub struct VirtualRange {
+    ptr: *mut c_void,
+    reserved: usize,
+    granularity: usize,
+    device: Device,
+    // In address order; each starts where the previous one ends.
+    chunks: Vec<PhysicalMemory>,
+}
+
+// Safety: the range owns its address space and physical memory, which can
+// be used from any thread.
+unsafe impl Send for VirtualRange {}
+unsafe impl Sync for VirtualRange {}
+
+impl VirtualRange {
+    /// The allocation granularity of physical memory on `device`.
+    pub fn allocation_granularity(device: Device, granularity: Granularity) -> Result<usize> {
+        let option = match granularity {
+            Granularity::Minimum => {
+                hipMemAllocationGranularity_flags::hipMemAllocationGranularityMinimum
+            }
+            Granularity::Recommended => {
+                hipMemAllocationGranularity_flags::hipMemAllocationGranularityRecommended
+            }
+        };
+        let prop = allocation_prop(device, hipMemAllocationHandleType::hipMemHandleTypeNone);
+        let mut bytes = 0;
+        unsafe { hipMemGetAllocationGranularity(&mut bytes, &prop, option) }.into_result()?;
+        Ok(bytes)
+    }
+
+    /// Reserve at least `size` bytes of address space for memory on
+    /// `device`, without committing any physical memory.
+    pub fn reserve(device: Device, size: usize) -> Result<VirtualRange> {
+        let granularity = VirtualRange::allocation_granularity(device, Granularity::Minimum)?;
+        let reserved = round_up(size, granularity)?;
+        let mut ptr = ptr::null_mut();
+        unsafe { hipMemAddressReserve(&mut ptr, reserved, 0, ptr::null_mut(), 0) }.into_result()?;
+        Ok(VirtualRange {
+            ptr,
+            reserved,
+            granularity,
+            device,
+            chunks: Vec::new(),
+        })
+    }
+
+    /// The device the physical memory is on.
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    /// The device pointer to the start of the range.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+
+    /// The size of the address space, in bytes.
+    pub fn reserved(&self) -> usize {
+        self.reserved
+    }
+
+    /// The size of the committed prefix of the range, in bytes.
+    pub fn committed(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.size).sum()
+    }
+
+    /// The granularity that commits are rounded up to, in bytes.
+    pub fn granularity(&self) -> usize {
+        self.granularity
+    }
+
+    /// Commit physical memory until at least `size` bytes of the range are
+    /// usable. Existing contents stay where they are. Fails with
+    /// `hipErrorInvalidValue` if `size` is more than was reserved.
+    pub fn grow_to(&mut self, size: usize) -> Result<()> {
+        let committed = self.committed();
+        if size <= committed {
+            return Ok(());
+        }
+        let end = round_up(size, self.granularity)?;
+        if end > self.reserved {
+            return Err(HipError::from(hipError_t::hipErrorInvalidValue));
+        }
+        let memory = PhysicalMemory::allocate(self.device, end - committed)?;
+        self.map(memory)
+    }
+
+    /// Map `memory`, e.g. memory imported from another process, at the end
+    /// of the committed part of the range. Fails with
+    /// `hipErrorInvalidValue` if it doesn't fit in what was reserved.
+    pub fn map(&mut self, memory: PhysicalMemory) -> Result<()> {
+        let committed = self.committed();
+        match committed.checked_add(memory.size) {
+            Some(end) if end <= self.reserved => {}
+            _ => return Err(HipError::from(hipError_t::hipErrorInvalidValue)),
+        }
+        let chunk_ptr = self.at(committed);
+        unsafe { hipMemMap(chunk_ptr, memory.size, 0, memory.handle, 0) }.into_result()?;
+        let access = hipMemAccessDesc {
+            location: device_location(self.device),
+            flags: hipMemAccessFlags::hipMemAccessFlagsProtReadWrite,
+        };
+        if let Err(e) = unsafe { hipMemSetAccess(chunk_ptr, memory.size, &access, 1) }.into_result()
+        {
+            let _ = unsafe { hipMemUnmap(chunk_ptr, memory.size) };
+            return Err(e);
+        }
+        self.chunks.push(memory);
+        Ok(())
+    }
+
+    /// Unmap and release physical memory from the end of the range while at
+    /// least `size` bytes stay committed. Memory is released in whole
+    /// commits, so more than `size` bytes may remain.
+    pub fn shrink_to(&mut self, size: usize) -> Result<()> {
+        let mut committed = self.committed();
+        while let Some(chunk) = self.chunks.last() {
+            if committed - chunk.size < size {
+                break;
+            }
+            committed -= chunk.size;
+            unsafe { hipMemUnmap(self.at(committed), chunk.size) }.into_result()?;
+            self.chunks.pop();
+        }
+        Ok(())
+    }
+
+    /// A view of the committed memory as values of `T`.
+    ///
+    /// # Safety
+    /// The committed memory must hold valid values of `T` before they're
+    /// read.
+    pub unsafe fn as_slice<T: Copy>(&self) -> DeviceSlice<'_, T> {
+        DeviceSlice::from_raw_parts(
+            self.ptr as *mut T,
+            self.committed_len::<T>(),
+            Some(self.device),
+        )
+    }
+
+    /// A mutable view of the committed memory as values of `T`.
+    ///
+    /// # Safety
+    /// The committed memory must hold valid values of `T` before they're
+    /// read.
+    pub unsafe fn as_slice_mut<T: Copy>(&mut self) -> DeviceSliceMut<'_, T> {
+        DeviceSliceMut::from_raw_parts(
+            self.ptr as *mut T,
+            self.committed_len::<T>(),
+            Some(self.device),
+        )
+    }
+
+    /// The device pointer `offset` bytes into the range.
+    fn at(&self, offset: usize) -> *mut c_void {
+        (self.ptr as *mut u8).wrapping_add(offset) as *mut c_void
+    }
+
+    fn committed_len<T>(&self) -> usize {
+        match mem::size_of::<T>() {
+            0 => 0,
+            size => self.committed() / size,
+        }
+    }
+}
+
+impl Drop for VirtualRange {
+    fn drop(&mut self) {
+        // Everything mapped has to be unmapped before the address space can
+        // be freed; if that fails, leak the address space instead.
+        let _ = self.shrink_to(0);
+        if self.chunks.is_empty() {
+            let _ = unsafe { hipMemAddressFree(self.ptr, self.reserved) };
+        }
+    }
+}
+
+fn allocation_prop(
+    device: Device,
+    handle_type: hipMemAllocationHandleType,
+) -> hipMemAllocationProp {
+    // Safety: the struct is plain data, and unused fields must be zero.
+    let mut prop: hipMemAllocationProp = unsafe { mem::MaybeUninit::zeroed().assume_init() };
+    prop.type_ = hipMemAllocationType::hipMemAllocationTypePinned;
+    prop.requestedHandleType = handle_type;
+    prop.location = device_location(device);
+    prop
+}
+
+/// Round `size` up to a multiple of `granularity`, failing with
+/// `hipErrorOutOfMemory` on overflow.
+fn round_up(size: usize, granularity: usize) -> Result<usize> {
+    let chunks = size / granularity + usize::from(size % granularity != 0);
+    chunks
+        .checked_mul(granularity)
+        .ok_or(HipError::from(hipError_t::hipErrorOutOfMemory))
+}
@@ -1,2 +1,8 @@
 #define __HIP_PLATFORM_HCC__
 #include <hip/hip_runtime_api.h>
+#include <hip/library_types.h>
+#include <hip/hip_bfloat16.h>
+// Not every hip_runtime_api.h declares the surface object functions for C;
+// repeating their declarations is harmless where it does.
+hipError_t hipCreateSurfaceObject(hipSurfaceObject_t* pSurfObject, const hipResourceDesc* pResDesc);
+hipError_t hipDestroySurfaceObject(hipSurfaceObject_t surfaceObject);
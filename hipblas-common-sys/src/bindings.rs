@@ -0,0 +1,58 @@
+/* automatically generated by rust-bindgen 0.65.1 */
+
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+#![allow(non_snake_case)]
+
+impl hipblasStatus_t {
+    #[doc = "< Function succeeds"]
+    pub const HIPBLAS_STATUS_SUCCESS: hipblasStatus_t = hipblasStatus_t(0);
+}
+impl hipblasStatus_t {
+    #[doc = "< HIPBLAS library not initialized"]
+    pub const HIPBLAS_STATUS_NOT_INITIALIZED: hipblasStatus_t = hipblasStatus_t(1);
+}
+impl hipblasStatus_t {
+    #[doc = "< resource allocation failed"]
+    pub const HIPBLAS_STATUS_ALLOC_FAILED: hipblasStatus_t = hipblasStatus_t(2);
+}
+impl hipblasStatus_t {
+    #[doc = "< unsupported numerical value was passed to function"]
+    pub const HIPBLAS_STATUS_INVALID_VALUE: hipblasStatus_t = hipblasStatus_t(3);
+}
+impl hipblasStatus_t {
+    #[doc = "< access to GPU memory space failed"]
+    pub const HIPBLAS_STATUS_MAPPING_ERROR: hipblasStatus_t = hipblasStatus_t(4);
+}
+impl hipblasStatus_t {
+    #[doc = "< GPU program failed to execute"]
+    pub const HIPBLAS_STATUS_EXECUTION_FAILED: hipblasStatus_t = hipblasStatus_t(5);
+}
+impl hipblasStatus_t {
+    #[doc = "< an internal HIPBLAS operation failed"]
+    pub const HIPBLAS_STATUS_INTERNAL_ERROR: hipblasStatus_t = hipblasStatus_t(6);
+}
+impl hipblasStatus_t {
+    #[doc = "< function not implemented"]
+    pub const HIPBLAS_STATUS_NOT_SUPPORTED: hipblasStatus_t = hipblasStatus_t(7);
+}
+impl hipblasStatus_t {
+    #[doc = "< architecture mismatch"]
+    pub const HIPBLAS_STATUS_ARCH_MISMATCH: hipblasStatus_t = hipblasStatus_t(8);
+}
+impl hipblasStatus_t {
+    #[doc = "< hipBLAS handle is null pointer"]
+    pub const HIPBLAS_STATUS_HANDLE_IS_NULLPTR: hipblasStatus_t = hipblasStatus_t(9);
+}
+impl hipblasStatus_t {
+    #[doc = "<  unsupported enum value was passed to function"]
+    pub const HIPBLAS_STATUS_INVALID_ENUM: hipblasStatus_t = hipblasStatus_t(10);
+}
+impl hipblasStatus_t {
+    #[doc = "<  back-end returned an unsupported status code"]
+    pub const HIPBLAS_STATUS_UNKNOWN: hipblasStatus_t = hipblasStatus_t(11);
+}
+#[repr(transparent)]
+#[doc = " \\brief hipblas status codes definition"]
+#[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct hipblasStatus_t(pub ::libc::c_uint);
@@ -0,0 +1,156 @@
+//! Errors shared by hipBLAS and hipBLASLt.
+
+use crate::hipblasStatus_t;
+use std::{ffi::CStr, fmt, os::raw::c_char};
+
+/// A `Result` whose error defaults to [`BlasError`].
+pub type Result<T, E = BlasError> = std::result::Result<T, E>;
+
+/// Every [`hipblasStatus_t`] known to these bindings, with its name.
+macro_rules! known_statuses {
+    ($($name:ident),* $(,)?) => {
+        const KNOWN_STATUSES: &[(hipblasStatus_t, &str)] =
+            &[$((hipblasStatus_t::$name, stringify!($name))),*];
+    };
+}
+
+known_statuses!(
+    HIPBLAS_STATUS_SUCCESS,
+    HIPBLAS_STATUS_NOT_INITIALIZED,
+    HIPBLAS_STATUS_ALLOC_FAILED,
+    HIPBLAS_STATUS_INVALID_VALUE,
+    HIPBLAS_STATUS_MAPPING_ERROR,
+    HIPBLAS_STATUS_EXECUTION_FAILED,
+    HIPBLAS_STATUS_INTERNAL_ERROR,
+    HIPBLAS_STATUS_NOT_SUPPORTED,
+    HIPBLAS_STATUS_ARCH_MISMATCH,
+    HIPBLAS_STATUS_HANDLE_IS_NULLPTR,
+    HIPBLAS_STATUS_INVALID_ENUM,
+    HIPBLAS_STATUS_UNKNOWN,
+);
+
+impl hipblasStatus_t {
+    /// Convert a raw status code into a known [`hipblasStatus_t`]. Returns
+    /// `None` for codes these bindings don't know about.
+    pub fn from_code(code: u32) -> Option<hipblasStatus_t> {
+        KNOWN_STATUSES.iter().map(|&(s, _)| s).find(|s| s.0 == code)
+    }
+
+    /// `Ok(())` for [`hipblasStatus_t::HIPBLAS_STATUS_SUCCESS`], otherwise
+    /// the error.
+    pub fn into_result(self) -> Result<()> {
+        match self {
+            hipblasStatus_t::HIPBLAS_STATUS_SUCCESS => Ok(()),
+            s => Err(BlasError::from(s)),
+        }
+    }
+}
+
+/// An error returned by hipBLAS or hipBLASLt.
+///
+/// Both libraries return the same [`hipblasStatus_t`], so errors from
+/// either can be propagated through one [`Result`]. The raw status code is
+/// kept, so codes that aren't known to these bindings are preserved.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlasError {
+    code: u32,
+}
+
+impl BlasError {
+    /// Create an error from a raw status code. Returns `None` for
+    /// `HIPBLAS_STATUS_SUCCESS`.
+    pub fn from_code(code: u32) -> Option<BlasError> {
+        if code == hipblasStatus_t::HIPBLAS_STATUS_SUCCESS.0 {
+            None
+        } else {
+            Some(BlasError { code })
+        }
+    }
+
+    /// The raw status code.
+    pub fn code(self) -> u32 {
+        self.code
+    }
+
+    /// The [`hipblasStatus_t`] for this error, if it's known to these
+    /// bindings.
+    pub fn kind(self) -> Option<hipblasStatus_t> {
+        hipblasStatus_t::from_code(self.code)
+    }
+
+    /// The name of the status, e.g. "HIPBLAS_STATUS_ALLOC_FAILED", according
+    /// to `hipblasStatusToString` if a loaded library exports it.
+    pub fn name(self) -> String {
+        match (self.known_name(), status_to_string()) {
+            (Some(name), Some(to_string)) => {
+                let ptr = unsafe { to_string(hipblasStatus_t(self.code)) };
+                if ptr.is_null() {
+                    name.to_string()
+                } else {
+                    unsafe { CStr::from_ptr(ptr) }
+                        .to_string_lossy()
+                        .into_owned()
+                }
+            }
+            (Some(name), None) => name.to_string(),
+            (None, _) => format!("hipblasStatus_t({})", self.code),
+        }
+    }
+
+    /// The name of the constant of [`hipblasStatus_t`] for this error, if
+    /// it's known to these bindings.
+    fn known_name(self) -> Option<&'static str> {
+        KNOWN_STATUSES
+            .iter()
+            .find(|(s, _)| s.0 == self.code)
+            .map(|&(_, name)| name)
+    }
+}
+
+type StatusToString = unsafe extern "C" fn(hipblasStatus_t) -> *const c_char;
+
+/// `hipblasStatusToString` from whichever library in the process exports it,
+/// whether it was linked or loaded at runtime.
+#[cfg(unix)]
+fn status_to_string() -> Option<StatusToString> {
+    let symbol = b"hipblasStatusToString\0";
+    let ptr = unsafe { libc::dlsym(libc::RTLD_DEFAULT, symbol.as_ptr().cast()) };
+    if ptr.is_null() {
+        None
+    } else {
+        // Safety: a function with this name has this signature.
+        Some(unsafe { std::mem::transmute::<*mut libc::c_void, StatusToString>(ptr) })
+    }
+}
+
+/// Without a way to search every loaded library, only the names known to
+/// these bindings are used.
+#[cfg(not(unix))]
+fn status_to_string() -> Option<StatusToString> {
+    None
+}
+
+impl From<hipblasStatus_t> for BlasError {
+    /// Note that this accepts `HIPBLAS_STATUS_SUCCESS`; prefer
+    /// [`hipblasStatus_t::into_result`].
+    fn from(s: hipblasStatus_t) -> BlasError {
+        BlasError { code: s.0 }
+    }
+}
+
+impl fmt::Debug for BlasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.known_name() {
+            Some(name) => write!(f, "BlasError({name})"),
+            None => write!(f, "BlasError({})", self.code),
+        }
+    }
+}
+
+impl fmt::Display for BlasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hipBLAS error {} ({})", self.name(), self.code)
+    }
+}
+
+impl std::error::Error for BlasError {}
@@ -0,0 +1,13 @@
+//! Types that hipBLAS and hipBLASLt share, from `hipblas-common.h`.
+//!
+//! This crate links nothing, so `hipblas-sys` and `hipblaslt-sys` can both
+//! use it without either depending on the other's library.
+
+// libc's C types are aliases of ones newer than the MSRV, which clippy
+// mistakes for using them directly.
+#[allow(unknown_lints, clippy::incompatible_msrv)]
+mod bindings;
+pub use bindings::*;
+
+mod error;
+pub use error::{BlasError, Result};
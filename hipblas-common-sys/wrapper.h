@@ -0,0 +1 @@
+#include <hipblas-common/hipblas-common.h>
@@ -10,6 +10,14 @@ bindgen "${SCRIPTPATH}"/wrapper.h \
         --raw-line "#![allow(non_camel_case_types)]" \
         --raw-line "#![allow(non_upper_case_globals)]" \
         --raw-line "#![allow(non_snake_case)]" \
+        --raw-line "use hip_runtime_sys::*;" \
+        --raw-line "use hipblas_common_sys::hipblasStatus_t;" \
+        --allowlist-function "hipblas.*" \
+        --allowlist-type "hipblas.*" \
+        --allowlist-var "hipblas.*" \
+        --allowlist-var "HIPBLAS.*" \
+        --no-recursive-allowlist \
+        --blocklist-type "hipblasStatus_t" \
         --rustified-enum "hip.*" \
         --generate-block \
         --ctypes-prefix "::libc" \
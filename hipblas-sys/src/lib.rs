@@ -1,2 +1,42 @@
+// bindgen derives comparisons for structs holding callbacks, and neither it
+// nor the dynamic loading shims are written to satisfy clippy.
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::missing_transmute_annotations,
+    clippy::useless_transmute,
+    clippy::type_complexity,
+    clippy::incompatible_msrv
+)]
+#[cfg(not(feature = "dynamic-loading"))]
 mod bindings;
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
+#[allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::missing_transmute_annotations,
+    clippy::useless_transmute,
+    clippy::type_complexity,
+    clippy::incompatible_msrv
+)]
+#[allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#[cfg(feature = "dynamic-loading")]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 pub use bindings::*;
+// The HIP runtime types in the bindings are shared with hip-runtime-sys
+// rather than regenerated.
+pub use hip_runtime_sys::hipStream_t;
+
+// The status type, and the error built from it, are shared with hipBLAS(Lt).
+pub use hipblas_common_sys::{hipblasStatus_t, BlasError, Result};
+
+#[cfg(feature = "dynamic-loading")]
+mod dynamic;
+#[cfg(feature = "dynamic-loading")]
+pub use dynamic::{
+    available_symbols, check_symbol, is_available, load, missing_symbols, LoadError,
+    DEFAULT_LIBRARY_DIR, LIBRARY_NAMES,
+};
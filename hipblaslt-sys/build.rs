@@ -33,6 +33,112 @@ fn main() {
     println!("cargo:rerun-if-env-changed=HIP_BLASLT_PATH");
     println!("cargo:rerun-if-env-changed=HIPBLASLT_PATH");
 
+    // With dynamic loading, hipblaslt is opened at runtime, so it only needs
+    // to be found to link against it or to regenerate the bindings.
+    if !cfg!(feature = "dynamic-loading") || cfg!(feature = "bindgen") {
+        #[allow(unused_variables)]
+        let (hip_path, hip_blaslt_path) = find_paths();
+
+        if !cfg!(feature = "dynamic-loading") {
+            let hip_blaslt_lib = hip_blaslt_path.join("lib");
+            println!("cargo:rustc-link-search=native={}", hip_blaslt_lib.display());
+            println!("cargo:rustc-link-lib=dylib=hipblaslt");
+        }
+
+        #[cfg(feature = "bindgen")]
+        {
+            // The bindgen::Builder is the main entry point to bindgen, and lets you
+            // build up options for the resulting bindings.
+            println!("cargo:rerun-if-changed=wrapper.h");
+            let bindings = bindgen::Builder::default()
+                .raw_line("#![allow(non_camel_case_types)]")
+                .raw_line("#![allow(non_upper_case_globals)]")
+                .raw_line("#![allow(non_snake_case)]")
+                // HIP runtime types come from hip-runtime-sys, and the status
+                // type shared with hipBLAS(Lt) from hipblas-common-sys.
+                .raw_line("use hip_runtime_sys::*;")
+                .raw_line("use hipblas_common_sys::hipblasStatus_t;")
+                // The input header we would like to generate bindings for.
+                .header("wrapper.h")
+                // Enable C++ mode
+                .clang_arg("-xc++")
+                .clang_arg("-std=c++14")
+                // Define HIP platform for AMD/ROCm
+                .clang_arg("-D__HIP_PLATFORM_AMD__")
+                .clang_arg(format!("-I{}", hip_blaslt_path.join("include").display()))
+                .clang_arg(format!("-I{}", hip_blaslt_path.join("include").join("hipblaslt").display()))
+                .clang_arg(format!("-I{}", hip_path.join("include").display()))
+                // Also include regular hipblas headers as hipblaslt often depends on them
+                .clang_arg({
+                    let hipblas_include = hip_path.join("..").join("hipblas").join("include");
+                    if hipblas_include.exists() {
+                        format!("-I{}", hipblas_include.display())
+                    } else {
+                        // Fallback: try to find hipblas in the same parent directory
+                        format!("-I{}", hip_blaslt_path.join("..").join("hipblas").join("include").display())
+                    }
+                })
+                // Only generate bindings for hipblaslt and the types it shares
+                // with hipblas; anything it refers to from the HIP runtime,
+                // such as hipDataType and hip_bfloat16, is already bound.
+                .allowlist_function("hipblasLt.*")
+                .allowlist_type("_?hipblas.*")
+                .allowlist_var("hipblas.*")
+                .allowlist_var("HIPBLAS.*")
+                .allowlist_recursively(false)
+                .blocklist_type("hipblasStatus_t")
+                .blocklist_type("hipDataType")
+                .blocklist_type("hip_bfloat16")
+                // Block std library bindings
+                .blocklist_type("std.*")
+                .blocklist_function("std.*")
+                .rustified_non_exhaustive_enum("hip.*")
+                .generate_block(false)
+                .size_t_is_usize(true)
+                .ctypes_prefix("::libc")
+                .derive_default(true)
+                .derive_eq(true)
+                .derive_ord(true)
+                .derive_hash(true)
+                // Tell cargo to invalidate the built crate whenever any of the
+                // included header files changed.
+                .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+                // Finish the builder and generate the bindings.
+                .generate()
+                // Unwrap the Result and panic on failure.
+                .expect("Unable to generate bindings");
+            bindings
+                .write_to_file("src/bindings.rs")
+                .expect("Couldn't write bindings!");
+        }
+    }
+
+    #[cfg(feature = "dynamic-loading")]
+    {
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=src/bindings.rs");
+        let out_dir = PathBuf::from(var("OUT_DIR").unwrap());
+        hip_loader_build::generate(
+            "src/bindings.rs",
+            &out_dir.join("bindings.rs"),
+            &[
+                hip_loader_build::Status {
+                    ty: "hipError_t",
+                    not_loaded: "hipErrorSharedObjectInitFailed",
+                    not_found: "hipErrorSharedObjectSymbolNotFound",
+                },
+                hip_loader_build::Status {
+                    ty: "hipblasStatus_t",
+                    not_loaded: "HIPBLAS_STATUS_NOT_INITIALIZED",
+                    not_found: "HIPBLAS_STATUS_NOT_SUPPORTED",
+                },
+            ],
+        );
+    }
+}
+
+/// Find the HIP and hipblaslt installation paths.
+fn find_paths() -> (PathBuf, PathBuf) {
     // Try to get HIP_PATH.
     let hip_path =
         try_get_path_from_var("HIP_PATH").unwrap_or_else(|| PathBuf::from(DEFAULT_HIP_PATH));
@@ -66,7 +172,13 @@ fn main() {
             None => {
                 let pb = hip_path.join("..").join("hipblaslt");
                 println!("cargo:warning={}: Assuming HIP_PATH is related to hipblaslt; checking if '{}' exists", env!("CARGO_PKG_NAME"), pb.display());
-                pb.canonicalize().unwrap()
+                pb.canonicalize().unwrap_or_else(|e| {
+                    panic!(
+                        "{}: Couldn't find hipblaslt at '{}' ({e}); set HIP_BLASLT_PATH to its location",
+                        env!("CARGO_PKG_NAME"),
+                        pb.display()
+                    )
+                })
             }
         };
 
@@ -79,67 +191,5 @@ fn main() {
         hip_blaslt_path.display()
     );
 
-    let hip_blaslt_lib = hip_blaslt_path.join("lib");
-    println!("cargo:rustc-link-search=native={}", hip_blaslt_lib.display());
-    println!("cargo:rustc-link-lib=dylib=hipblaslt");
-
-    #[cfg(feature = "bindgen")]
-    {
-        // The bindgen::Builder is the main entry point to bindgen, and lets you
-        // build up options for the resulting bindings.
-        println!("cargo:rerun-if-changed=wrapper.h");
-        let bindings = bindgen::Builder::default()
-            .raw_line("#![allow(non_camel_case_types)]")
-            .raw_line("#![allow(non_upper_case_globals)]")
-            .raw_line("#![allow(non_snake_case)]")
-            // The input header we would like to generate bindings for.
-            .header("wrapper.h")
-            // Enable C++ mode
-            .clang_arg("-xc++")
-            .clang_arg("-std=c++14")
-            // Define HIP platform for AMD/ROCm
-            .clang_arg("-D__HIP_PLATFORM_AMD__")
-            .clang_arg(format!("-I{}", hip_blaslt_path.join("include").display()))
-            .clang_arg(format!("-I{}", hip_blaslt_path.join("include").join("hipblaslt").display()))
-            .clang_arg(format!("-I{}", hip_path.join("include").display()))
-            // Also include regular hipblas headers as hipblaslt often depends on them
-            .clang_arg({
-                let hipblas_include = hip_path.join("..").join("hipblas").join("include");
-                if hipblas_include.exists() {
-                    format!("-I{}", hipblas_include.display())
-                } else {
-                    // Fallback: try to find hipblas in the same parent directory
-                    format!("-I{}", hip_blaslt_path.join("..").join("hipblas").join("include").display())
-                }
-            })
-            // Only generate bindings for hipblaslt and hip functions/types
-            .allowlist_function("hipblasLt.*")
-            .allowlist_function("hip.*")
-            .allowlist_type("hipblasLt.*")
-            .allowlist_type("hip.*")
-            .allowlist_var("hipblasLt.*")
-            .allowlist_var("hip.*")
-            .allowlist_var("HIP.*")
-            // Block std library bindings
-            .blocklist_type("std.*")
-            .blocklist_function("std.*")
-            .rustified_non_exhaustive_enum("hip.*")
-            .generate_block(false)
-            .size_t_is_usize(true)
-            .ctypes_prefix("::libc")
-            .derive_default(true)
-            .derive_eq(true)
-            .derive_ord(true)
-            .derive_hash(true)
-            // Tell cargo to invalidate the built crate whenever any of the
-            // included header files changed.
-            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-            // Finish the builder and generate the bindings.
-            .generate()
-            // Unwrap the Result and panic on failure.
-            .expect("Unable to generate bindings");
-        bindings
-            .write_to_file("src/bindings.rs")
-            .expect("Couldn't write bindings!");
-    }
-}
\ No newline at end of file
+    (hip_path, hip_blaslt_path)
+}
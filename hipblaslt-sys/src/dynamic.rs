@@ -0,0 +1,81 @@
+//! Runtime loading of the hipBLASLt library.
+//!
+//! With the `dynamic-loading` feature, nothing links against `libhipblaslt`.
+//! Instead, the library is opened the first time any function is called (or
+//! when [`load`] is called) and every function is resolved into a table.
+//! Functions returning [`hipblasStatus_t`](crate::hipblasStatus_t) report a
+//! missing library with `HIPBLAS_STATUS_NOT_INITIALIZED` and a missing symbol
+//! with `HIPBLAS_STATUS_NOT_SUPPORTED`, and those returning
+//! [`hipError_t`](crate::hipError_t) use `hipErrorSharedObjectInitFailed` and
+//! `hipErrorSharedObjectSymbolNotFound`; other functions panic, so check
+//! [`is_available`] or [`check_symbol`] before calling them.
+
+use crate::bindings::Api;
+
+use hip_loader::Loader;
+
+pub use hip_loader::LoadError;
+
+/// The names under which the hipBLASLt library is searched for, in order.
+#[cfg(windows)]
+pub const LIBRARY_NAMES: &[&str] = &["hipblaslt.dll"];
+/// The names under which the hipBLASLt library is searched for, in order.
+#[cfg(not(windows))]
+pub const LIBRARY_NAMES: &[&str] = &["libhipblaslt.so", "libhipblaslt.so.0", "libhipblaslt.so.1"];
+
+/// The last place searched for the hipBLASLt library.
+pub const DEFAULT_LIBRARY_DIR: &str = "/opt/rocm/lib";
+
+// The system loader searches first, then the `lib` directory of each of
+// these variables, then `DEFAULT_LIBRARY_DIR`.
+static LOADER: Loader<Api> = Loader::new(
+    "hipBLASLt",
+    LIBRARY_NAMES,
+    &["HIP_BLASLT_PATH", "HIPBLASLT_PATH", "ROCM_PATH"],
+    DEFAULT_LIBRARY_DIR,
+);
+
+pub(crate) fn api() -> Option<&'static Api> {
+    LOADER.api()
+}
+
+// Only called by functions that don't return a status.
+#[allow(dead_code)]
+#[cold]
+pub(crate) fn unavailable(symbol: &str) -> ! {
+    match check_symbol(symbol) {
+        Err(e) => panic!("{}: {e}", env!("CARGO_PKG_NAME")),
+        Ok(()) => unreachable!(),
+    }
+}
+
+/// Load the hipBLASLt library, if it hasn't been loaded already. This happens
+/// implicitly on the first call to any of its functions, but calling it up
+/// front allows a missing library to be handled gracefully.
+pub fn load() -> Result<(), LoadError> {
+    LOADER.load()
+}
+
+/// Whether the hipBLASLt library could be loaded.
+pub fn is_available() -> bool {
+    load().is_ok()
+}
+
+/// Check that the hipBLASLt library is loaded and exports `symbol`. Functions
+/// added in newer versions may be missing from older installations.
+pub fn check_symbol(symbol: &str) -> Result<(), LoadError> {
+    LOADER.check_symbol(symbol)
+}
+
+/// The names of all bound functions exported by the loaded library. Empty if
+/// the library couldn't be loaded.
+pub fn available_symbols() -> Vec<&'static str> {
+    LOADER.symbols(true)
+}
+
+/// The names of all bound functions that the loaded library doesn't export,
+/// e.g. because they were added in a newer hipBLASLt. Empty if the library
+/// couldn't be loaded.
+pub fn missing_symbols() -> Vec<&'static str> {
+    LOADER.symbols(false)
+}
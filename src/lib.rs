@@ -2,12 +2,38 @@
 pub mod hipblas {
     #[doc(inline)]
     pub use hipblas_sys::*;
+
+    /// Whether hipBLAS can be used. Without the `dynamic-loading` feature,
+    /// hipBLAS is linked and this is always `true`.
+    pub fn available() -> bool {
+        #[cfg(feature = "dynamic-loading")]
+        {
+            hipblas_sys::is_available()
+        }
+        #[cfg(not(feature = "dynamic-loading"))]
+        {
+            true
+        }
+    }
 }
 
 #[cfg(feature = "blaslt")]
 pub mod hipblaslt {
     #[doc(inline)]
     pub use hipblaslt_sys::*;
+
+    /// Whether hipBLASLt can be used. Without the `dynamic-loading` feature,
+    /// hipBLASLt is linked and this is always `true`.
+    pub fn available() -> bool {
+        #[cfg(feature = "dynamic-loading")]
+        {
+            hipblaslt_sys::is_available()
+        }
+        #[cfg(not(feature = "dynamic-loading"))]
+        {
+            true
+        }
+    }
 }
 
 pub mod hiprt {
@@ -0,0 +1,37 @@
+#!/bin/bash
+# Regenerate the sample code objects from kernels.ll with LLVM's llc.
+#
+# The objects are relocatable (no ld.lld is needed), so they can be
+# inspected but not loaded onto a GPU. kernels.bundle is a clang offload
+# bundle of both, laid out as clang-offload-bundler does for HIP.
+
+set -euo pipefail
+cd "$(dirname "$0")"
+
+LLC=${LLC:-llc}
+
+"$LLC" -march=amdgcn -mcpu=gfx90a -mattr=-xnack -filetype=obj kernels.ll -o kernels-gfx90a.o
+"$LLC" -march=amdgcn -mcpu=gfx908 -mattr=+sramecc,-xnack -filetype=obj kernels.ll \
+    -o kernels-gfx908.o
+
+python3 - <<'PY'
+import struct
+
+entries = [
+    ("host-x86_64-unknown-linux-gnu-", b""),
+    ("hipv4-amdgcn-amd-amdhsa--gfx90a:xnack-", open("kernels-gfx90a.o", "rb").read()),
+    ("hipv4-amdgcn-amd-amdhsa--gfx908:sramecc+:xnack-", open("kernels-gfx908.o", "rb").read()),
+]
+header_size = 24 + 8 + sum(24 + len(triple) for triple, _ in entries)
+align = lambda offset: (offset + 4095) // 4096 * 4096
+offset = header_size
+header = b"__CLANG_OFFLOAD_BUNDLE__" + struct.pack("<Q", len(entries))
+body = b""
+for triple, data in entries:
+    if data:
+        offset = align(offset)
+    header += struct.pack("<QQQ", offset, len(data), len(triple)) + triple.encode()
+    body += b"\0" * (offset - header_size - len(body)) + data
+    offset += len(data)
+open("kernels.bundle", "wb").write(header + body)
+PY
@@ -0,0 +1,75 @@
+; Sample kernels for the code-object tests; see generate.sh.
+
+target datalayout = "e-p:64:64-p1:64:64-p2:32:32-p3:32:32-p4:64:64-p5:32:32-p6:32:32-i64:64-v16:16-v24:32-v32:32-v48:64-v96:128-v192:256-v256:256-v512:512-v1024:1024-v2048:2048-n32:64-S32-A5-G1-ni:7"
+target triple = "amdgcn-amd-amdhsa"
+
+%struct.Params = type { float, double }
+
+declare i32 @llvm.amdgcn.workitem.id.x()
+declare i32 @llvm.amdgcn.workgroup.id.x()
+
+define internal i32 @global_id() #1 {
+  %tid = call i32 @llvm.amdgcn.workitem.id.x()
+  %bid = call i32 @llvm.amdgcn.workgroup.id.x()
+  %base = mul i32 %bid, 256
+  %id = add i32 %base, %tid
+  ret i32 %id
+}
+
+; void scale(float* values, float factor, unsigned len)
+define amdgpu_kernel void @scale(float addrspace(1)* %values, float %factor, i32 %len) #0 {
+entry:
+  %id = call i32 @global_id()
+  %in = icmp ult i32 %id, %len
+  br i1 %in, label %body, label %exit
+body:
+  %idx = zext i32 %id to i64
+  %ptr = getelementptr float, float addrspace(1)* %values, i64 %idx
+  %value = load float, float addrspace(1)* %ptr
+  %scaled = fmul float %value, %factor
+  store float %scaled, float addrspace(1)* %ptr
+  br label %exit
+exit:
+  ret void
+}
+
+; void affine(const double* src, double* dst, Params params, char flag)
+define amdgpu_kernel void @affine(double addrspace(1)* %src, double addrspace(1)* %dst, %struct.Params %params, i8 %flag) #0 {
+entry:
+  %lds = alloca [4 x double], addrspace(5)
+  %id = call i32 @global_id()
+  %idx = zext i32 %id to i64
+  %in = getelementptr double, double addrspace(1)* %src, i64 %idx
+  %value = load double, double addrspace(1)* %in
+  %scale32 = extractvalue %struct.Params %params, 0
+  %scale = fpext float %scale32 to double
+  %offset = extractvalue %struct.Params %params, 1
+  %scaled = fmul double %value, %scale
+  %result = fadd double %scaled, %offset
+  %slot = getelementptr [4 x double], [4 x double] addrspace(5)* %lds, i32 0, i32 %id
+  store volatile double %result, double addrspace(5)* %slot
+  %back = load volatile double, double addrspace(5)* %slot
+  %out = getelementptr double, double addrspace(1)* %dst, i64 %idx
+  store double %back, double addrspace(1)* %out
+  ret void
+}
+
+@shared = internal addrspace(3) global [64 x float] undef, align 4
+
+; void reduce(const float* src, float* dst)
+define amdgpu_kernel void @reduce(float addrspace(1)* %src, float addrspace(1)* %dst) #0 {
+entry:
+  %tid = call i32 @llvm.amdgcn.workitem.id.x()
+  %idx = zext i32 %tid to i64
+  %in = getelementptr float, float addrspace(1)* %src, i64 %idx
+  %value = load float, float addrspace(1)* %in
+  %slot = getelementptr [64 x float], [64 x float] addrspace(3)* @shared, i32 0, i32 %tid
+  store float %value, float addrspace(3)* %slot
+  %first = getelementptr [64 x float], [64 x float] addrspace(3)* @shared, i32 0, i32 0
+  %sum = load float, float addrspace(3)* %first
+  store float %sum, float addrspace(1)* %dst
+  ret void
+}
+
+attributes #0 = { "amdgpu-flat-work-group-size"="1,256" }
+attributes #1 = { alwaysinline }
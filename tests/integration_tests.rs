@@ -1,17 +1,1290 @@
-use hip_sys::hiprt::{hipError_t, hipInit};
+use hip_sys::hiprt::{
+    attribute, copy_peer, dim3, hipChannelFormatDesc, hipChannelFormatKind, hipError_t,
+    hipEventDisableTiming, hipEventInterprocess, hipGraphExecUpdateResult, hipInit,
+    hipIpcEventHandle_t, hipMemcpyKind, hipResourceType, hipStreamNonBlocking,
+    hipTextureAddressMode, hipTextureFilterMode, pool_attribute, AddressMode, ArgKind, ArgMismatch,
+    ArrayFlags, ChannelFormat, ChannelKind, CodeObject, CodeObjectError, CoherencyMode,
+    ContextFlags, Device, DeviceBuffer, DeviceRepr, Event, FilterMode, Granularity, Graph,
+    GraphModel, HipArray, HipArray3D, HipError, HostKernel, HostMallocFlags, HostRegisterFlags,
+    HostRegistration, IpcEventHandle, IpcMemHandle, KernelArgBuffer, KernelArgs, KernelMetadata,
+    LaunchConfig, LaunchLimits, ManagedBuffer, MemAccess, MemPool, MemoryAdvice, MemoryAttach,
+    MemoryLocation, MipmappedArray, Module, ModuleLoadError, MultiDeviceLaunch,
+    MultiDeviceLaunchFlags, NodeModel, OccupancyLimiter, OccupancyModel, OffloadBundle,
+    PhysicalMemory, PinnedHostBuffer, PrimaryContext, ReadMode, Stream, SurfaceObject, TargetId,
+    TextureObject, TextureResource, Topology, VirtualRange,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    ptr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    task::{Context, Poll, Wake},
+    thread::{self, Thread},
+};
+
+/// Return early from a test that needs the HIP runtime if it can't be loaded,
+/// saying so on stderr. The test harness only captures the print macros, so
+/// the message shows even though the test passes.
+macro_rules! require_hip {
+    () => {
+        #[cfg(feature = "dynamic-loading")]
+        if !hip_sys::hiprt::is_available() {
+            use std::io::Write;
+            fn here() {}
+            fn name_of<T>(_: T) -> &'static str {
+                std::any::type_name::<T>()
+            }
+            let test = name_of(here).trim_end_matches("::here");
+            let message = format!("skipping {test}: the HIP runtime isn't available");
+            let _ = writeln!(std::io::stderr(), "{message}");
+            return;
+        }
+    };
+}
 
 #[test]
 fn test_hip_init() {
+    // Without a HIP installation there's nothing to initialise; see
+    // `dynamic_tests` for that case.
+    require_hip!();
+
     let error = unsafe { hipInit(0) };
     assert_eq!(error, hipError_t::hipSuccess);
 }
 
+#[test]
+fn test_devices() {
+    require_hip!();
+
+    let devices = Device::all().unwrap();
+    assert_eq!(devices.len(), Device::count().unwrap());
+    for device in devices {
+        assert_eq!(Device::get(device.ordinal()), Ok(device));
+        let name = device.name().unwrap();
+        assert!(!name.is_empty());
+        assert!(!name.contains('\0'));
+        assert!(device.total_memory().unwrap() > 0);
+        assert!(device.gcn_arch_name().unwrap().starts_with("gfx"));
+        assert!(device.attribute::<attribute::WarpSize>().unwrap() > 0);
+    }
+    assert!(Device::get(-1).is_err());
+}
+
+#[test]
+fn test_device_buffer() {
+    require_hip!();
+
+    let values: Vec<u32> = (0..1024).collect();
+    let mut buffer = DeviceBuffer::from_slice(&values).unwrap();
+    assert_eq!(buffer.len(), values.len());
+    assert_eq!(buffer.to_vec().unwrap(), values);
+
+    buffer
+        .slice_mut(16..32)
+        .unwrap()
+        .copy_from_host(&[7; 16])
+        .unwrap();
+    let mut host = [0; 20];
+    buffer
+        .slice(14..34)
+        .unwrap()
+        .copy_to_host(&mut host)
+        .unwrap();
+    assert_eq!(&host[..2], &[14, 15]);
+    assert_eq!(&host[2..18], &[7; 16]);
+    assert_eq!(&host[18..], &[32, 33]);
+
+    let zeroed = unsafe { DeviceBuffer::<f32>::zeroed(100) }.unwrap();
+    assert_eq!(zeroed.to_vec().unwrap(), vec![0.0; 100]);
+}
+
+#[test]
+fn test_device_buffer_checks_sizes() {
+    // None of these reach the HIP runtime.
+    let invalid = Err(HipError::from(hipError_t::hipErrorInvalidValue));
+
+    let overflow = unsafe { DeviceBuffer::<u64>::uninitialized(usize::MAX / 4) };
+    assert_eq!(
+        overflow.unwrap_err(),
+        HipError::from(hipError_t::hipErrorOutOfMemory)
+    );
+
+    let mut empty = DeviceBuffer::<u32>::from_slice(&[]).unwrap();
+    assert!(empty.is_empty());
+    assert!(empty.as_ptr().is_null());
+    assert_eq!(empty.device(), None);
+    assert_eq!(empty.to_vec(), Ok(vec![]));
+    assert_eq!(empty.copy_from_host(&[1]), invalid);
+    assert_eq!(empty.copy_to_host(&mut [0]), invalid);
+    assert!(empty.slice(0..1).is_err());
+    assert!(empty.slice(..=0).is_err());
+    assert!(empty.slice(0..0).unwrap().is_empty());
+}
+
+#[test]
+fn test_stream_scope() {
+    require_hip!();
+
+    let stream = Stream::with_flags(hipStreamNonBlocking).unwrap();
+    let start = Event::new().unwrap();
+    let stop = Event::new().unwrap();
+    let host: Vec<u32> = (0..4096).collect();
+    let mut result = vec![0; host.len()];
+    let mut buffer = unsafe { DeviceBuffer::<u32>::uninitialized(host.len()) }.unwrap();
+    let mut copy = unsafe { DeviceBuffer::<u32>::uninitialized(host.len()) }.unwrap();
+
+    start.record(&stream).unwrap();
+    stream
+        .scope(|s| s.copy_from_host(buffer.as_slice_mut(), &host))
+        .unwrap()
+        .unwrap();
+    stream
+        .scope(|s| s.copy_device(buffer.as_slice(), copy.as_slice_mut()))
+        .unwrap()
+        .unwrap();
+    stream
+        .scope(|s| s.copy_to_host(copy.as_slice(), &mut result))
+        .unwrap()
+        .unwrap();
+    stop.record(&stream).unwrap();
+    stop.synchronize().unwrap();
+
+    assert_eq!(result, host);
+    assert!(stream.query().unwrap());
+    assert!(stop.query().unwrap());
+    stop.elapsed_since(&start).unwrap();
+
+    let scoped = stream.scope(|s| s.copy_from_host(buffer.as_slice_mut(), &host[1..]));
+    assert_eq!(
+        scoped.unwrap(),
+        Err(HipError::from(hipError_t::hipErrorInvalidValue))
+    );
+}
+
+#[test]
+fn test_stream_host_callbacks() {
+    require_hip!();
+
+    let stream = Stream::new().unwrap();
+    let (sender, receiver) = mpsc::channel();
+    let callback_sender = sender.clone();
+    stream
+        .launch_host_func(move || sender.send(1).unwrap())
+        .unwrap();
+    stream
+        .launch_host_func(|| panic!("caught by the trampoline"))
+        .unwrap();
+    stream
+        .add_callback(move |status| {
+            status.unwrap();
+            callback_sender.send(2).unwrap();
+        })
+        .unwrap();
+    stream.synchronize().unwrap();
+
+    assert_eq!(receiver.recv().unwrap(), 1);
+    assert_eq!(receiver.recv().unwrap(), 2);
+}
+
+/// Poll a future to completion on this thread, without an async runtime.
+fn block_on<F: Future>(mut future: F) -> F::Output {
+    struct Unpark(Thread);
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(Unpark(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    // Safety: the future is shadowed, so it's never moved again.
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn test_completion_futures() {
+    require_hip!();
+
+    let stream = Stream::with_flags(hipStreamNonBlocking).unwrap();
+    let host = vec![3u8; 1 << 20];
+    let mut buffer = unsafe { DeviceBuffer::<u8>::uninitialized(host.len()) }.unwrap();
+    let event = Event::new().unwrap();
+    let uploaded = stream.scope(|s| {
+        s.copy_from_host(buffer.as_slice_mut(), &host)?;
+        event.record(s.stream())?;
+        block_on(event.wait_async())?;
+        block_on(s.stream().completion())
+    });
+    uploaded.unwrap().unwrap();
+    assert!(event.query().unwrap());
+    assert_eq!(buffer.to_vec().unwrap(), host);
+}
+
+#[test]
+fn test_pinned_host_memory() {
+    require_hip!();
+
+    let flags = HostMallocFlags::MAPPED | HostMallocFlags::PORTABLE;
+    let mut pinned = PinnedHostBuffer::from_slice(&[5u16; 4096], flags).unwrap();
+    assert_eq!(pinned.flags(), flags);
+    assert!(!pinned.device_ptr().unwrap().is_null());
+    pinned[0] = 6;
+
+    let mut buffer = unsafe { DeviceBuffer::<u16>::uninitialized(pinned.len()) }.unwrap();
+    let stream = Stream::new().unwrap();
+    stream
+        .scope(|s| s.copy_from_host(buffer.as_slice_mut(), &pinned))
+        .unwrap()
+        .unwrap();
+
+    let mut values = vec![0u16; pinned.len()];
+    let mut registered = HostRegistration::new(&mut values, HostRegisterFlags::empty()).unwrap();
+    stream
+        .scope(|s| s.copy_to_host(buffer.as_slice(), &mut registered))
+        .unwrap()
+        .unwrap();
+    drop(registered);
+    assert_eq!(values, &pinned[..]);
+
+    let empty = PinnedHostBuffer::<u64>::from_slice(&[], HostMallocFlags::empty()).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_managed_memory() {
+    require_hip!();
+
+    let device = Device::current().unwrap();
+    if !device.attribute::<attribute::ManagedMemory>().unwrap() {
+        return;
+    }
+
+    let stream = Stream::new().unwrap();
+    let mut managed = ManagedBuffer::from_slice(&[1.5f64; 1024], MemoryAttach::Global).unwrap();
+    managed.advise(MemoryAdvice::SetReadMostly, device).unwrap();
+    assert!(managed.read_mostly().unwrap());
+    managed
+        .advise(MemoryAdvice::SetPreferredLocation, MemoryLocation::Host)
+        .unwrap();
+    assert_eq!(
+        managed.preferred_location().unwrap(),
+        Some(MemoryLocation::Host)
+    );
+    let attributes = managed.range_attributes().unwrap();
+    assert!(attributes.read_mostly);
+    assert_eq!(attributes.preferred_location, Some(MemoryLocation::Host));
+
+    managed.prefetch_to(device, &stream).unwrap();
+    let mut copy = unsafe { DeviceBuffer::<f64>::uninitialized(managed.len()) }.unwrap();
+    let mut attached = managed.attach_to(&stream).unwrap();
+    stream
+        .scope(|s| s.copy_device(attached.as_slice(), copy.as_slice_mut()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(copy.to_vec().unwrap(), attached.host_slice().unwrap());
+
+    attached.host_slice_mut().unwrap()[0] = 2.5;
+    drop(attached);
+    assert_eq!(
+        managed.as_slice().slice(..1).unwrap().to_vec().unwrap(),
+        [2.5]
+    );
+}
+
+#[test]
+fn test_empty_managed_buffer() {
+    // Nothing is allocated, so this doesn't need a device.
+    let empty = ManagedBuffer::<f32>::from_slice(&[], MemoryAttach::Global).unwrap();
+    empty
+        .advise(MemoryAdvice::SetReadMostly, MemoryLocation::Host)
+        .unwrap();
+    assert!(!empty.read_mostly().unwrap());
+    assert_eq!(empty.preferred_location().unwrap(), None);
+    assert_eq!(empty.coherency_mode().unwrap(), CoherencyMode::FineGrain);
+    assert_eq!(empty.accessed_by().unwrap(), []);
+    let attributes = empty.range_attributes().unwrap();
+    assert_eq!(attributes.last_prefetch_location, None);
+    assert_eq!(attributes.coherency_mode, CoherencyMode::FineGrain);
+}
+
+#[test]
+fn test_mem_pool() {
+    require_hip!();
+
+    let device = Device::current().unwrap();
+    if !device
+        .attribute::<attribute::MemoryPoolsSupported>()
+        .unwrap()
+    {
+        return;
+    }
+
+    let pool = MemPool::new(device).unwrap();
+    pool.set_attribute::<pool_attribute::ReleaseThreshold>(u64::MAX)
+        .unwrap();
+    assert_eq!(
+        pool.attribute::<pool_attribute::ReleaseThreshold>()
+            .unwrap(),
+        u64::MAX
+    );
+    pool.set_attribute::<pool_attribute::ReuseAllowOpportunistic>(false)
+        .unwrap();
+    assert!(!pool
+        .attribute::<pool_attribute::ReuseAllowOpportunistic>()
+        .unwrap());
+    assert_eq!(pool.access(device).unwrap(), MemAccess::ReadWrite);
+
+    let stream = Stream::new().unwrap();
+    let host = vec![9i32; 1 << 16];
+    let mut result = vec![0; host.len()];
+    for _ in 0..4 {
+        let mut buffer = unsafe { pool.alloc::<i32>(host.len(), &stream) }.unwrap();
+        stream
+            .scope(|s| s.copy_from_host(buffer.as_slice_mut(), &host))
+            .unwrap()
+            .unwrap();
+        stream
+            .scope(|s| s.copy_to_host(buffer.as_slice(), &mut result))
+            .unwrap()
+            .unwrap();
+        assert!(pool.attribute::<pool_attribute::UsedMemCurrent>().unwrap() > 0);
+    }
+    stream.synchronize().unwrap();
+    assert_eq!(result, host);
+    assert_eq!(
+        pool.attribute::<pool_attribute::UsedMemCurrent>().unwrap(),
+        0
+    );
+    assert!(pool.attribute::<pool_attribute::ReservedMemHigh>().unwrap() > 0);
+    pool.set_attribute::<pool_attribute::ReservedMemHigh>(0)
+        .unwrap();
+    pool.trim_to(0).unwrap();
+
+    MemPool::default_for(device).unwrap();
+}
+
+#[test]
+fn test_virtual_range() {
+    require_hip!();
+
+    let device = Device::current().unwrap();
+    if !device
+        .attribute::<attribute::VirtualMemoryManagementSupported>()
+        .unwrap()
+    {
+        return;
+    }
+
+    let granularity = VirtualRange::allocation_granularity(device, Granularity::Minimum).unwrap();
+    assert!(granularity > 0);
+    let mut range = VirtualRange::reserve(device, 16 * granularity).unwrap();
+    assert_eq!(range.reserved(), 16 * granularity);
+    assert_eq!(range.committed(), 0);
+
+    range.grow_to(1).unwrap();
+    assert_eq!(range.committed(), granularity);
+    let start = range.as_ptr();
+    let values = vec![7u8; granularity];
+    unsafe { range.as_slice_mut::<u8>() }
+        .copy_from_host(&values)
+        .unwrap();
+
+    range.grow_to(3 * granularity).unwrap();
+    assert_eq!(range.committed(), 3 * granularity);
+    assert_eq!(range.as_ptr(), start);
+    let committed = unsafe { range.as_slice::<u8>() };
+    assert_eq!(committed.len(), 3 * granularity);
+    assert_eq!(
+        committed.slice(..granularity).unwrap().to_vec().unwrap(),
+        values
+    );
+
+    assert!(range.grow_to(17 * granularity).is_err());
+    range.shrink_to(granularity).unwrap();
+    assert_eq!(range.committed(), granularity);
+    range.shrink_to(0).unwrap();
+    assert_eq!(range.committed(), 0);
+}
+
+#[test]
+fn test_ipc_handles() {
+    require_hip!();
+
+    let buffer = DeviceBuffer::from_slice(&[1u32, 2, 3]).unwrap();
+    let handle = buffer.ipc_handle().unwrap();
+    assert_eq!(IpcMemHandle::from_bytes(*handle.as_bytes()), handle);
+
+    let event = Event::with_flags(hipEventInterprocess | hipEventDisableTiming).unwrap();
+    event.ipc_handle().unwrap();
+
+    let device = Device::current().unwrap();
+    #[cfg(unix)]
+    if device
+        .attribute::<attribute::VirtualMemoryManagementSupported>()
+        .unwrap()
+    {
+        use std::os::unix::io::AsRawFd;
+
+        let memory = PhysicalMemory::shareable(device, 1).unwrap();
+        let fd = memory.export_fd().unwrap();
+        assert!(fd.as_raw_fd() >= 0);
+        let mut range = VirtualRange::reserve(device, 2 * memory.size()).unwrap();
+        range.map(memory).unwrap();
+        assert_eq!(range.committed(), range.granularity());
+    }
+}
+
+#[test]
+fn test_ipc_handle_bytes() {
+    let mut bytes = [0; 64];
+    bytes[0] = 0xab;
+    let handle = IpcEventHandle::from_bytes(bytes);
+    assert_eq!(handle.as_bytes(), &bytes);
+    assert!(format!("{:?}", handle).starts_with("IpcEventHandle(ab00"));
+
+    let raw = hipIpcEventHandle_t::from(handle);
+    assert_eq!(raw.reserved[0] as u8, 0xab);
+    assert_eq!(IpcEventHandle::from(raw), handle);
+}
+
+#[test]
+fn test_topology() {
+    require_hip!();
+
+    let topology = Topology::discover().unwrap();
+    assert_eq!(topology.devices().len(), Device::count().unwrap());
+    let device = topology.devices()[0];
+    assert!(topology.link(device, device).is_none());
+    let peers = topology.nearest_peers(device);
+    assert!(!peers.contains(&device));
+    let peer = match peers.first() {
+        Some(&peer) => peer,
+        None => return,
+    };
+    assert!(topology.link(device, peer).unwrap().can_access);
+
+    let first = device.enable_peer_access(peer).unwrap();
+    let _access = device.enable_peer_access(peer).unwrap();
+    // The second guard keeps access enabled.
+    drop(first);
+    let src = DeviceBuffer::from_slice(&[1u32, 2, 3]).unwrap();
+    peer.set_current().unwrap();
+    let mut dst = unsafe { DeviceBuffer::<u32>::zeroed(3) }.unwrap();
+    let mut dst_async = unsafe { DeviceBuffer::<u32>::zeroed(3) }.unwrap();
+    device.set_current().unwrap();
+    copy_peer(src.as_slice(), device, dst.as_slice_mut(), peer).unwrap();
+    let stream = Stream::new().unwrap();
+    stream
+        .scope(|s| s.copy_peer(src.as_slice(), device, dst_async.as_slice_mut(), peer))
+        .unwrap()
+        .unwrap();
+    assert_eq!(dst.to_vec().unwrap(), [1, 2, 3]);
+    assert_eq!(dst_async.to_vec().unwrap(), [1, 2, 3]);
+}
+
+#[test]
+fn test_graphs() {
+    require_hip!();
+
+    let stream = Stream::new().unwrap();
+    let ones = vec![1u32; 256];
+    let twos = vec![2u32; 256];
+    let mut buffer = unsafe { DeviceBuffer::<u32>::uninitialized(256) }.unwrap();
+    let launches = AtomicUsize::new(0);
+    {
+        let mut graph = Graph::new().unwrap();
+        let upload = graph
+            .add_copy_from_host(buffer.as_slice_mut(), &ones, &[])
+            .unwrap();
+        let count = graph
+            .add_host(
+                || {
+                    launches.fetch_add(1, Ordering::SeqCst);
+                },
+                &[upload.into()],
+            )
+            .unwrap();
+        graph.add_empty(&[upload.into(), count.into()]).unwrap();
+        let mut exec = graph.instantiate().unwrap();
+        drop(graph);
+        exec.launch(&stream).unwrap();
+        exec.launch(&stream).unwrap();
+        exec.synchronize().unwrap();
+        assert_eq!(launches.load(Ordering::SeqCst), 2);
+    }
+    assert_eq!(buffer.to_vec().unwrap(), ones);
+
+    // Captured work only runs when the graph is launched.
+    let graph = stream
+        .capture(|s| {
+            assert!(s.stream().is_capturing().unwrap());
+            assert!(s.stream().launch_host_func(|| ()).is_err());
+            s.copy_from_host(buffer.as_slice_mut(), &twos)
+        })
+        .unwrap();
+    assert!(!stream.is_capturing().unwrap());
+    let mut exec = graph.instantiate().unwrap();
+    drop(graph);
+    exec.launch(&stream).unwrap();
+    drop(exec);
+    assert_eq!(buffer.to_vec().unwrap(), twos);
+
+    let mut graph = Graph::new().unwrap();
+    graph
+        .add_copy_from_host(buffer.as_slice_mut(), &ones, &[])
+        .unwrap();
+    let mut exec = graph.instantiate().unwrap();
+    let mut other = Graph::new().unwrap();
+    other.add_empty(&[]).unwrap();
+    other.add_empty(&[]).unwrap();
+    let error = exec.update(&other).unwrap_err();
+    assert_ne!(
+        error.result,
+        hipGraphExecUpdateResult::hipGraphExecUpdateSuccess
+    );
+    assert_eq!(
+        error.error.kind(),
+        Some(hipError_t::hipErrorGraphExecUpdateFailure)
+    );
+}
+
+#[test]
+fn test_graph_model() {
+    require_hip!();
+
+    let host = vec![0u8; 64];
+    let mut buffer = unsafe { DeviceBuffer::<u8>::uninitialized(64) }.unwrap();
+    let mut child = Graph::new().unwrap();
+    child.add_empty(&[]).unwrap();
+    let mut graph = Graph::new().unwrap();
+    let upload = graph
+        .add_copy_from_host(buffer.as_slice_mut(), &host, &[])
+        .unwrap();
+    let join = graph.add_child(&child, &[upload.into()]).unwrap();
+    assert_eq!(graph.nodes().unwrap().len(), 2);
+    assert_eq!(graph.root_nodes().unwrap(), [upload.node()]);
+    assert_eq!(graph.edges().unwrap(), [(upload.node(), join.node())]);
+
+    let model = graph.model().unwrap();
+    assert_eq!(model.roots().len(), 1);
+    assert_eq!(model.edges.len(), 1);
+    assert!(model.nodes.contains(&NodeModel::ChildGraph(GraphModel {
+        nodes: vec![NodeModel::Empty],
+        edges: vec![],
+    })));
+}
+
+#[test]
+fn test_graph_model_export() {
+    let model = GraphModel {
+        nodes: vec![
+            NodeModel::Copy {
+                kind: hipMemcpyKind::hipMemcpyHostToDevice,
+                src: 0x1000,
+                dst: 0x2000,
+                extent: [64, 1, 1],
+            },
+            NodeModel::ChildGraph(GraphModel {
+                nodes: vec![NodeModel::Empty],
+                edges: vec![],
+            }),
+        ],
+        edges: vec![(0, 1)],
+    };
+    assert_eq!(model.roots(), [0]);
+    assert_eq!(
+        model.to_dot(),
+        r#"digraph {
+    n0 [label="0: copy hipMemcpyHostToDevice\n0x1000 -> 0x2000\n64x1x1 bytes"];
+    n1 [label="1: child graph\n1 nodes"];
+    subgraph cluster_n1 {
+        label="n1";
+        n1_0 [label="0: empty"];
+    }
+    n1 -> n1_0 [style=dashed];
+    n0 -> n1;
+}
+"#
+    );
+    assert_eq!(
+        model.to_json(),
+        "{\"nodes\":[\
+         {\"id\":0,\"type\":\"copy\",\"kind\":\"hipMemcpyHostToDevice\",\
+         \"src\":\"0x1000\",\"dst\":\"0x2000\",\"extent\":[64,1,1]},\
+         {\"id\":1,\"type\":\"child_graph\",\"graph\":\
+         {\"nodes\":[{\"id\":0,\"type\":\"empty\"}],\"edges\":[]}}],\
+         \"edges\":[[0,1]]}"
+    );
+
+    let other = GraphModel {
+        nodes: vec![NodeModel::Other(8), NodeModel::Other(13)],
+        edges: vec![],
+    };
+    assert_eq!(
+        other.to_json(),
+        "{\"nodes\":[\
+         {\"id\":0,\"type\":\"other\",\"node_type\":\"hipGraphNodeTypeExtSemaphoreSignal\"},\
+         {\"id\":1,\"type\":\"other\",\"node_type\":\"hipGraphNodeType(13)\"}],\
+         \"edges\":[]}"
+    );
+}
+
+#[test]
+fn test_module_loading() {
+    // Images are checked before HIP is involved.
+    let error = Module::from_bytes(b"not a code object").unwrap_err();
+    assert_eq!(error.kind(), Some(hipError_t::hipErrorInvalidImage));
+    let bundle = include_bytes!("data/kernels.bundle");
+    let error = Module::from_bytes(&bundle[..bundle.len() / 2]).unwrap_err();
+    assert_eq!(error.kind(), Some(hipError_t::hipErrorInvalidImage));
+    // The file is read before anything reaches the runtime.
+    match Module::load("/nonexistent/kernels.hsaco").unwrap_err() {
+        ModuleLoadError::Io(e) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+        e => panic!("unexpected error {:?}", e),
+    }
+
+    require_hip!();
+    if Device::count().unwrap() == 0 {
+        return;
+    }
+
+    assert!(Module::load("kernels\0.hsaco").is_err());
+    let limits = LaunchLimits::of(Device::current().unwrap()).unwrap();
+    assert!(limits.max_threads_per_block > 0);
+    assert!(limits.max_block.iter().all(|&dim| dim > 0));
+    assert!(LaunchConfig::new(1, limits.max_threads_per_block)
+        .check(&limits)
+        .is_ok());
+}
+
+#[test]
+fn test_code_object() {
+    let code_object = CodeObject::parse(include_bytes!("data/kernels-gfx90a.o")).unwrap();
+    assert_eq!(code_object.target.to_string(), "gfx90a:xnack-");
+    assert_eq!(code_object.target.processor(), "gfx90a");
+    assert_eq!(code_object.target.feature("xnack"), Some(false));
+    assert_eq!(code_object.target.feature("sramecc"), None);
+    let names: Vec<&str> = code_object
+        .kernels
+        .iter()
+        .map(|k| k.name.as_str())
+        .collect();
+    assert_eq!(names, ["scale", "affine", "reduce"]);
+
+    let scale = code_object.kernel("scale").unwrap();
+    assert_eq!(scale.symbol, "scale.kd");
+    assert_eq!(scale.kernarg_size, 16);
+    assert_eq!(scale.kernarg_align, 8);
+    assert_eq!(scale.lds_size, 0);
+    assert_eq!(scale.wavefront_size, 64);
+    assert_eq!(scale.max_flat_workgroup_size, 256);
+    assert!(scale.sgpr_count > 0 && scale.vgpr_count > 0);
+    let affine = code_object.kernel("affine").unwrap();
+    assert_eq!(affine.kernarg_size, 36);
+    assert!(affine.scratch_size > 0);
+    assert_eq!(code_object.kernel("reduce").unwrap().lds_size, 256);
+    assert!(code_object.kernel("missing").is_none());
+
+    let error = CodeObject::parse(&include_bytes!("data/kernels-gfx90a.o")[..100]).unwrap_err();
+    assert_eq!(error, CodeObjectError::Truncated);
+    assert_eq!(
+        CodeObject::parse(b"not a code object"),
+        Err(CodeObjectError::UnknownFormat)
+    );
+}
+
+#[test]
+fn test_offload_bundle() {
+    let image = include_bytes!("data/kernels.bundle");
+    let bundle = OffloadBundle::parse(image).unwrap();
+    let triples: Vec<&str> = bundle.entries().iter().map(|e| e.triple).collect();
+    assert_eq!(
+        triples,
+        [
+            "host-x86_64-unknown-linux-gnu-",
+            "hipv4-amdgcn-amd-amdhsa--gfx90a:xnack-",
+            "hipv4-amdgcn-amd-amdhsa--gfx908:sramecc+:xnack-",
+        ]
+    );
+    assert!(bundle.entries()[0].target().is_none());
+    let targets: Vec<String> = bundle
+        .targets()
+        .unwrap()
+        .iter()
+        .map(TargetId::to_string)
+        .collect();
+    assert_eq!(targets, ["gfx90a:xnack-", "gfx908:sramecc+:xnack-"]);
+    assert_eq!(
+        bundle.entries()[1].data,
+        &include_bytes!("data/kernels-gfx90a.o")[..]
+    );
+
+    let code_objects = CodeObject::from_image(image).unwrap();
+    assert_eq!(code_objects.len(), 2);
+    assert_eq!(code_objects[1].target.to_string(), "gfx908:sramecc+:xnack-");
+    assert_eq!(code_objects[1].kernels.len(), 3);
+
+    assert_eq!(
+        OffloadBundle::parse(&image[..image.len() - 1]),
+        Err(CodeObjectError::Truncated)
+    );
+    assert_eq!(
+        OffloadBundle::parse(b"CCOB\x01\x00"),
+        Err(CodeObjectError::CompressedBundle)
+    );
+}
+
+#[test]
+fn test_target_id() {
+    let target: TargetId = "gfx90a:xnack-:sramecc+".parse().unwrap();
+    assert_eq!(target.to_string(), "gfx90a:sramecc+:xnack-");
+    let device: TargetId = "gfx90a:sramecc+:xnack-".parse().unwrap();
+    assert!(target.is_compatible_with(&device));
+    assert!("gfx90a"
+        .parse::<TargetId>()
+        .unwrap()
+        .is_compatible_with(&device));
+    assert!(!"gfx90a:xnack+"
+        .parse::<TargetId>()
+        .unwrap()
+        .is_compatible_with(&device));
+    assert!(!"gfx908"
+        .parse::<TargetId>()
+        .unwrap()
+        .is_compatible_with(&device));
+    for invalid in ["", ":xnack-", "gfx90a:xnack", "gfx90a:xnack-:xnack+"] {
+        assert!(invalid.parse::<TargetId>().is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_kernel_arg_checks() {
+    let code_object = CodeObject::parse(include_bytes!("data/kernels-gfx90a.o")).unwrap();
+    let scale = code_object.kernel("scale").unwrap();
+    let args: Vec<_> = scale
+        .explicit_args()
+        .map(|arg| (arg.name.as_deref(), arg.offset, arg.size, arg.kind.clone()))
+        .collect();
+    assert_eq!(
+        args,
+        [
+            (Some("values"), 0, 8, ArgKind::GlobalBuffer),
+            (Some("factor"), 8, 4, ArgKind::ByValue),
+            (Some("len"), 12, 4, ArgKind::ByValue),
+        ]
+    );
+
+    let check = |metadata: &KernelMetadata, args: &dyn Fn(&mut KernelArgBuffer)| {
+        let mut buffer = KernelArgBuffer::new();
+        args(&mut buffer);
+        metadata.check_args(&buffer)
+    };
+    let values = ptr::null_mut::<f32>();
+    assert_eq!(check(scale, &|b| (values, 2.0f32, 3u32).push_to(b)), Ok(()));
+
+    let error = check(scale, &|b| (2.0f32, values, 3u32).push_to(b)).unwrap_err();
+    assert_eq!(error.kernel, "scale");
+    assert_eq!(error.index, Some(0));
+    assert_eq!(error.mismatch, ArgMismatch::Pointer { expected: true });
+    assert_eq!(
+        error.to_string(),
+        "kernel `scale`: argument 0 is a pointer, but a value was supplied"
+    );
+
+    let error = check(scale, &|b| (values, 2.0f64, 3u32).push_to(b)).unwrap_err();
+    assert_eq!(error.index, Some(1));
+    assert_eq!(
+        error.mismatch,
+        ArgMismatch::Size {
+            expected: 4,
+            supplied: 8
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "kernel `scale`: argument 1 is 4 bytes, but 8 were supplied"
+    );
+
+    let error = check(scale, &|b| (values, 2.0f32).push_to(b)).unwrap_err();
+    assert_eq!(error.index, None);
+    assert_eq!(
+        error.mismatch,
+        ArgMismatch::Count {
+            expected: 3,
+            supplied: 2
+        }
+    );
+    assert_eq!(
+        HipError::from(error).kind(),
+        Some(hipError_t::hipErrorInvalidValue)
+    );
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Params {
+        scale: f32,
+        offset: f64,
+    }
+    // Safety: a `#[repr(C)]` struct of primitives.
+    unsafe impl DeviceRepr for Params {}
+    let affine = code_object.kernel("affine").unwrap();
+    let params = Params {
+        scale: 2.0,
+        offset: 1.0,
+    };
+    let src = ptr::null::<f64>();
+    let dst = ptr::null_mut::<f64>();
+    assert_eq!(
+        check(affine, &|b| (src, dst, params, 1u8).push_to(b)),
+        Ok(())
+    );
+
+    // A `char` followed by a `float` has the `float` at offset 4.
+    let mut packed = scale.clone();
+    packed.args[0].kind = ArgKind::ByValue;
+    packed.args[0].size = 1;
+    packed.args[2].offset = 4;
+    packed.args.remove(1);
+    let error = check(&packed, &|b| (1u8, [0u8; 4]).push_to(b)).unwrap_err();
+    assert_eq!(error.index, Some(1));
+    assert_eq!(
+        error.mismatch,
+        ArgMismatch::Offset {
+            expected: 4,
+            supplied: 1
+        }
+    );
+}
+
+#[test]
+fn test_occupancy_model() {
+    let code_object = CodeObject::parse(include_bytes!("data/kernels-gfx90a.o")).unwrap();
+    let model = OccupancyModel::for_target(&code_object.target).unwrap();
+    assert_eq!(model.wavefront_size, 64);
+    assert!(OccupancyModel::for_target(&"gfx1100".parse().unwrap()).is_none());
+
+    // Light on registers, so only the wave limit applies: 8 waves on each of
+    // 4 SIMDs.
+    let scale = code_object.kernel("scale").unwrap();
+    let occupancy = model.occupancy(scale, 256, 0);
+    assert_eq!(occupancy.blocks_per_cu, 8);
+    assert_eq!(occupancy.waves_per_cu, 32);
+    assert_eq!(occupancy.limiter, OccupancyLimiter::Waves);
+    // Blocks of 3 waves leave 2 waves unused.
+    assert_eq!(model.occupancy(scale, 160, 0).waves_per_cu, 30);
+    assert_eq!(
+        model.occupancy(scale, 512, 0).limiter,
+        OccupancyLimiter::BlockSize
+    );
+    assert_eq!(model.occupancy(scale, 0, 0).blocks_per_cu, 0);
+
+    // 256 bytes of static LDS plus 16 KiB of dynamic LDS per block.
+    let reduce = code_object.kernel("reduce").unwrap();
+    let occupancy = model.occupancy(reduce, 64, 16 * 1024);
+    assert_eq!(occupancy.blocks_per_cu, 3);
+    assert_eq!(occupancy.limiter, OccupancyLimiter::Lds);
+    assert_eq!(model.occupancy(reduce, 64, 64 * 1024).blocks_per_cu, 0);
+
+    let mut heavy = scale.clone();
+    heavy.vgpr_count = 100;
+    let occupancy = model.occupancy(&heavy, 256, 0);
+    assert_eq!(occupancy.waves_per_cu, 16);
+    assert_eq!(occupancy.limiter, OccupancyLimiter::Vgprs);
+    heavy.vgpr_count = 8;
+    heavy.sgpr_count = 200;
+    assert_eq!(
+        model.occupancy(&heavy, 64, 0).limiter,
+        OccupancyLimiter::Sgprs
+    );
+
+    // gfx908 has half the vector registers, and accumulation registers in a
+    // file of their own.
+    let gfx908 = OccupancyModel::for_target(&"gfx908:xnack-".parse().unwrap()).unwrap();
+    heavy.sgpr_count = 8;
+    heavy.agpr_count = 128;
+    assert_eq!(gfx908.occupancy(&heavy, 64, 0).waves_per_cu, 8);
+
+    assert_eq!(model.optimal_block_size(scale, 0), Some(256));
+    heavy.max_flat_workgroup_size = 1024;
+    heavy.agpr_count = 0;
+    heavy.vgpr_count = 96;
+    // 5 waves per SIMD, so 20 per compute unit: one block of 16 waves fits,
+    // but two of 10 fill it.
+    assert_eq!(model.occupancy(&heavy, 1024, 0).waves_per_cu, 16);
+    assert_eq!(model.optimal_block_size(&heavy, 0), Some(640));
+    assert_eq!(model.optimal_block_size(reduce, 64 * 1024), None);
+
+    // Register counts come from the code object, so may be anything.
+    heavy.vgpr_count = u32::MAX;
+    heavy.sgpr_count = u32::MAX;
+    assert_eq!(model.occupancy(&heavy, 64, 0).blocks_per_cu, 0);
+
+    // A model without waves or SIMDs fits no blocks, rather than panicking.
+    let empty = OccupancyModel {
+        wavefront_size: 0,
+        ..model
+    };
+    assert_eq!(
+        empty.occupancy(scale, 64, 0).limiter,
+        OccupancyLimiter::BlockSize
+    );
+    assert_eq!(empty.optimal_block_size(scale, 0), None);
+    let empty = OccupancyModel {
+        simds_per_cu: 0,
+        ..model
+    };
+    assert_eq!(
+        empty.occupancy(scale, 64, 0).limiter,
+        OccupancyLimiter::BlockSize
+    );
+}
+
+#[test]
+fn test_device_scope() {
+    require_hip!();
+    let devices = Device::all().unwrap();
+    let (first, last) = match (devices.first(), devices.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return,
+    };
+    first.set_current().unwrap();
+
+    // Wrappers remember the device they were created on.
+    let (buffer, stream, event) = last
+        .scope(|| {
+            assert_eq!(Device::current().unwrap(), last);
+            (
+                DeviceBuffer::from_slice(&[1u32, 2, 3]).unwrap(),
+                Stream::new().unwrap(),
+                Event::new().unwrap(),
+            )
+        })
+        .unwrap();
+    assert_eq!(Device::current().unwrap(), first);
+    assert_eq!(buffer.device(), Some(last));
+    assert_eq!(stream.device(), last);
+    assert_eq!(event.device(), last);
+
+    // ...and make it current when they need it, then switch back.
+    assert_eq!(buffer.to_vec().unwrap(), [1, 2, 3]);
+    event.record(&stream).unwrap();
+    event.synchronize().unwrap();
+    assert_eq!(Device::current().unwrap(), first);
+
+    // The previous device is restored even if the closure panics.
+    let panicked = thread::spawn(move || {
+        first.set_current().unwrap();
+        let result = std::panic::catch_unwind(|| {
+            last.scope(|| panic!("in scope")).unwrap();
+        });
+        (result.is_err(), Device::current().unwrap())
+    })
+    .join()
+    .unwrap();
+    assert_eq!(panicked, (true, first));
+
+    let guard = last.activate().unwrap();
+    assert_eq!(guard.previous(), first);
+    assert_eq!(Device::current().unwrap(), last);
+    guard.restore().unwrap();
+    assert_eq!(Device::current().unwrap(), first);
+}
+
+#[test]
+fn test_contexts() {
+    assert_eq!(ContextFlags::empty().bits(), 0);
+    assert!((ContextFlags::SCHEDULE_SPIN | ContextFlags::MAP_HOST).contains(ContextFlags::MAP_HOST));
+
+    require_hip!();
+    let devices = Device::all().unwrap();
+    let (first, last) = match (devices.first(), devices.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return,
+    };
+    first.set_current().unwrap();
+
+    let primary = PrimaryContext::retain(last).unwrap();
+    assert_eq!(primary.device(), last);
+    let (_, active) = PrimaryContext::state(last).unwrap();
+    assert!(active);
+    {
+        let _guard = primary.push().unwrap();
+        assert_eq!(Device::current().unwrap(), last);
+        let buffer = DeviceBuffer::from_slice(&[1u32, 2, 3, 4]).unwrap();
+        let mut host = [0; 4];
+        buffer.copy_to_host(&mut host).unwrap();
+        assert_eq!(host, [1, 2, 3, 4]);
+    }
+    assert_eq!(Device::current().unwrap(), first);
+
+    // Creating a context leaves the caller's device alone, and guards
+    // nest.
+    let context = hip_sys::hiprt::Context::new(last, ContextFlags::empty()).unwrap();
+    assert_eq!(Device::current().unwrap(), first);
+    let outer = primary.push().unwrap();
+    let inner = context.push().unwrap();
+    assert_eq!(Device::current().unwrap(), last);
+    inner.pop().unwrap();
+    outer.pop().unwrap();
+    assert_eq!(Device::current().unwrap(), first);
+
+    // Popping out of order leaves the stack alone. This leaves the outer
+    // context on this thread's stack, so it comes last.
+    let outer = primary.push().unwrap();
+    let inner = context.push().unwrap();
+    let error = outer.pop().unwrap_err();
+    assert_eq!(error.kind(), Some(hipError_t::hipErrorInvalidContext));
+    inner.pop().unwrap();
+}
+
+#[test]
+fn test_launch_config() {
+    let limits = LaunchLimits {
+        max_threads_per_block: 1024,
+        max_block: [1024, 1024, 64],
+        max_grid: [u32::MAX, 65_535, 65_535],
+        max_shared_mem: 64 * 1024,
+    };
+    let config = LaunchConfig::for_len(1000, 256);
+    assert_eq!(config.grid, dim3 { x: 4, y: 1, z: 1 });
+    assert_eq!(config.block, dim3 { x: 256, y: 1, z: 1 });
+    assert!(config.stream.is_none());
+    assert!(config.check(&limits).is_ok());
+    assert!(LaunchConfig::new((8, 8), (32, 32)).check(&limits).is_ok());
+
+    let invalid = |config: LaunchConfig<'_>| {
+        config.check(&limits).unwrap_err().kind() == Some(hipError_t::hipErrorInvalidConfiguration)
+    };
+    assert!(invalid(LaunchConfig::new(0, 64)));
+    assert!(invalid(LaunchConfig::for_len(0, 64)));
+    assert!(invalid(LaunchConfig::new(1, (1, 1, 65))));
+    assert!(invalid(LaunchConfig::new(1, (64, 32))));
+    assert!(invalid(LaunchConfig::new((1, 65_536), 1)));
+    assert!(invalid(
+        LaunchConfig::new(1, 1).with_shared_mem(64 * 1024 + 1)
+    ));
+    assert!(LaunchConfig::new(1, 1)
+        .with_shared_mem(64 * 1024)
+        .check(&limits)
+        .is_ok());
+}
+
+#[test]
+fn test_multi_device_launch_checks() {
+    let empty = MultiDeviceLaunch::independent().check().unwrap_err();
+    assert_eq!(empty.device, None);
+    assert_eq!(empty.error.kind(), Some(hipError_t::hipErrorInvalidValue));
+    assert_eq!(
+        (MultiDeviceLaunchFlags::NO_PRE_SYNC | MultiDeviceLaunchFlags::NO_POST_SYNC).bits(),
+        3
+    );
+
+    require_hip!();
+    if Device::count().unwrap() == 0 {
+        return;
+    }
+    let device = Device::get(0).unwrap();
+    // Never launched: every launch below fails its checks first.
+    let kernel = unsafe { HostKernel::from_stub(ptr::null()) };
+
+    // Each device needs a stream of its own.
+    let error = MultiDeviceLaunch::cooperative()
+        .add(device, kernel, LaunchConfig::new(1, 64), ())
+        .check()
+        .unwrap_err();
+    assert_eq!(error.device, Some(device));
+    assert_eq!(error.error.kind(), Some(hipError_t::hipErrorInvalidValue));
+    assert!(error.to_string().starts_with("launch on device 0 failed"));
+
+    device.set_current().unwrap();
+    let stream = Stream::new().unwrap();
+    let config = LaunchConfig::new(1, 64).on_stream(&stream);
+    let launch = MultiDeviceLaunch::independent()
+        .add(device, kernel, config, (1u32,))
+        .add(device, kernel, config, (2u32,));
+    assert_eq!(launch.devices().collect::<Vec<_>>(), [device, device]);
+    let error = launch.check().unwrap_err();
+    assert_eq!(error.device, Some(device));
+    assert_eq!(error.error.kind(), Some(hipError_t::hipErrorInvalidDevice));
+
+    // A launch's stream must be on its device.
+    if Device::count().unwrap() < 2 {
+        return;
+    }
+    let other = Device::get(1).unwrap();
+    let error = MultiDeviceLaunch::independent()
+        .add(other, kernel, config, ())
+        .check()
+        .unwrap_err();
+    assert_eq!(error.device, Some(other));
+    assert_eq!(error.error.kind(), Some(hipError_t::hipErrorInvalidDevice));
+}
+
+#[test]
+fn test_kernel_args() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Params {
+        scale: f32,
+        offset: f64,
+    }
+    // Safety: a `#[repr(C)]` struct of primitives.
+    unsafe impl DeviceRepr for Params {}
+
+    let mut buffer = KernelArgBuffer::new();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.push(&1u8), 0);
+    assert_eq!(buffer.push(&2u32), 4);
+    assert_eq!(buffer.push(&3u16), 8);
+    assert_eq!(
+        buffer.push(&Params {
+            scale: 1.0,
+            offset: 2.0
+        }),
+        16
+    );
+    assert_eq!(buffer.push(&[1u8; 3]), 32);
+    assert_eq!(buffer.len(), 35);
+
+    let mut tuple = KernelArgBuffer::new();
+    (1u8, 2u32, 3u16).push_to(&mut tuple);
+    assert_eq!(tuple.len(), 10);
+    let mut empty = KernelArgBuffer::new();
+    ().push_to(&mut empty);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_host_memory_flags() {
+    let mut flags = HostMallocFlags::empty();
+    assert_eq!(flags.bits(), 0);
+    flags |= HostMallocFlags::WRITE_COMBINED;
+    flags |= HostMallocFlags::MAPPED;
+    assert!(flags.contains(HostMallocFlags::MAPPED));
+    assert!(!flags.contains(HostMallocFlags::MAPPED | HostMallocFlags::COHERENT));
+    assert_eq!(
+        flags.bits(),
+        hip_sys::hiprt::hipHostMallocWriteCombined | hip_sys::hiprt::hipHostMallocMapped
+    );
+    assert_eq!(HostRegisterFlags::from_bits(2), HostRegisterFlags::MAPPED);
+}
+
+#[test]
+fn test_hip_error_into_result() {
+    assert_eq!(hipError_t::hipSuccess.into_result(), Ok(()));
+
+    let error = hipError_t::hipErrorOutOfMemory.into_result().unwrap_err();
+    assert_eq!(error.code(), 2);
+    assert_eq!(error.kind(), Some(hipError_t::hipErrorOutOfMemory));
+    assert!(error.to_string().contains("hipErrorOutOfMemory"));
+}
+
+#[test]
+fn test_hip_error_unknown_code() {
+    assert_eq!(HipError::from_code(0), None);
+    assert_eq!(hipError_t::from_code(123_456), None);
+
+    // Codes from newer runtimes must survive the round trip.
+    let error = HipError::from_code(123_456).unwrap();
+    assert_eq!(error.code(), 123_456);
+    assert_eq!(error.kind(), None);
+    assert!(error.to_string().contains("123456"));
+
+    // So must codes the runtime returns through the bindings.
+    let error = hipError_t(123_456).into_result().unwrap_err();
+    assert_eq!(error, HipError::from_code(123_456).unwrap());
+    assert_eq!(format!("{error:?}"), "HipError(123456)");
+
+    // The raw status prints its variant's name where it has one.
+    assert_eq!(
+        format!("{:?}", hipError_t::hipErrorOutOfMemory),
+        "hipErrorOutOfMemory"
+    );
+    assert_eq!(format!("{:?}", hipError_t(123_456)), "hipError_t(123456)");
+}
+
+#[cfg(feature = "dynamic-loading")]
+mod dynamic_tests {
+    use hip_sys::hiprt::{check_symbol, hipError_t, hipInit, load, LoadError};
+
+    #[test]
+    fn test_missing_library_is_reported() {
+        match load() {
+            Ok(()) => assert_eq!(check_symbol("hipInit"), Ok(())),
+            Err(e) => {
+                assert!(matches!(e, LoadError::LibraryNotFound { .. }));
+                let error = unsafe { hipInit(0) };
+                assert_eq!(error, hipError_t::hipErrorSharedObjectInitFailed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        if load().is_ok() {
+            assert_eq!(
+                check_symbol("hipNotARealFunction"),
+                Err(LoadError::MissingSymbol {
+                    library: "HIP runtime",
+                    symbol: "hipNotARealFunction".to_string(),
+                })
+            );
+        }
+    }
+
+    #[cfg(feature = "blas")]
+    #[test]
+    fn test_missing_hipblas_is_reported() {
+        use hip_sys::hipblas::{
+            available, available_symbols, hipblasCreate, hipblasHandle_t, hipblasStatus_t,
+        };
+
+        if !available() {
+            assert!(available_symbols().is_empty());
+            let mut handle: hipblasHandle_t = std::ptr::null_mut();
+            let status = unsafe { hipblasCreate(&mut handle) };
+            assert_eq!(status, hipblasStatus_t::HIPBLAS_STATUS_NOT_INITIALIZED);
+        } else {
+            assert!(available_symbols().contains(&"hipblasGemmEx"));
+        }
+    }
+
+    #[cfg(feature = "blaslt")]
+    #[test]
+    fn test_missing_hipblaslt_is_reported() {
+        use hip_sys::hipblaslt::{available, check_symbol, LoadError};
+
+        match check_symbol("hipblasLtMatmul") {
+            Ok(()) => assert!(available()),
+            Err(e) => {
+                assert!(!available());
+                assert!(matches!(e, LoadError::LibraryNotFound { .. }));
+            }
+        }
+    }
+}
+
 #[cfg(feature = "blas")]
 mod blas_tests {
-    use hip_sys::hipblas::{hipblasCreate, hipblasDestroy, hipblasHandle_t, hipblasStatus_t};
+    use hip_sys::hipblas::{
+        available, hipblasCreate, hipblasDestroy, hipblasHandle_t, hipblasStatus_t,
+    };
 
     #[test]
     fn test_hipblas_create() {
+        if !available() {
+            return;
+        }
+
         let mut handle: hipblasHandle_t = std::ptr::null_mut();
         let status = unsafe { hipblasCreate(&mut handle as *mut hipblasHandle_t) };
         assert_eq!(status, hipblasStatus_t::HIPBLAS_STATUS_SUCCESS);
@@ -20,20 +1293,219 @@ mod blas_tests {
     }
 }
 
+#[cfg(all(feature = "blas", feature = "blaslt"))]
+mod blas_error_tests {
+    use hip_sys::{hipblas, hipblaslt};
+
+    fn both(blas: hipblas::hipblasStatus_t, lt: hipblaslt::hipblasStatus_t) -> hipblas::Result<()> {
+        blas.into_result()?;
+        lt.into_result()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_blas_error_is_shared() {
+        use hipblas::hipblasStatus_t as BlasStatus;
+        use hipblaslt::hipblasStatus_t as LtStatus;
+
+        assert_eq!(
+            both(
+                BlasStatus::HIPBLAS_STATUS_SUCCESS,
+                LtStatus::HIPBLAS_STATUS_SUCCESS
+            ),
+            Ok(())
+        );
+        let error = both(
+            BlasStatus::HIPBLAS_STATUS_SUCCESS,
+            LtStatus::HIPBLAS_STATUS_INVALID_VALUE,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            hipblas::BlasError::from(BlasStatus::HIPBLAS_STATUS_INVALID_VALUE)
+        );
+        assert_eq!(error.kind(), Some(BlasStatus::HIPBLAS_STATUS_INVALID_VALUE));
+    }
+
+    #[test]
+    fn test_blas_error_unknown_code() {
+        assert_eq!(hipblas::BlasError::from_code(0), None);
+        let error = hipblas::BlasError::from_code(4242).unwrap();
+        assert_eq!(error.kind(), None);
+        assert!(error.to_string().contains("4242"));
+
+        // Codes either library returns are kept as they are.
+        let error = hipblaslt::hipblasStatus_t(4242).into_result().unwrap_err();
+        assert_eq!(error.code(), 4242);
+        assert_eq!(format!("{error:?}"), "BlasError(4242)");
+    }
+}
+
 #[cfg(feature = "blaslt")]
 mod blaslt_tests {
-    use hip_sys::hipblaslt::{hipblasLtCreate, hipblasLtDestroy, hipblasLtHandle_t, hipblasStatus_t};
+    use hip_sys::hipblaslt::{
+        available, hipblasLtCreate, hipblasLtDestroy, hipblasLtHandle_t, hipblasStatus_t,
+    };
 
     #[test]
     fn test_hipblaslt_create() {
+        if !available() {
+            return;
+        }
+
         let mut handle: hipblasLtHandle_t = std::ptr::null_mut();
         let status = unsafe { hipblasLtCreate(&mut handle as *mut hipblasLtHandle_t) };
         assert_eq!(status, hipblasStatus_t::HIPBLAS_STATUS_SUCCESS);
-        
+
         // Only destroy if creation was successful
         if !handle.is_null() {
             let status = unsafe { hipblasLtDestroy(handle) };
             assert_eq!(status, hipblasStatus_t::HIPBLAS_STATUS_SUCCESS);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_runtime_types_are_shared() {
+        // These only compile if hipblaslt-sys re-uses hip-runtime-sys' types.
+        let stream: hip_sys::hiprt::hipStream_t = std::ptr::null_mut();
+        let stream: hip_sys::hipblaslt::hipStream_t = stream;
+        let ty: hip_sys::hipblaslt::hipDataType = hip_sys::hiprt::hipDataType::HIP_R_16BF;
+        let one: hip_sys::hipblaslt::hip_bfloat16 = hip_sys::hiprt::hip_bfloat16 { data: 0x3f80 };
+        assert!(stream.is_null());
+        assert_eq!(ty, hip_sys::hipblaslt::hipDataType::HIP_R_16BF);
+        assert_eq!(one.data, 0x3f80);
+    }
+}
+
+#[test]
+fn test_channel_formats() {
+    let rgba = ChannelFormat::of::<[u8; 4]>();
+    assert_eq!(rgba, ChannelFormat::new([8; 4], ChannelKind::Unsigned));
+    assert_eq!((rgba.channels(), rgba.texel_size()), (4, 4));
+    let float2 = ChannelFormat::of::<[f32; 2]>();
+    assert_eq!(float2.bits, [32, 32, 0, 0]);
+    assert_eq!((float2.channels(), float2.texel_size()), (2, 8));
+    assert_eq!(ChannelFormat::of::<i16>().kind, ChannelKind::Signed);
+
+    let raw = float2.to_raw();
+    assert_eq!((raw.x, raw.y, raw.z, raw.w), (32, 32, 0, 0));
+    assert_eq!(ChannelFormat::from_raw(&raw), Some(float2));
+    let none = hipChannelFormatDesc {
+        f: hipChannelFormatKind::hipChannelFormatKindNone,
+        ..raw
+    };
+    assert_eq!(ChannelFormat::from_raw(&none), None);
+    assert_eq!(
+        ChannelFormat::from_raw(&hipChannelFormatDesc { x: -8, ..raw }),
+        None
+    );
+}
+
+#[test]
+fn test_texture_descriptions() {
+    let builder = TextureObject::builder();
+    let desc = builder.as_raw();
+    assert_eq!(
+        desc.addressMode,
+        [hipTextureAddressMode::hipAddressModeClamp; 3]
+    );
+    assert_eq!(desc.filterMode, hipTextureFilterMode::hipFilterModePoint);
+    assert_eq!(desc.normalizedCoords, 0);
+
+    let builder = builder
+        .address_modes([AddressMode::Wrap, AddressMode::Mirror, AddressMode::Border])
+        .filter_mode(FilterMode::Linear)
+        .read_mode(ReadMode::NormalizedFloat)
+        .normalized_coords(true)
+        .border_color([1.0, 0.5, 0.0, 1.0])
+        .mipmap_level_clamp(0.0, 4.0);
+    let desc = builder.as_raw();
+    assert_eq!(
+        desc.addressMode,
+        [
+            hipTextureAddressMode::hipAddressModeWrap,
+            hipTextureAddressMode::hipAddressModeMirror,
+            hipTextureAddressMode::hipAddressModeBorder,
+        ]
+    );
+    assert_eq!(desc.filterMode, hipTextureFilterMode::hipFilterModeLinear);
+    assert_eq!(desc.normalizedCoords, 1);
+    assert_eq!(desc.borderColor, [1.0, 0.5, 0.0, 1.0]);
+    assert_eq!(
+        (desc.minMipmapLevelClamp, desc.maxMipmapLevelClamp),
+        (0.0, 4.0)
+    );
+
+    // Pitched resources are checked against the memory they borrow. None of
+    // this reaches the HIP runtime.
+    let empty = DeviceBuffer::<[u8; 4]>::from_slice(&[]).unwrap();
+    let resource = TextureResource::pitch_2d(empty.as_slice(), 0, 0, 16).unwrap();
+    assert_eq!(
+        resource.as_raw().resType,
+        hipResourceType::hipResourceTypePitch2D
+    );
+    // Nothing was allocated, so there's no device to create textures on.
+    assert_eq!(resource.device(), None);
+    let invalid = |width, height, pitch| {
+        TextureResource::pitch_2d(empty.as_slice(), width, height, pitch)
+            .unwrap_err()
+            .kind()
+            == Some(hipError_t::hipErrorInvalidValue)
+    };
+    assert!(invalid(1, 1, 16));
+    assert!(invalid(0, 0, 6));
+    assert!(invalid(8, 0, 16));
+    let linear = TextureResource::from(empty.as_slice());
+    assert_eq!(
+        linear.as_raw().resType,
+        hipResourceType::hipResourceTypeLinear
+    );
+}
+
+#[test]
+fn test_arrays_and_textures() {
+    require_hip!();
+    if Device::count().unwrap() == 0 {
+        return;
+    }
+
+    let texels: Vec<[u8; 4]> = (0..12u8).map(|i| [i, i + 1, i + 2, 255]).collect();
+    let mut image = HipArray::<[u8; 4]>::new(4, 3, ArrayFlags::empty()).unwrap();
+    assert_eq!((image.width(), image.height(), image.len()), (4, 3, 12));
+    image.copy_from_host(&texels).unwrap();
+    assert_eq!(image.to_vec().unwrap(), texels);
+    assert!(image.copy_from_host(&texels[1..]).is_err());
+
+    let volume_texels: Vec<f32> = (0..24).map(|i| i as f32).collect();
+    let mut volume = HipArray3D::<f32>::new(4, 3, 2, ArrayFlags::empty()).unwrap();
+    assert_eq!(volume.len(), 24);
+    volume.copy_from_host(&volume_texels).unwrap();
+    assert_eq!(volume.to_vec().unwrap(), volume_texels);
+
+    let mut mipmap = MipmappedArray::<u16>::new(8, 8, 0, 3, ArrayFlags::empty()).unwrap();
+    let level = mipmap.level_extent(2).unwrap();
+    assert_eq!((level.width, level.height, level.depth), (2, 2, 0));
+    assert!(mipmap.level_extent(3).is_none());
+    mipmap.copy_level_from_host(2, &[1, 2, 3, 4]).unwrap();
+    let mut back = [0; 4];
+    mipmap.copy_level_to_host(2, &mut back).unwrap();
+    assert_eq!(back, [1, 2, 3, 4]);
+
+    assert_eq!(TextureResource::from(&image).device(), Some(image.device()));
+    let texture = TextureObject::builder()
+        .filter_mode(FilterMode::Point)
+        .build(&image)
+        .unwrap();
+    assert_eq!(
+        texture.resource_desc().unwrap().resType,
+        hipResourceType::hipResourceTypeArray
+    );
+    drop(texture);
+
+    let mut canvas = HipArray::<f32>::new(8, 8, ArrayFlags::SURFACE_LOAD_STORE).unwrap();
+    let surface = SurfaceObject::new(&mut canvas).unwrap();
+    assert!(!surface.as_raw().is_null());
+    drop(surface);
+    let mut block = HipArray3D::<u32>::new(4, 4, 4, ArrayFlags::SURFACE_LOAD_STORE).unwrap();
+    assert!(SurfaceObject::new_3d(&mut block).is_ok());
+}